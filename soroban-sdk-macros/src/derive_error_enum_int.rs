@@ -93,6 +93,28 @@ pub fn derive_type_error_enum_int(
     quote! {
         #spec_gen
 
+        impl #enum_ident {
+            /// Returns the variant of this error type that the error
+            /// represents, or `None` if the error is not a contract error with
+            /// one of this type's codes.
+            #[inline(always)]
+            pub fn try_from_error(error: #path::Error) -> Option<Self> {
+                <Self as TryFrom<#path::Error>>::try_from(error).ok()
+            }
+
+            /// Returns the variant of this error type that the invoke error
+            /// represents, or `None` if the invocation aborted or returned a
+            /// contract error that is not one of this type's codes.
+            ///
+            /// Useful for recovering the typed error of a contract invoked
+            /// through a client or `Env::try_invoke_contract` with
+            /// `InvokeError` as the error type.
+            #[inline(always)]
+            pub fn try_from_invoke_error(error: #path::InvokeError) -> Option<Self> {
+                <Self as TryFrom<#path::InvokeError>>::try_from(error).ok()
+            }
+        }
+
         impl TryFrom<#path::Error> for #enum_ident {
             type Error = #path::Error;
             #[inline(always)]
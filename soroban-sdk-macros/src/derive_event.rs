@@ -111,11 +111,36 @@ fn derive_impls(args: &ContractEventArgs, input: &DeriveInput) -> Result<TokenSt
                 .with_span(&input.span()))?,
         };
 
+    // The host limits the number of topics of an event, which includes the
+    // prefix topics as well as the topic fields.
+    const MAX_TOPICS: usize = 4;
+    let prefix_topics_len = prefix_topics.len();
+    if prefix_topics_len > MAX_TOPICS {
+        errors.push(
+            Error::custom(format!(
+                "contract events are limited to {MAX_TOPICS} topics, and {prefix_topics_len} prefix topics are specified"
+            ))
+            .with_span(&input.ident.span()),
+        );
+    }
+
     // Map each field of the struct to a spec for a param.
+    let mut topics = prefix_topics_len;
     let params = fields
         .map(|field| {
             let ident = field.ident.as_ref().unwrap();
             let is_topic = field.attrs.iter().any(|a| a.path().is_ident("topic"));
+            if is_topic {
+                topics += 1;
+                if topics > MAX_TOPICS && prefix_topics_len <= MAX_TOPICS {
+                    errors.push(
+                        Error::custom(format!(
+                            "contract events are limited to {MAX_TOPICS} topics, including the {prefix_topics_len} prefix topics"
+                        ))
+                        .with_span(&field.span()),
+                    );
+                }
+            }
             let location = if is_topic {
                 ScSpecEventParamLocationV0::TopicList
             } else {
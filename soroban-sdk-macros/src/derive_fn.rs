@@ -22,6 +22,7 @@ pub fn derive_pub_fn(
     inputs: &Punctuated<FnArg, Comma>,
     trait_ident: Option<&Ident>,
     client_ident: &str,
+    extend_instance: &TokenStream2,
 ) -> Result<TokenStream2, TokenStream2> {
     // Collect errors as they are encountered and emit them at the end.
     let mut errors = Vec::<Error>::new();
@@ -165,6 +166,7 @@ pub fn derive_pub_fn(
             #[deprecated(note = #deprecated_note)]
             pub fn invoke_raw(env: #crate_path::Env, #(#wrap_args),*) -> #crate_path::Val {
                 #use_trait;
+                #extend_instance
                 <_ as #crate_path::IntoVal<#crate_path::Env, #crate_path::Val>>::into_val(
                     #[allow(deprecated)]
                     &#call(
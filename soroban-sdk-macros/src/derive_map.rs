@@ -0,0 +1,217 @@
+use itertools::Itertools;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, FieldsNamed, Path};
+
+// The maximum length of a symbol, and so of a field name used as a key.
+const MAX_FIELD_NAME_LEN: usize = 32;
+
+fn named_fields(input: &DeriveInput) -> Result<&FieldsNamed, Error> {
+    match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(fields) => Ok(fields),
+            _ => Err(Error::new(
+                input.ident.span(),
+                "only structs with named fields can be converted to and from a map",
+            )),
+        },
+        _ => Err(Error::new(
+            input.ident.span(),
+            "only structs with named fields can be converted to and from a map",
+        )),
+    }
+}
+
+fn field_names(fields: &FieldsNamed) -> Result<Vec<(&syn::Ident, String)>, Error> {
+    let mut errors = Vec::<Error>::new();
+    let names = fields
+        .named
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().unwrap();
+            let name = ident.to_string();
+            if name.starts_with("r#") {
+                errors.push(Error::new(
+                    ident.span(),
+                    "raw identifiers cannot be used as struct field names converted to a map",
+                ));
+            } else if name.len() > MAX_FIELD_NAME_LEN {
+                errors.push(Error::new(
+                    ident.span(),
+                    format!(
+                        "struct field name is too long: {}, max is {MAX_FIELD_NAME_LEN}",
+                        name.len()
+                    ),
+                ));
+            }
+            (ident, name)
+        })
+        .collect();
+    match errors.into_iter().reduce(|mut a, b| {
+        a.combine(b);
+        a
+    }) {
+        Some(e) => Err(e),
+        None => Ok(names),
+    }
+}
+
+pub fn derive_into_map(path: &Path, input: &DeriveInput) -> TokenStream2 {
+    let ident = &input.ident;
+    let names = match named_fields(input).and_then(field_names) {
+        Ok(names) => names,
+        Err(e) => return e.to_compile_error(),
+    };
+    // Keys are passed to the host in order.
+    let (field_idents, field_names): (Vec<_>, Vec<_>) = names
+        .into_iter()
+        .sorted_by(|(_, a), (_, b)| a.cmp(b))
+        .unzip();
+    let field_count_usize = field_idents.len();
+    quote! {
+        impl #path::TryFromVal<#path::Env, #ident> for #path::Map<#path::Symbol, #path::Val> {
+            type Error = #path::ConversionError;
+            fn try_from_val(env: &#path::Env, val: &#ident) -> Result<Self, #path::ConversionError> {
+                use #path::{TryIntoVal,EnvBase,ConversionError,Val};
+                const KEYS: [&'static str; #field_count_usize] = [#(#field_names),*];
+                let vals: [Val; #field_count_usize] = [
+                    #((&val.#field_idents).try_into_val(env).map_err(|_| ConversionError)?),*
+                ];
+                let map: Val = env.map_new_from_slices(&KEYS, &vals).map_err(|_| ConversionError)?.into();
+                map.try_into_val(env)
+            }
+        }
+
+        impl #path::TryFromVal<#path::Env, &#ident> for #path::Map<#path::Symbol, #path::Val> {
+            type Error = #path::ConversionError;
+            #[inline(always)]
+            fn try_from_val(env: &#path::Env, val: &&#ident) -> Result<Self, #path::ConversionError> {
+                <_ as #path::TryFromVal<#path::Env, #ident>>::try_from_val(env, *val)
+            }
+        }
+    }
+}
+
+pub fn derive_from_map(path: &Path, input: &DeriveInput) -> TokenStream2 {
+    let ident = &input.ident;
+    let names = match named_fields(input).and_then(field_names) {
+        Ok(names) => names,
+        Err(e) => return e.to_compile_error(),
+    };
+    let fields = names.iter().map(|(field_ident, field_name)| {
+        quote! {
+            #field_ident: map
+                .get(#path::Symbol::new(env, #field_name))
+                .ok_or(ConversionError)?
+                .try_into_val(env)
+                .map_err(|_| ConversionError)?
+        }
+    });
+    quote! {
+        impl #path::TryFromVal<#path::Env, #path::Map<#path::Symbol, #path::Val>> for #ident {
+            type Error = #path::ConversionError;
+            fn try_from_val(env: &#path::Env, map: &#path::Map<#path::Symbol, #path::Val>) -> Result<Self, #path::ConversionError> {
+                use #path::{TryIntoVal,ConversionError};
+                Ok(Self {
+                    #(#fields,)*
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use quote::quote;
+    use syn::{parse_quote, DeriveInput, Path};
+
+    use super::{derive_from_map, derive_into_map};
+
+    fn input() -> DeriveInput {
+        parse_quote! {
+            struct Struct {
+                b: u32,
+                a: i64,
+            }
+        }
+    }
+
+    #[test]
+    fn test_derive_into_map() {
+        let path: Path = parse_quote!(soroban_sdk);
+        let expect = quote! {
+            impl soroban_sdk::TryFromVal<soroban_sdk::Env, Struct> for soroban_sdk::Map<soroban_sdk::Symbol, soroban_sdk::Val> {
+                type Error = soroban_sdk::ConversionError;
+                fn try_from_val(env: &soroban_sdk::Env, val: &Struct) -> Result<Self, soroban_sdk::ConversionError> {
+                    use soroban_sdk::{TryIntoVal,EnvBase,ConversionError,Val};
+                    const KEYS: [&'static str; 2usize] = ["a", "b"];
+                    let vals: [Val; 2usize] = [
+                        (&val.a).try_into_val(env).map_err(|_| ConversionError)?,
+                        (&val.b).try_into_val(env).map_err(|_| ConversionError)?
+                    ];
+                    let map: Val = env.map_new_from_slices(&KEYS, &vals).map_err(|_| ConversionError)?.into();
+                    map.try_into_val(env)
+                }
+            }
+
+            impl soroban_sdk::TryFromVal<soroban_sdk::Env, &Struct> for soroban_sdk::Map<soroban_sdk::Symbol, soroban_sdk::Val> {
+                type Error = soroban_sdk::ConversionError;
+                #[inline(always)]
+                fn try_from_val(env: &soroban_sdk::Env, val: &&Struct) -> Result<Self, soroban_sdk::ConversionError> {
+                    <_ as soroban_sdk::TryFromVal<soroban_sdk::Env, Struct>>::try_from_val(env, *val)
+                }
+            }
+        };
+        assert_eq!(
+            derive_into_map(&path, &input()).to_string(),
+            expect.to_string()
+        );
+    }
+
+    #[test]
+    fn test_derive_from_map() {
+        let path: Path = parse_quote!(soroban_sdk);
+        let expect = quote! {
+            impl soroban_sdk::TryFromVal<soroban_sdk::Env, soroban_sdk::Map<soroban_sdk::Symbol, soroban_sdk::Val>> for Struct {
+                type Error = soroban_sdk::ConversionError;
+                fn try_from_val(env: &soroban_sdk::Env, map: &soroban_sdk::Map<soroban_sdk::Symbol, soroban_sdk::Val>) -> Result<Self, soroban_sdk::ConversionError> {
+                    use soroban_sdk::{TryIntoVal,ConversionError};
+                    Ok(Self {
+                        b: map
+                            .get(soroban_sdk::Symbol::new(env, "b"))
+                            .ok_or(ConversionError)?
+                            .try_into_val(env)
+                            .map_err(|_| ConversionError)?,
+                        a: map
+                            .get(soroban_sdk::Symbol::new(env, "a"))
+                            .ok_or(ConversionError)?
+                            .try_into_val(env)
+                            .map_err(|_| ConversionError)?,
+                    })
+                }
+            }
+        };
+        assert_eq!(
+            derive_from_map(&path, &input()).to_string(),
+            expect.to_string()
+        );
+    }
+
+    #[test]
+    fn test_raw_field_name_rejected() {
+        let path: Path = parse_quote!(soroban_sdk);
+        let input: DeriveInput = parse_quote! {
+            struct Struct {
+                r#type: u32,
+            }
+        };
+        for output in [
+            derive_into_map(&path, &input),
+            derive_from_map(&path, &input),
+        ] {
+            let output = output.to_string();
+            assert!(output.contains("compile_error"), "{output}");
+            assert!(output.contains("raw identifiers"), "{output}");
+        }
+    }
+}
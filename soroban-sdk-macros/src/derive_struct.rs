@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use proc_macro2::{Literal, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
-use syn::{Attribute, DataStruct, Error, Ident, Path, Visibility};
+use syn::{Attribute, DataStruct, Error, Field, Ident, Path, Visibility};
 
 use stellar_xdr::curr as stellar_xdr;
 use stellar_xdr::{
@@ -27,7 +27,8 @@ pub fn derive_type_struct(
     let mut errors = Vec::<Error>::new();
     let fields = &data.fields;
     let field_count_usize: usize = fields.len();
-    let (spec_fields, field_idents, field_names, field_idx_lits, try_from_xdrs, try_into_xdrs): (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>) = fields
+    let has_defaults = fields.iter().any(is_default_field);
+    let (spec_fields, field_idents, field_names, field_idx_lits, try_from_vals, try_from_xdrs, try_into_xdrs): (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>) = fields
         .iter()
         .sorted_by_key(|field| field.ident.as_ref().unwrap().to_string())
         .enumerate()
@@ -35,6 +36,7 @@ pub fn derive_type_struct(
             let field_ident = field.ident.as_ref().unwrap();
             let field_name = field_ident.to_string();
             let field_idx_lit = Literal::usize_unsuffixed(field_num);
+            let is_default = is_default_field(field);
             let spec_field = ScSpecUdtStructFieldV0 {
                 doc: docs_from_attrs(&field.attrs),
                 name: field_name.clone().try_into().unwrap_or_else(|_| {
@@ -50,12 +52,50 @@ pub fn derive_type_struct(
                     }
                 },
             };
-            let try_from_xdr = quote! {
-                #field_ident: {
-                    let key: #path::xdr::ScVal = #path::xdr::ScSymbol(#field_name.try_into().map_err(|_| #path::xdr::Error::Invalid)?).into();
-                    let idx = map.binary_search_by_key(&key, |entry| entry.key.clone()).map_err(|_| #path::xdr::Error::Invalid)?;
-                    let rv: #path::Val = (&map[idx].val.clone()).try_into_val(env).map_err(|_| #path::xdr::Error::Invalid)?;
-                    rv.try_into_val(env).map_err(|_| #path::xdr::Error::Invalid)?
+            // Only used if the struct has fields with defaults, where the
+            // keys are looked up one at a time and counted.
+            let try_from_val = if is_default {
+                quote! {
+                    #field_ident: match map.get(#path::Symbol::new(env, #field_name)) {
+                        Some(v) => {
+                            found += 1;
+                            v.try_into_val(env).map_err(|_| ConversionError)?
+                        }
+                        None => Default::default(),
+                    }
+                }
+            } else {
+                quote! {
+                    #field_ident: {
+                        let v = map.get(#path::Symbol::new(env, #field_name)).ok_or(ConversionError)?;
+                        found += 1;
+                        v.try_into_val(env).map_err(|_| ConversionError)?
+                    }
+                }
+            };
+            let try_from_xdr = if is_default {
+                quote! {
+                    #field_ident: {
+                        let key: #path::xdr::ScVal = #path::xdr::ScSymbol(#field_name.try_into().map_err(|_| #path::xdr::Error::Invalid)?).into();
+                        match map.binary_search_by_key(&key, |entry| entry.key.clone()) {
+                            Ok(idx) => {
+                                found += 1;
+                                let rv: #path::Val = (&map[idx].val.clone()).try_into_val(env).map_err(|_| #path::xdr::Error::Invalid)?;
+                                rv.try_into_val(env).map_err(|_| #path::xdr::Error::Invalid)?
+                            }
+                            Err(_) => Default::default(),
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    #field_ident: {
+                        let key: #path::xdr::ScVal = #path::xdr::ScSymbol(#field_name.try_into().map_err(|_| #path::xdr::Error::Invalid)?).into();
+                        let idx = map.binary_search_by_key(&key, |entry| entry.key.clone()).map_err(|_| #path::xdr::Error::Invalid)?;
+                        found += 1;
+                        let rv: #path::Val = (&map[idx].val.clone()).try_into_val(env).map_err(|_| #path::xdr::Error::Invalid)?;
+                        rv.try_into_val(env).map_err(|_| #path::xdr::Error::Invalid)?
+                    }
                 }
             };
             let try_into_xdr = quote! {
@@ -64,7 +104,7 @@ pub fn derive_type_struct(
                     val: (&val.#field_ident).try_into().map_err(|_| #path::xdr::Error::Invalid)?,
                 }
             };
-            (spec_field, field_ident, field_name, field_idx_lit, try_from_xdr, try_into_xdr)
+            (spec_field, field_ident, field_name, field_idx_lit, try_from_val, try_from_xdr, try_into_xdr)
         })
         .multiunzip();
 
@@ -100,6 +140,36 @@ pub fn derive_type_struct(
         None
     };
 
+    // Structs without fields with defaults are unpacked by the host in one
+    // call, which requires the map to have exactly the keys of the fields.
+    // Otherwise each key is looked up, and the keys found are counted to check
+    // that the map has no other keys.
+    let try_from_val_body = if has_defaults {
+        quote! {
+            use #path::{TryIntoVal,ConversionError,Map,Symbol,Val};
+            let map: Map<Symbol, Val> = val.try_into_val(env)?;
+            let mut found = 0u32;
+            let rv = Self {
+                #(#try_from_vals,)*
+            };
+            if found != map.len() {
+                return Err(ConversionError);
+            }
+            Ok(rv)
+        }
+    } else {
+        quote! {
+            use #path::{TryIntoVal,EnvBase,ConversionError,Val,MapObject};
+            const KEYS: [&'static str; #field_count_usize] = [#(#field_names),*];
+            let mut vals: [Val; #field_count_usize] = [Val::VOID.to_val(); #field_count_usize];
+            let map: MapObject = val.try_into().map_err(|_| ConversionError)?;
+            env.map_unpack_to_slice(map, &KEYS, &mut vals).map_err(|_| ConversionError)?;
+            Ok(Self {
+                #(#field_idents: vals[#field_idx_lits].try_into_val(env).map_err(|_| #path::ConversionError)?,)*
+            })
+        }
+    };
+
     // Output.
     let mut output = quote! {
         #spec_gen
@@ -107,14 +177,7 @@ pub fn derive_type_struct(
         impl #path::TryFromVal<#path::Env, #path::Val> for #ident {
             type Error = #path::ConversionError;
             fn try_from_val(env: &#path::Env, val: &#path::Val) -> Result<Self, #path::ConversionError> {
-                use #path::{TryIntoVal,EnvBase,ConversionError,Val,MapObject};
-                const KEYS: [&'static str; #field_count_usize] = [#(#field_names),*];
-                let mut vals: [Val; #field_count_usize] = [Val::VOID.to_val(); #field_count_usize];
-                let map: MapObject = val.try_into().map_err(|_| ConversionError)?;
-                env.map_unpack_to_slice(map, &KEYS, &mut vals).map_err(|_| ConversionError)?;
-                Ok(Self {
-                    #(#field_idents: vals[#field_idx_lits].try_into_val(env).map_err(|_| #path::ConversionError)?,)*
-                })
+                #try_from_val_body
             }
         }
 
@@ -150,13 +213,19 @@ pub fn derive_type_struct(
                     use #path::xdr::Validate;
                     use #path::TryIntoVal;
                     let map = val;
-                    if map.len() != #field_count_usize {
+                    if map.len() > #field_count_usize {
                         return Err(#path::xdr::Error::Invalid);
                     }
                     map.validate()?;
-                    Ok(Self{
+                    #[allow(unused_mut)]
+                    let mut found = 0usize;
+                    let rv = Self{
                         #(#try_from_xdrs,)*
-                    })
+                    };
+                    if found != map.len() {
+                        return Err(#path::xdr::Error::Invalid);
+                    }
+                    Ok(rv)
                 }
             }
 
@@ -213,3 +282,9 @@ pub fn derive_type_struct(
     }
     output
 }
+
+// Returns true if the field has the `#[default]` attribute, and is filled with
+// its default value when it is missing from the map being converted.
+pub fn is_default_field(field: &Field) -> bool {
+    field.attrs.iter().any(|a| a.path().is_ident("default"))
+}
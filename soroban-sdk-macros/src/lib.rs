@@ -9,6 +9,7 @@ mod derive_enum_int;
 mod derive_error_enum_int;
 mod derive_event;
 mod derive_fn;
+mod derive_map;
 mod derive_spec_fn;
 mod derive_struct;
 mod derive_struct_tuple;
@@ -25,8 +26,9 @@ use derive_enum_int::derive_type_enum_int;
 use derive_error_enum_int::derive_type_error_enum_int;
 use derive_event::derive_event;
 use derive_fn::{derive_contract_function_registration_ctor, derive_pub_fn};
+use derive_map::{derive_from_map, derive_into_map};
 use derive_spec_fn::derive_fn_spec;
-use derive_struct::derive_type_struct;
+use derive_struct::{derive_type_struct, is_default_field};
 use derive_struct_tuple::derive_type_struct_tuple;
 
 use darling::{ast::NestedMeta, FromMeta};
@@ -193,6 +195,14 @@ pub fn contract(metadata: TokenStream, input: TokenStream) -> TokenStream {
 struct ContractImplArgs {
     #[darling(default = "default_crate_path")]
     crate_path: Path,
+    #[darling(default)]
+    extend_instance: Option<darling::util::Override<ExtendInstanceArgs>>,
+}
+
+#[derive(Debug, FromMeta)]
+struct ExtendInstanceArgs {
+    threshold: Expr,
+    extend_to: Expr,
 }
 
 #[proc_macro_attribute]
@@ -210,6 +220,21 @@ pub fn contractimpl(metadata: TokenStream, input: TokenStream) -> TokenStream {
     let crate_path = &args.crate_path;
     let crate_path_str = quote!(#crate_path).to_string();
 
+    // Without values, extend to the maximum TTL once below half of it.
+    let extend_instance = match &args.extend_instance {
+        None => quote! {},
+        Some(darling::util::Override::Inherit) => quote! {
+            let max_ttl = env.storage().max_ttl();
+            env.extend_instance_ttl_auto(max_ttl / 2, max_ttl);
+        },
+        Some(darling::util::Override::Explicit(ExtendInstanceArgs {
+            threshold,
+            extend_to,
+        })) => quote! {
+            env.extend_instance_ttl_auto(#threshold, #extend_to);
+        },
+    };
+
     let imp = parse_macro_input!(input as ItemImpl);
     let trait_ident = imp.trait_.as_ref().and_then(|x| x.1.get_ident());
     let ty = &imp.self_ty;
@@ -254,6 +279,7 @@ pub fn contractimpl(metadata: TokenStream, input: TokenStream) -> TokenStream {
                 &m.sig.inputs,
                 trait_ident,
                 &client_ident,
+                &extend_instance,
             )
         })
         .collect();
@@ -400,15 +426,25 @@ pub fn contracttype(metadata: TokenStream, input: TokenStream) -> TokenStream {
             Fields::Named(_) => {
                 derive_type_struct(&args.crate_path, vis, ident, attrs, s, gen_spec, &args.lib)
             }
-            Fields::Unnamed(_) => derive_type_struct_tuple(
-                &args.crate_path,
-                vis,
-                ident,
-                attrs,
-                s,
-                gen_spec,
-                &args.lib,
-            ),
+            Fields::Unnamed(_) => {
+                if let Some(field) = s.fields.iter().find(|f| is_default_field(f)) {
+                    Error::new(
+                        field.span(),
+                        "default fields are only supported on structs with named fields",
+                    )
+                    .to_compile_error()
+                } else {
+                    derive_type_struct_tuple(
+                        &args.crate_path,
+                        vis,
+                        ident,
+                        attrs,
+                        s,
+                        gen_spec,
+                        &args.lib,
+                    )
+                }
+            }
             Fields::Unit => Error::new(
                 s.fields.span(),
                 "unit structs are not supported as contract types",
@@ -437,6 +473,14 @@ pub fn contracttype(metadata: TokenStream, input: TokenStream) -> TokenStream {
         )
         .to_compile_error(),
     };
+    // The default attributes of fields are only read by this macro, and are
+    // removed from the struct.
+    let mut input = input;
+    if let Data::Struct(s) = &mut input.data {
+        for field in s.fields.iter_mut() {
+            field.attrs.retain(|a| !a.path().is_ident("default"));
+        }
+    }
     quote! {
         #input
         #derived
@@ -444,6 +488,18 @@ pub fn contracttype(metadata: TokenStream, input: TokenStream) -> TokenStream {
     .into()
 }
 
+#[proc_macro_derive(IntoMap)]
+pub fn into_map(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_into_map(&default_crate_path(), &input).into()
+}
+
+#[proc_macro_derive(FromMap)]
+pub fn from_map(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_from_map(&default_crate_path(), &input).into()
+}
+
 #[proc_macro_attribute]
 pub fn contracterror(metadata: TokenStream, input: TokenStream) -> TokenStream {
     let args = match NestedMeta::parse_meta_list(metadata.into()) {
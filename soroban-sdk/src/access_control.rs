@@ -0,0 +1,186 @@
+//! Access control contains a helper for role-based access control.
+use crate::{
+    storage::{ReservedKey, SdkKey},
+    Address, Env, Map, Symbol, Vec,
+};
+
+/// The role that administers every role that has not been given a different
+/// admin role with [`AccessControl::set_role_admin`].
+pub const DEFAULT_ADMIN_ROLE: Symbol = soroban_sdk_macros::internal_symbol_short!("admin");
+
+/// AccessControl stores the members of named roles for the current contract,
+/// such as a `minter` or a `pauser` role.
+///
+/// Each role has an admin role, whose members can grant and revoke the role.
+/// The admin role of every role is [`DEFAULT_ADMIN_ROLE`] unless changed with
+/// [`set_role_admin`][AccessControl::set_role_admin]. The first admin is set
+/// with [`set_initial_admin`][AccessControl::set_initial_admin], usually from
+/// the contract's constructor.
+///
+/// Roles and their members are stored in the current contract's instance
+/// storage, as a `Map<Symbol, Vec<Address>>`, so roles are intended for a
+/// small number of members each.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{
+///     access_control::AccessControl, contract, contractimpl, symbol_short, Address, Env, Symbol,
+/// };
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn __constructor(env: Env, admin: Address) {
+///         AccessControl::set_initial_admin(&env, &admin);
+///     }
+///
+///     pub fn grant_role(env: Env, caller: Address, role: Symbol, account: Address) {
+///         AccessControl::grant_role(&env, &caller, &role, &account);
+///     }
+///
+///     pub fn mint(env: Env, minter: Address, to: Address, amount: i128) {
+///         AccessControl::require_role(&env, &symbol_short!("minter"), &minter);
+///         // ...
+///     }
+/// }
+/// # fn main() { }
+/// ```
+pub struct AccessControl;
+
+impl AccessControl {
+    /// Grants [`DEFAULT_ADMIN_ROLE`] to `admin`, without requiring any
+    /// authorization.
+    ///
+    /// Intended to be called once, such as from the contract's constructor.
+    ///
+    /// ### Panics
+    ///
+    /// If [`DEFAULT_ADMIN_ROLE`] already has any members.
+    pub fn set_initial_admin(env: &Env, admin: &Address) {
+        if !Self::role_members(env, &DEFAULT_ADMIN_ROLE).is_empty() {
+            sdk_panic!("admin already set");
+        }
+        Self::add_member(env, &DEFAULT_ADMIN_ROLE, admin);
+    }
+
+    /// Returns true if `account` is a member of `role`.
+    pub fn has_role(env: &Env, role: &Symbol, account: &Address) -> bool {
+        Self::role_members(env, role).contains(account)
+    }
+
+    /// Returns the members of `role`, in the order they were granted the
+    /// role.
+    pub fn role_members(env: &Env, role: &Symbol) -> Vec<Address> {
+        Self::roles(env)
+            .get(role.clone())
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Returns the admin role of `role`, whose members can grant and revoke
+    /// `role`.
+    pub fn role_admin(env: &Env, role: &Symbol) -> Symbol {
+        env.storage()
+            .instance()
+            .get::<_, Map<Symbol, Symbol>>(&Self::role_admins_key())
+            .and_then(|admins| admins.get(role.clone()))
+            .unwrap_or(DEFAULT_ADMIN_ROLE)
+    }
+
+    /// Sets the admin role of `role`, without requiring any authorization.
+    ///
+    /// Contracts must authorize the caller before calling this function, if
+    /// it is not called from the contract's constructor.
+    pub fn set_role_admin(env: &Env, role: &Symbol, admin_role: &Symbol) {
+        let storage = env.storage().instance();
+        let mut admins: Map<Symbol, Symbol> = storage
+            .get(&Self::role_admins_key())
+            .unwrap_or_else(|| Map::new(env));
+        admins.set(role.clone(), admin_role.clone());
+        storage.set(&Self::role_admins_key(), &admins);
+    }
+
+    /// Grants `role` to `account`.
+    ///
+    /// The `caller` must authorize the invocation and be a member of the
+    /// role's admin role. Granting a role to a member of the role has no
+    /// effect.
+    ///
+    /// ### Panics
+    ///
+    /// If `caller` has not authorized the invocation.
+    ///
+    /// If `caller` is not a member of the role's admin role.
+    pub fn grant_role(env: &Env, caller: &Address, role: &Symbol, account: &Address) {
+        Self::require_role(env, &Self::role_admin(env, role), caller);
+        if !Self::has_role(env, role, account) {
+            Self::add_member(env, role, account);
+        }
+    }
+
+    /// Revokes `role` from `account`.
+    ///
+    /// The `caller` must authorize the invocation and be a member of the
+    /// role's admin role. Revoking a role from an account that is not a
+    /// member has no effect.
+    ///
+    /// ### Panics
+    ///
+    /// If `caller` has not authorized the invocation.
+    ///
+    /// If `caller` is not a member of the role's admin role.
+    pub fn revoke_role(env: &Env, caller: &Address, role: &Symbol, account: &Address) {
+        Self::require_role(env, &Self::role_admin(env, role), caller);
+        let mut members = Self::role_members(env, role);
+        if let Some(i) = members.first_index_of(account) {
+            members.remove(i);
+            let mut roles = Self::roles(env);
+            if members.is_empty() {
+                roles.remove(role.clone());
+            } else {
+                roles.set(role.clone(), members);
+            }
+            env.storage().instance().set(&Self::roles_key(), &roles);
+        }
+    }
+
+    /// Requires `account` to have authorized the invocation and to be a
+    /// member of `role`.
+    ///
+    /// ### Panics
+    ///
+    /// If `account` has not authorized the invocation.
+    ///
+    /// If `account` is not a member of `role`.
+    pub fn require_role(env: &Env, role: &Symbol, account: &Address) {
+        account.require_auth();
+        if !Self::has_role(env, role, account) {
+            sdk_panic!("account is missing role");
+        }
+    }
+
+    fn add_member(env: &Env, role: &Symbol, account: &Address) {
+        let mut roles = Self::roles(env);
+        let mut members = roles.get(role.clone()).unwrap_or_else(|| Vec::new(env));
+        members.push_back(account.clone());
+        roles.set(role.clone(), members);
+        env.storage().instance().set(&Self::roles_key(), &roles);
+    }
+
+    fn roles(env: &Env) -> Map<Symbol, Vec<Address>> {
+        env.storage()
+            .instance()
+            .get(&Self::roles_key())
+            .unwrap_or_else(|| Map::new(env))
+    }
+
+    fn roles_key() -> ReservedKey {
+        SdkKey::Roles.into()
+    }
+
+    fn role_admins_key() -> ReservedKey {
+        SdkKey::RoleAdmins.into()
+    }
+}
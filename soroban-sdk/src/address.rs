@@ -312,6 +312,61 @@ impl Address {
         }
     }
 
+    /// Creates an `Address` corresponding to the provided Stellar strkey,
+    /// returning an error if the strkey is malformed.
+    ///
+    /// The only supported strkey types are account keys (`G...`) and contract
+    /// keys (`C...`). Any other strkey type, or a strkey with an invalid
+    /// encoding or checksum, results in a [ConversionError].
+    ///
+    /// Prefer using the `Address` directly as input or output argument. Only
+    /// use this in special cases when addresses need to be shared between
+    /// different environments (e.g. different chains).
+    pub fn try_from_string(strkey: &String) -> Result<Self, ConversionError> {
+        Self::try_from_string_bytes(&strkey.to_bytes())
+    }
+
+    /// Creates an `Address` corresponding to the provided Stellar strkey
+    /// bytes, returning an error if the strkey is malformed.
+    ///
+    /// The bytes should contain exactly the same contents as `String` would
+    /// (i.e. base-32 ASCII string).
+    ///
+    /// The only supported strkey types are account keys (`G...`) and contract
+    /// keys (`C...`). Any other strkey type, or a strkey with an invalid
+    /// encoding or checksum, results in a [ConversionError].
+    ///
+    /// Prefer using the `Address` directly as input or output argument. Only
+    /// use this in special cases when addresses need to be shared between
+    /// different environments (e.g. different chains).
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Address, Bytes, Env};
+    ///
+    /// let env = Env::default();
+    /// let strkey = b"GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ";
+    /// let address = Address::try_from_string_bytes(&Bytes::from_slice(&env, strkey));
+    /// assert!(address.is_ok());
+    ///
+    /// // The last character is changed, so the checksum doesn't match.
+    /// let strkey = b"GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGA";
+    /// let address = Address::try_from_string_bytes(&Bytes::from_slice(&env, strkey));
+    /// assert!(address.is_err());
+    /// ```
+    pub fn try_from_string_bytes(strkey: &Bytes) -> Result<Self, ConversionError> {
+        let mut buf = [0u8; STRKEY_LEN];
+        if strkey.len() as usize != buf.len() {
+            return Err(ConversionError);
+        }
+        strkey.copy_into_slice(&mut buf);
+        if !is_valid_strkey(&buf) {
+            return Err(ConversionError);
+        }
+        Ok(Self::from_string_bytes(strkey))
+    }
+
     /// Returns the executable type of this address, if any.
     ///
     /// Returns None when the contract or account does not exist.   
@@ -336,6 +391,50 @@ impl Address {
         !executable_val.is_void()
     }
 
+    /// Returns whether this address is a contract address (`C...`).
+    ///
+    /// The check only inspects the address itself and doesn't require the
+    /// contract to exist. At most one of `is_contract` and [`is_account`]
+    /// returns true, and neither does for other types of address, such as
+    /// claimable balances and liquidity pools.
+    ///
+    /// [`is_account`]: Address::is_account
+    pub fn is_contract(&self) -> bool {
+        #[cfg(not(target_family = "wasm"))]
+        return matches!(ScAddress::from(self), ScAddress::Contract(_));
+        #[cfg(target_family = "wasm")]
+        return self.strkey_prefix() == b'C';
+    }
+
+    /// Returns whether this address is an account address (`G...`).
+    ///
+    /// The check only inspects the address itself and doesn't require the
+    /// account to exist. At most one of [`is_contract`] and `is_account`
+    /// returns true, and neither does for other types of address, such as
+    /// claimable balances and liquidity pools.
+    ///
+    /// [`is_contract`]: Address::is_contract
+    pub fn is_account(&self) -> bool {
+        #[cfg(not(target_family = "wasm"))]
+        return matches!(ScAddress::from(self), ScAddress::Account(_));
+        #[cfg(target_family = "wasm")]
+        return self.strkey_prefix() == b'G';
+    }
+
+    // Returns the first character of the strkey, which identifies the type of
+    // the address. Contracts have no host function that returns the type, so
+    // the strkey is the only way to read it.
+    #[cfg(target_family = "wasm")]
+    fn strkey_prefix(&self) -> u8 {
+        use crate::env::internal::EnvBase as _;
+        let strkey = self.env.address_to_strkey(self.obj).unwrap_infallible();
+        let mut prefix = [0u8; 1];
+        self.env
+            .string_copy_to_slice(strkey, Val::U32_ZERO, &mut prefix)
+            .unwrap_optimized();
+        prefix[0]
+    }
+
     /// Converts this `Address` into the corresponding Stellar strkey.
     pub fn to_string(&self) -> String {
         String::try_from_val(
@@ -372,6 +471,59 @@ impl Address {
     }
 }
 
+// The length of an account or contract strkey.
+const STRKEY_LEN: usize = 56;
+
+// Returns true if the strkey is a well formed account (`G...`) or contract
+// (`C...`) strkey.
+//
+// A strkey is the base32 encoding of a version byte, the 32 byte key, and a
+// CRC16-XModem checksum of the preceding bytes in little endian order. 56
+// base32 characters decode to exactly those 35 bytes.
+fn is_valid_strkey(strkey: &[u8; STRKEY_LEN]) -> bool {
+    const VERSION_ACCOUNT: u8 = 6 << 3;
+    const VERSION_CONTRACT: u8 = 2 << 3;
+
+    let mut decoded = [0u8; 35];
+    let mut acc: u64 = 0;
+    let mut bits = 0;
+    let mut n = 0;
+    for c in strkey {
+        let v = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return false,
+        };
+        acc = (acc << 5) | v as u64;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            decoded[n] = (acc >> bits) as u8;
+            n += 1;
+        }
+    }
+    if decoded[0] != VERSION_ACCOUNT && decoded[0] != VERSION_CONTRACT {
+        return false;
+    }
+    let checksum = u16::from_le_bytes([decoded[33], decoded[34]]);
+    crc16_xmodem(&decoded[..33]) == checksum
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for b in data {
+        crc ^= (*b as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
 #[cfg(any(not(target_family = "wasm"), test, feature = "testutils"))]
 use crate::env::xdr::{ContractId, Hash};
 use crate::unwrap::UnwrapOptimized;
@@ -0,0 +1,207 @@
+use core::{cmp::Ordering, fmt::Debug};
+
+use crate::{Env, IntoVal, TryFromVal, Val, Vec};
+
+/// BinaryHeap is a priority queue backed by a [Vec], where [pop][Self::pop]
+/// returns the greatest item.
+///
+/// Items are ordered either by the host, with [push][Self::push], or by a
+/// comparator function, with [push_by][Self::push_by]. The same ordering must
+/// be used for every item pushed to a heap.
+///
+/// ### Cost
+///
+/// Every write to a host [Vec] produces a new [Vec], copying the items.
+/// Sifting items through a heap layout takes up to log n writes per push or
+/// pop, so instead the items are kept sorted, which takes one write per push
+/// or pop:
+///
+/// - [push][Self::push] is one host binary search and one insert.
+/// - [push_by][Self::push_by] is O(log n) comparisons, converting each
+///   compared item into `T` in the guest, and one insert.
+/// - [pop][Self::pop] and [peek][Self::peek] are a single host call.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{BinaryHeap, Env};
+///
+/// let env = Env::default();
+/// let mut heap = BinaryHeap::<u32>::new(&env);
+/// heap.push(3);
+/// heap.push(7);
+/// heap.push(5);
+/// assert_eq!(heap.peek(), Some(7));
+/// assert_eq!(heap.pop(), Some(7));
+/// assert_eq!(heap.pop(), Some(5));
+/// assert_eq!(heap.pop(), Some(3));
+/// assert_eq!(heap.pop(), None);
+/// ```
+pub struct BinaryHeap<T> {
+    // The items in ascending order, so that the greatest is last.
+    items: Vec<T>,
+}
+
+impl<T> Clone for BinaryHeap<T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+        }
+    }
+}
+
+impl<T> BinaryHeap<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    /// Create an empty BinaryHeap.
+    pub fn new(env: &Env) -> Self {
+        BinaryHeap {
+            items: Vec::new(env),
+        }
+    }
+
+    /// Returns the number of items in the BinaryHeap.
+    pub fn len(&self) -> u32 {
+        self.items.len()
+    }
+
+    /// Returns true if the BinaryHeap has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Pushes an item, ordered by the host.
+    ///
+    /// Items are ordered by the host, using the same ordering as
+    /// [`Vec::sort`], without converting items into `T` in the guest.
+    pub fn push(&mut self, item: T) {
+        let i = match self.items.binary_search(&item) {
+            Ok(i) | Err(i) => i,
+        };
+        self.items.insert(i, item);
+    }
+
+    /// Pushes an item, ordered by a comparator function.
+    ///
+    /// Items that compare equal are popped in the order they were pushed.
+    ///
+    /// ### Panics
+    ///
+    /// If any compared value cannot be converted to type T.
+    pub fn push_by<F>(&mut self, item: T, mut f: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        T: Clone,
+        T::Error: Debug,
+    {
+        // Find the first position holding an item not less than this one,
+        // placing it before any equal items so that they are popped first.
+        let mut low = 0;
+        let mut high = self.items.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if f(&self.items.get_unchecked(mid), &item) == Ordering::Less {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        self.items.insert(low, item);
+    }
+
+    /// Returns the greatest item, or None if empty.
+    pub fn peek(&self) -> Option<T> {
+        self.items.last()
+    }
+
+    /// Removes and returns the greatest item, or None if empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop_back()
+    }
+
+    /// Returns the items in ascending order.
+    #[must_use]
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.items
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::vec;
+
+    #[test]
+    fn test_push_pop_out_of_order() {
+        let env = Env::default();
+        let mut heap = BinaryHeap::<i64>::new(&env);
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+
+        for i in [5, -3, 9, 0, 9, 2, -3, 7] {
+            heap.push(i);
+        }
+        assert_eq!(heap.len(), 8);
+        assert_eq!(heap.peek(), Some(9));
+
+        let mut popped = std::vec::Vec::new();
+        while let Some(i) = heap.pop() {
+            popped.push(i);
+        }
+        assert_eq!(popped, [9, 9, 7, 5, 2, 0, -3, -3]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn test_push_pop_interleaved() {
+        let env = Env::default();
+        let mut heap = BinaryHeap::<u32>::new(&env);
+        heap.push(2);
+        heap.push(8);
+        assert_eq!(heap.pop(), Some(8));
+        heap.push(1);
+        heap.push(5);
+        assert_eq!(heap.pop(), Some(5));
+        assert_eq!(heap.pop(), Some(2));
+        heap.push(3);
+        assert_eq!(heap.into_sorted_vec(), vec![&env, 1, 3]);
+    }
+
+    #[test]
+    fn test_push_by_with_ties() {
+        let env = Env::default();
+        // Orders of (price, id), by price only.
+        let mut heap = BinaryHeap::<(u32, u32)>::new(&env);
+        let by_price = |a: &(u32, u32), b: &(u32, u32)| a.0.cmp(&b.0);
+        for order in [(10, 1), (30, 2), (10, 3), (20, 4), (30, 5), (10, 6)] {
+            heap.push_by(order, by_price);
+        }
+        assert_eq!(heap.peek(), Some((30, 2)));
+
+        let mut popped = std::vec::Vec::new();
+        while let Some(order) = heap.pop() {
+            popped.push(order);
+        }
+        // Orders with the same price are popped in the order they were pushed.
+        assert_eq!(
+            popped,
+            [(30, 2), (30, 5), (20, 4), (10, 1), (10, 3), (10, 6)]
+        );
+    }
+
+    #[test]
+    fn test_push_by_reverse_is_min_heap() {
+        let env = Env::default();
+        let mut heap = BinaryHeap::<u32>::new(&env);
+        for i in [4, 1, 3, 1, 2] {
+            heap.push_by(i, |a, b| b.cmp(a));
+        }
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.pop(), Some(2));
+        assert_eq!(heap.pop(), Some(3));
+        assert_eq!(heap.pop(), Some(4));
+    }
+}
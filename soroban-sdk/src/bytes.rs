@@ -482,6 +482,33 @@ impl Bytes {
         }
     }
 
+    /// Create a Bytes containing `count` copies of `byte`.
+    ///
+    /// The bytes are built by repeatedly appending the bytes to themselves,
+    /// so the number of host calls grows with the logarithm of `count`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Bytes, Env};
+    ///
+    /// let env = Env::default();
+    /// let padding = Bytes::repeat(&env, 0xff, 4);
+    /// assert_eq!(padding, Bytes::from_array(&env, &[0xff; 4]));
+    /// ```
+    pub fn repeat(env: &Env, byte: u8, count: u32) -> Bytes {
+        const CHUNK_SIZE: u32 = 32;
+        let mut res = Bytes::from_slice(
+            env,
+            &[byte; CHUNK_SIZE as usize][..count.min(CHUNK_SIZE) as usize],
+        );
+        while res.len() < count {
+            let n = res.len().min(count - res.len());
+            res.append(&res.slice(..n));
+        }
+        res
+    }
+
     /// Sets the byte at the position with new value.
     ///
     /// ### Panics
@@ -698,6 +725,21 @@ impl Bytes {
             .unwrap_optimized()
     }
 
+    /// Create a Bytes by joining the parts in order.
+    ///
+    /// Each part is appended to the result with one host call, the bytes are
+    /// never copied into guest memory. The host has no call for joining many
+    /// parts at once, so the number of host calls is the number of parts, and
+    /// the cost is the same as calling [`append`][Bytes::append] for each
+    /// part.
+    pub fn concat(env: &Env, parts: &[&Bytes]) -> Bytes {
+        let mut res = Bytes::new(env);
+        for part in parts {
+            res.append(part);
+        }
+        res
+    }
+
     /// Copy the bytes from slice.
     ///
     /// The full number of bytes in slice are always copied and [Bytes] is grown
@@ -750,10 +792,85 @@ impl Bytes {
         unsafe { Self::unchecked_new(env.clone(), bin) }
     }
 
+    /// Splits the bytes into two at the position, returning the bytes before
+    /// the position and the bytes from the position onwards.
+    ///
+    /// ### Panics
+    ///
+    /// If the position is out-of-bounds.
+    #[must_use]
+    pub fn split_at(&self, mid: u32) -> (Self, Self) {
+        (self.slice(..mid), self.slice(mid..))
+    }
+
+    /// Splits the bytes into two at the position, truncating self to the bytes
+    /// before the position and returning the bytes from the position onwards.
+    ///
+    /// ### Panics
+    ///
+    /// If the position is out-of-bounds.
+    #[must_use]
+    pub fn split_off(&mut self, at: u32) -> Self {
+        let tail = self.slice(at..);
+        *self = self.slice(..at);
+        tail
+    }
+
     pub fn iter(&self) -> BytesIter {
         self.clone().into_iter()
     }
 
+    /// Returns the index of the first occurrence of the byte, or None if the
+    /// byte does not occur.
+    pub fn first_index_of(&self, byte: u8) -> Option<u32> {
+        self.iter().position(|b| b == byte).map(|i| i as u32)
+    }
+
+    /// Returns the index of the first occurrence of the needle, or None if the
+    /// needle does not occur.
+    ///
+    /// An empty needle occurs at index 0.
+    pub fn index_of_slice(&self, needle: &Bytes) -> Option<u32> {
+        let len = self.len();
+        let needle_len = needle.len();
+        if needle_len > len {
+            return None;
+        }
+        (0..=len - needle_len).find(|i| self.slice(*i..*i + needle_len) == *needle)
+    }
+
+    /// Returns an iterator over the bytes in chunks of `size` bytes.
+    ///
+    /// The chunks do not overlap. If the length of the bytes is not a
+    /// multiple of `size`, the last chunk is shorter than `size`.
+    ///
+    /// ### Panics
+    ///
+    /// If `size` is zero.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{bytes, Env};
+    ///
+    /// let env = Env::default();
+    /// let b = bytes!(&env, 0x0102030405);
+    /// let mut chunks = b.chunks(2);
+    /// assert_eq!(chunks.next(), Some(bytes!(&env, 0x0102)));
+    /// assert_eq!(chunks.next(), Some(bytes!(&env, 0x0304)));
+    /// assert_eq!(chunks.next(), Some(bytes!(&env, 0x05)));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    pub fn chunks(&self, size: u32) -> BytesChunks {
+        if size == 0 {
+            sdk_panic!("chunk size must be non-zero");
+        }
+        BytesChunks {
+            bytes: self.clone(),
+            size,
+        }
+    }
+
     /// Copy the bytes into a buffer of given size.
     ///
     /// Returns the buffer and a range of where the bytes live in the given
@@ -800,6 +917,185 @@ impl Bytes {
     pub fn to_string(&self) -> String {
         self.into()
     }
+
+    /// Returns the bytes encoded as lowercase hexadecimal.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{bytes, Env, String};
+    ///
+    /// let env = Env::default();
+    /// let b = bytes!(&env, 0x00ff10);
+    /// assert_eq!(b.to_hex(), String::from_str(&env, "00ff10"));
+    /// ```
+    pub fn to_hex(&self) -> String {
+        const CHUNK_SIZE: u32 = 128;
+        let mut buf = [0u8; CHUNK_SIZE as usize];
+        let mut out = [0u8; 2 * CHUNK_SIZE as usize];
+        let mut hex = Bytes::new(self.env());
+        // Copy the bytes into the guest a chunk at a time, so that only fixed
+        // size buffers are needed on the stack.
+        for chunk in self.chunks(CHUNK_SIZE) {
+            let n = chunk.len() as usize;
+            chunk.copy_into_slice(&mut buf[..n]);
+            for (digits, b) in out.chunks_exact_mut(2).zip(&buf[..n]) {
+                digits[0] = HEX_DIGITS[(b >> 4) as usize];
+                digits[1] = HEX_DIGITS[(b & 0xf) as usize];
+            }
+            hex.extend_from_slice(&out[..2 * n]);
+        }
+        hex.to_string()
+    }
+
+    /// Create a Bytes from a hexadecimal string.
+    ///
+    /// Both lowercase and uppercase digits are accepted.
+    ///
+    /// ### Errors
+    ///
+    /// If the string has an odd length, or contains a character that is not a
+    /// hexadecimal digit.
+    pub fn from_hex(env: &Env, s: &String) -> Result<Bytes, ConversionError> {
+        const CHUNK_SIZE: u32 = 256;
+        let s = s.to_bytes();
+        if s.len() % 2 != 0 {
+            return Err(ConversionError);
+        }
+        let mut buf = [0u8; CHUNK_SIZE as usize];
+        let mut out = [0u8; CHUNK_SIZE as usize / 2];
+        let mut bytes = Bytes::new(env);
+        for chunk in s.chunks(CHUNK_SIZE) {
+            let n = chunk.len() as usize;
+            chunk.copy_into_slice(&mut buf[..n]);
+            for (b, digits) in out.iter_mut().zip(buf[..n].chunks_exact(2)) {
+                *b = (hex_value(digits[0])? << 4) | hex_value(digits[1])?;
+            }
+            bytes.extend_from_slice(&out[..n / 2]);
+        }
+        Ok(bytes)
+    }
+
+    /// Returns the bytes encoded as base64, with the standard alphabet and
+    /// padding of RFC 4648.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Bytes, Env, String};
+    ///
+    /// let env = Env::default();
+    /// let b = Bytes::from_slice(&env, b"soroban");
+    /// assert_eq!(b.to_base64(), String::from_str(&env, "c29yb2Jhbg=="));
+    /// ```
+    pub fn to_base64(&self) -> String {
+        // A multiple of 3, so that only the last chunk needs padding.
+        const CHUNK_SIZE: u32 = 96;
+        let mut buf = [0u8; CHUNK_SIZE as usize];
+        let mut out = [0u8; CHUNK_SIZE as usize / 3 * 4];
+        let mut base64 = Bytes::new(self.env());
+        for chunk in self.chunks(CHUNK_SIZE) {
+            let n = chunk.len() as usize;
+            chunk.copy_into_slice(&mut buf[..n]);
+            let mut len = 0;
+            for (chars, group) in out.chunks_exact_mut(4).zip(buf[..n].chunks(3)) {
+                let v = ((group[0] as u32) << 16)
+                    | ((*group.get(1).unwrap_or(&0) as u32) << 8)
+                    | *group.get(2).unwrap_or(&0) as u32;
+                for (i, c) in chars.iter_mut().enumerate() {
+                    *c = if i <= group.len() {
+                        BASE64_ALPHABET[((v >> (18 - 6 * i)) & 0x3f) as usize]
+                    } else {
+                        b'='
+                    };
+                }
+                len += 4;
+            }
+            base64.extend_from_slice(&out[..len]);
+        }
+        base64.to_string()
+    }
+
+    /// Create a Bytes from a base64 string, with the standard alphabet and
+    /// padding of RFC 4648.
+    ///
+    /// ### Errors
+    ///
+    /// If the string's length is not a multiple of 4, it contains a character
+    /// that is not in the alphabet, it has padding other than at the end, or
+    /// the bits discarded by the padding are not zero.
+    pub fn from_base64(env: &Env, s: &String) -> Result<Bytes, ConversionError> {
+        // A multiple of 4, so that chunks contain whole groups.
+        const CHUNK_SIZE: u32 = 128;
+        let s = s.to_bytes();
+        let len = s.len();
+        if len % 4 != 0 {
+            return Err(ConversionError);
+        }
+        let mut buf = [0u8; CHUNK_SIZE as usize];
+        let mut out = [0u8; CHUNK_SIZE as usize / 4 * 3];
+        let mut bytes = Bytes::new(env);
+        for (i, chunk) in s.chunks(CHUNK_SIZE).enumerate() {
+            let n = chunk.len() as usize;
+            chunk.copy_into_slice(&mut buf[..n]);
+            let is_last_chunk = (i as u32 + 1) * CHUNK_SIZE >= len;
+            let mut out_len = 0;
+            for (j, chars) in buf[..n].chunks_exact(4).enumerate() {
+                // Padding is only allowed at the end of the last group.
+                let padding = if is_last_chunk && (j + 1) * 4 == n {
+                    chars.iter().rev().take_while(|c| **c == b'=').count()
+                } else {
+                    0
+                };
+                if padding > 2 {
+                    return Err(ConversionError);
+                }
+                let mut v = 0u32;
+                for (k, c) in chars.iter().enumerate() {
+                    let bits = if k < 4 - padding {
+                        base64_value(*c)?
+                    } else {
+                        0
+                    };
+                    v = (v << 6) | bits;
+                }
+                // The bits that are discarded by padding must be zero.
+                if v & [0, 0xff, 0xffff][padding] != 0 {
+                    return Err(ConversionError);
+                }
+                let decoded = [(v >> 16) as u8, (v >> 8) as u8, v as u8];
+                out[out_len..out_len + 3 - padding].copy_from_slice(&decoded[..3 - padding]);
+                out_len += 3 - padding;
+            }
+            bytes.extend_from_slice(&out[..out_len]);
+        }
+        Ok(bytes)
+    }
+}
+
+const HEX_DIGITS: [u8; 16] = *b"0123456789abcdef";
+
+const BASE64_ALPHABET: [u8; 64] =
+    *b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn hex_value(c: u8) -> Result<u8, ConversionError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(ConversionError),
+    }
+}
+
+fn base64_value(c: u8) -> Result<u32, ConversionError> {
+    match c {
+        b'A'..=b'Z' => Ok((c - b'A') as u32),
+        b'a'..=b'z' => Ok((c - b'a') as u32 + 26),
+        b'0'..=b'9' => Ok((c - b'0') as u32 + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        _ => Err(ConversionError),
+    }
 }
 
 /// A `BytesBuffer` stores a variable number of bytes, up to a fixed limit `B`.
@@ -827,6 +1123,71 @@ impl<const B: usize> BytesBuffer<B> {
     }
 }
 
+/// A `BytesBuilder` assembles a [Bytes] from multiple parts, such as the
+/// coordinates of a curve point.
+///
+/// The bytes being built live in the host. Each push is a single host call
+/// that appends to them, no bytes are copied into guest memory and nothing is
+/// allocated by the guest.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{bytes, BytesBuilder, BytesN, Env};
+///
+/// let env = Env::default();
+/// let x = BytesN::from_array(&env, &[1; 4]);
+/// let bytes = BytesBuilder::new(&env)
+///     .push_byte(0)
+///     .push_bytesn(&x)
+///     .push_bytes(&bytes![&env, [2, 3]])
+///     .build();
+/// assert_eq!(bytes, bytes![&env, [0, 1, 1, 1, 1, 2, 3]]);
+/// ```
+#[derive(Clone)]
+pub struct BytesBuilder(Bytes);
+
+impl BytesBuilder {
+    /// Create a builder with no bytes.
+    pub fn new(env: &Env) -> Self {
+        Self(Bytes::new(env))
+    }
+
+    /// Appends the byte.
+    pub fn push_byte(&mut self, b: u8) -> &mut Self {
+        self.0.push_back(b);
+        self
+    }
+
+    /// Appends the bytes.
+    pub fn push_bytes(&mut self, bytes: &Bytes) -> &mut Self {
+        self.0.append(bytes);
+        self
+    }
+
+    /// Appends the fixed-size bytes.
+    pub fn push_bytesn<const N: usize>(&mut self, bytes: &BytesN<N>) -> &mut Self {
+        self.0.append(bytes.as_ref());
+        self
+    }
+
+    /// Returns the number of bytes pushed so far.
+    pub fn len(&self) -> u32 {
+        self.0.len()
+    }
+
+    /// Returns true if no bytes have been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the built bytes.
+    #[must_use]
+    pub fn build(&self) -> Bytes {
+        self.0.clone()
+    }
+}
+
 impl IntoIterator for Bytes {
     type Item = u8;
     type IntoIter = BytesIter;
@@ -895,6 +1256,40 @@ impl ExactSizeIterator for BytesIter {
     }
 }
 
+#[derive(Clone)]
+pub struct BytesChunks {
+    bytes: Bytes,
+    size: u32,
+}
+
+impl Iterator for BytesChunks {
+    type Item = Bytes;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            None
+        } else {
+            let mid = self.size.min(self.bytes.len());
+            let (chunk, rest) = self.bytes.split_at(mid);
+            self.bytes = rest;
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl FusedIterator for BytesChunks {}
+
+impl ExactSizeIterator for BytesChunks {
+    fn len(&self) -> usize {
+        self.bytes.len().div_ceil(self.size) as usize
+    }
+}
+
 /// BytesN is a contiguous fixed-size array type containing `u8`s.
 ///
 /// The array is stored in the Host and available to the Guest through the
@@ -1180,6 +1575,22 @@ impl<const N: usize> BytesN<N> {
         BytesN(Bytes::from_slice(env, items))
     }
 
+    /// Create a BytesN from the slice, if the slice has length N.
+    ///
+    /// Use [`from_array`][BytesN::from_array] when the length is known at
+    /// compile time.
+    ///
+    /// ### Errors
+    ///
+    /// If the length of the slice is not N.
+    #[inline(always)]
+    pub fn from_slice(env: &Env, items: &[u8]) -> Result<BytesN<N>, ConversionError> {
+        if items.len() != N {
+            return Err(ConversionError);
+        }
+        Ok(BytesN(Bytes::from_slice(env, items)))
+    }
+
     /// Sets the byte at the position with new value.
     ///
     /// ### Panics
@@ -1805,6 +2216,306 @@ mod test {
         let _ = bin.slice(..=bin.len());
     }
 
+    #[test]
+    fn test_split_at() {
+        let env = Env::default();
+        let bin = bytes![&env, [0, 1, 2, 3, 4]];
+
+        let (a, b) = bin.split_at(2);
+        assert_eq!(a, bytes![&env, [0, 1]]);
+        assert_eq!(b, bytes![&env, [2, 3, 4]]);
+
+        let (a, b) = bin.split_at(0);
+        assert_eq!(a, bytes![&env]);
+        assert_eq!(b, bin);
+
+        let (a, b) = bin.split_at(bin.len());
+        assert_eq!(a, bin);
+        assert_eq!(b, bytes![&env]);
+
+        assert_eq!(bin, bytes![&env, [0, 1, 2, 3, 4]]); // makes sure original bytes is unchanged
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Object, IndexBounds)")]
+    fn test_split_at_panic() {
+        let env = Env::default();
+        let bin = bytes![&env, [0, 1, 2, 3, 4]];
+        let _ = bin.split_at(6);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let env = Env::default();
+        let mut bin = bytes![&env, [0, 1, 2, 3, 4]];
+
+        let tail = bin.split_off(3);
+        assert_eq!(bin, bytes![&env, [0, 1, 2]]);
+        assert_eq!(tail, bytes![&env, [3, 4]]);
+
+        let tail = bin.split_off(bin.len());
+        assert_eq!(bin, bytes![&env, [0, 1, 2]]);
+        assert_eq!(tail, bytes![&env]);
+
+        let tail = bin.split_off(0);
+        assert_eq!(bin, bytes![&env]);
+        assert_eq!(tail, bytes![&env, [0, 1, 2]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Object, IndexBounds)")]
+    fn test_split_off_panic() {
+        let env = Env::default();
+        let mut bin = bytes![&env, [0, 1, 2, 3, 4]];
+        let _ = bin.split_off(6);
+    }
+
+    #[test]
+    fn test_concat() {
+        let env = Env::default();
+        let a = bytes![&env, [0, 1]];
+        let b = bytes![&env, [2]];
+        let c = bytes![&env];
+
+        assert_eq!(Bytes::concat(&env, &[]), bytes![&env]);
+        assert_eq!(Bytes::concat(&env, &[&a]), a);
+        assert_eq!(
+            Bytes::concat(&env, &[&a, &b, &c, &a]),
+            bytes![&env, [0, 1, 2, 0, 1]]
+        );
+    }
+
+    #[test]
+    fn test_repeat() {
+        let env = Env::default();
+
+        assert_eq!(Bytes::repeat(&env, 0xff, 0), bytes![&env]);
+        assert_eq!(
+            Bytes::repeat(&env, 0xff, 3),
+            bytes![&env, [0xff, 0xff, 0xff]]
+        );
+        assert_eq!(
+            Bytes::repeat(&env, 0, 32),
+            Bytes::from_array(&env, &[0; 32])
+        );
+
+        for count in [31, 33, 64, 65, 100, 10_000] {
+            let bin = Bytes::repeat(&env, 0xab, count);
+            assert_eq!(bin.len(), count);
+            assert_eq!(
+                bin,
+                Bytes::from_slice(&env, &std::vec![0xab; count as usize])
+            );
+        }
+    }
+
+    #[test]
+    fn test_builder() {
+        let env = Env::default();
+        let x = BytesN::from_array(&env, &[1; 32]);
+        let y = BytesN::from_array(&env, &[2; 32]);
+
+        let mut builder = BytesBuilder::new(&env);
+        assert!(builder.is_empty());
+        builder.push_bytesn(&x).push_bytesn(&y);
+        assert_eq!(builder.len(), 64);
+
+        // the result is one object holding X || Y, ready to be read back as a
+        // fixed-size value
+        let xy: BytesN<64> = builder.build().try_into().unwrap();
+        let mut expected = [1u8; 64];
+        expected[32..].copy_from_slice(&[2; 32]);
+        assert_eq!(xy.to_array(), expected);
+
+        builder.push_byte(3).push_bytes(&bytes![&env, [4, 5]]);
+        let built = builder.build();
+        assert_eq!(built.len(), 67);
+        assert_eq!(built.slice(64..), bytes![&env, [3, 4, 5]]);
+    }
+
+    #[test]
+    fn test_bytesn_from_slice() {
+        let env = Env::default();
+        let data = [1u8, 2, 3, 4, 5];
+
+        let b = BytesN::<4>::from_slice(&env, &data[..4]).unwrap();
+        assert_eq!(b, BytesN::from_array(&env, &[1, 2, 3, 4]));
+
+        assert_eq!(
+            BytesN::<4>::from_slice(&env, &data[..3]),
+            Err(ConversionError)
+        );
+        assert_eq!(BytesN::<4>::from_slice(&env, &data), Err(ConversionError));
+        assert_eq!(BytesN::<4>::from_slice(&env, &[]), Err(ConversionError));
+        assert_eq!(
+            BytesN::<0>::from_slice(&env, &[]).unwrap(),
+            BytesN::from_array(&env, &[])
+        );
+    }
+
+    #[test]
+    fn test_first_index_of() {
+        let env = Env::default();
+        let b = bytes!(&env, 0x0102030203);
+
+        assert_eq!(b.first_index_of(1), Some(0));
+        assert_eq!(b.first_index_of(2), Some(1));
+        assert_eq!(b.first_index_of(3), Some(2));
+        assert_eq!(b.first_index_of(4), None);
+        assert_eq!(Bytes::new(&env).first_index_of(0), None);
+    }
+
+    #[test]
+    fn test_index_of_slice() {
+        let env = Env::default();
+        let b = bytes!(&env, 0x0102030203);
+
+        assert_eq!(b.index_of_slice(&bytes!(&env, 0x0102)), Some(0));
+        assert_eq!(b.index_of_slice(&bytes!(&env, 0x0203)), Some(1));
+        assert_eq!(b.index_of_slice(&bytes!(&env, 0x0302)), Some(2));
+        assert_eq!(b.index_of_slice(&bytes!(&env, 0x030203)), Some(2));
+        assert_eq!(b.index_of_slice(&bytes!(&env, 0x020203)), None);
+        assert_eq!(b.index_of_slice(&bytes!(&env, 0x0203020301)), None);
+        assert_eq!(b.index_of_slice(&b), Some(0));
+
+        assert_eq!(b.index_of_slice(&Bytes::new(&env)), Some(0));
+        assert_eq!(Bytes::new(&env).index_of_slice(&Bytes::new(&env)), Some(0));
+        assert_eq!(Bytes::new(&env).index_of_slice(&bytes!(&env, 0x01)), None);
+    }
+
+    #[test]
+    fn test_chunks() {
+        let env = Env::default();
+        let b = bytes!(&env, 0x010203040506);
+
+        let chunks = b.chunks(2);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            chunks.collect::<std::vec::Vec<_>>(),
+            [
+                bytes!(&env, 0x0102),
+                bytes!(&env, 0x0304),
+                bytes!(&env, 0x0506)
+            ]
+        );
+
+        let chunks = b.chunks(4);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(
+            chunks.collect::<std::vec::Vec<_>>(),
+            [bytes!(&env, 0x01020304), bytes!(&env, 0x0506)]
+        );
+
+        assert_eq!(b.chunks(10).collect::<std::vec::Vec<_>>(), [b.clone()]);
+        assert_eq!(Bytes::new(&env).chunks(2).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_chunks_zero_size_panic() {
+        let env = Env::default();
+        let b = bytes!(&env, 0x0102);
+        let _ = b.chunks(0);
+    }
+
+    #[test]
+    fn test_hex() {
+        let env = Env::default();
+        let b = bytes!(&env, 0x0123456789abcdef);
+        assert_eq!(b.to_hex(), String::from_str(&env, "0123456789abcdef"));
+        assert_eq!(
+            Bytes::from_hex(&env, &String::from_str(&env, "0123456789ABCDEF")),
+            Ok(b)
+        );
+        assert_eq!(Bytes::new(&env).to_hex(), String::from_str(&env, ""));
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let env = Env::default();
+        // Lengths either side of the chunk sizes.
+        for len in 0..300u32 {
+            let b = Bytes::from_slice(
+                &env,
+                &(0..len)
+                    .map(|i| (i * 37 + 11) as u8)
+                    .collect::<std::vec::Vec<_>>(),
+            );
+            let hex = b.to_hex();
+            assert_eq!(hex.len(), 2 * len);
+            assert_eq!(Bytes::from_hex(&env, &hex), Ok(b));
+        }
+    }
+
+    #[test]
+    fn test_from_hex_invalid() {
+        let env = Env::default();
+        for s in ["0", "abc", "0g", "zz", " 00", "0x00"] {
+            assert_eq!(
+                Bytes::from_hex(&env, &String::from_str(&env, s)),
+                Err(ConversionError),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_base64() {
+        let env = Env::default();
+        // The test vectors from RFC 4648.
+        for (b, s) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            let b = Bytes::from_slice(&env, b.as_bytes());
+            let s = String::from_str(&env, s);
+            assert_eq!(b.to_base64(), s);
+            assert_eq!(Bytes::from_base64(&env, &s), Ok(b));
+        }
+        let b = bytes!(&env, 0xfbff);
+        assert_eq!(b.to_base64(), String::from_str(&env, "+/8="));
+    }
+
+    #[test]
+    fn test_base64_round_trip() {
+        let env = Env::default();
+        // Lengths either side of the chunk sizes.
+        for len in 0..300u32 {
+            let b = Bytes::from_slice(
+                &env,
+                &(0..len)
+                    .map(|i| (i * 37 + 11) as u8)
+                    .collect::<std::vec::Vec<_>>(),
+            );
+            let base64 = b.to_base64();
+            assert_eq!(base64.len(), len.div_ceil(3) * 4);
+            assert_eq!(Bytes::from_base64(&env, &base64), Ok(b));
+        }
+    }
+
+    #[test]
+    fn test_from_base64_invalid() {
+        let env = Env::default();
+        for s in [
+            // Not a multiple of 4.
+            "Zg=", "Zm9vY", // Not in the alphabet.
+            "Zm9v!A==", "Zm-_", // Padding other than at the end.
+            "Zg==Zm9v", "=Zg=", "Z===", // Non-zero bits discarded by padding.
+            "Zh==", "Zm9=",
+        ] {
+            assert_eq!(
+                Bytes::from_base64(&env, &String::from_str(&env, s)),
+                Err(ConversionError),
+                "{s}"
+            );
+        }
+    }
+
     #[test]
     fn test_bytes_to_string() {
         let env = Env::default();
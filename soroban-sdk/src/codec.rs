@@ -0,0 +1,5 @@
+//! Codec contains functions for encoding and decoding values in formats
+//! used by off-chain systems.
+
+pub mod cbor;
+pub mod json;
@@ -0,0 +1,315 @@
+//! CBOR contains functions for encoding values in the Concise Binary Object
+//! Representation (RFC 8949), and decoding them back into [`Val`]s.
+//!
+//! Only the deterministic subset of CBOR (RFC 8949, section 4.2) is encoded
+//! and decoded, so that every value has exactly one encoding:
+//!
+//! - Integers and lengths are encoded in the fewest bytes.
+//! - Strings, arrays, and maps have definite lengths.
+//! - Map keys are sorted in the bytewise order of their encodings, and are
+//!   unique.
+//!
+//! ### Types
+//!
+//! | Value | CBOR |
+//! |-------|------|
+//! | `bool` | `false`, `true` |
+//! | `()` | `null` |
+//! | `u32`, `u64`, `u128` | unsigned integer |
+//! | `i32`, `i64`, `i128` | unsigned or negative integer |
+//! | [`Bytes`] | byte string |
+//! | [`String`] | text string |
+//! | [`Vec`] | array |
+//! | [`Map`] | map |
+//!
+//! Integers must be in the range of a CBOR integer, `-2^64` to `2^64 - 1`.
+//! When decoded, non-negative integers are `u64`s and negative integers are
+//! `i64`s, so an integer may not decode to the type it was encoded from.
+//! Negative integers below `i64::MIN` can be encoded, but are rejected when
+//! decoded.
+//!
+//! Arrays and maps can be nested at most [`MAX_DEPTH`] levels deep in the
+//! values that are decoded.
+//!
+//! Other types, such as [`Symbol`][crate::Symbol] and
+//! [`Address`][crate::Address], and other CBOR types, such as floats and
+//! tags, are not supported.
+use crate::{
+    env::internal::Tag, Bytes, ConversionError, Env, IntoVal, Map, String, TryFromVal, Val, Vec,
+};
+
+const UNSIGNED: u8 = 0;
+const NEGATIVE: u8 = 1;
+const BYTES: u8 = 2;
+const TEXT: u8 = 3;
+const ARRAY: u8 = 4;
+const MAP: u8 = 5;
+const SIMPLE: u8 = 7;
+
+const FALSE: u8 = 20;
+const TRUE: u8 = 21;
+const NULL: u8 = 22;
+
+/// The maximum depth that arrays and maps can be nested to in a value passed
+/// to [`decode`].
+pub const MAX_DEPTH: u32 = 64;
+
+/// Encodes the value as deterministic CBOR.
+///
+/// ### Errors
+///
+/// If the value, or a value nested in it, is of an unsupported type, is an
+/// integer out of range, or is a [`String`] that is not valid UTF-8. If a
+/// [`Map`] has two keys with the same encoding, such as `1u32` and `1u64`.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{bytes, codec::cbor, map, vec, Env, String};
+///
+/// let env = Env::default();
+/// let value = map![&env, (String::from_str(&env, "a"), vec![&env, 1u32, 2u32])];
+/// assert_eq!(cbor::encode(&env, &value), Ok(bytes!(&env, 0xa16161820102)));
+/// ```
+pub fn encode(env: &Env, value: impl IntoVal<Env, Val>) -> Result<Bytes, ConversionError> {
+    let mut out = Bytes::new(env);
+    encode_into(env, &mut out, value.into_val(env))?;
+    Ok(out)
+}
+
+/// Decodes deterministic CBOR into a value.
+///
+/// See the [module documentation][self] for the types that are decoded.
+///
+/// ### Errors
+///
+/// If the bytes are not the deterministic CBOR encoding of a single
+/// supported value, or nest arrays and maps more than [`MAX_DEPTH`] levels
+/// deep.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{bytes, codec::cbor, Env, TryFromVal, Vec};
+///
+/// let env = Env::default();
+/// let value = cbor::decode(&env, &bytes!(&env, 0x820120)).unwrap();
+/// let value = Vec::<i64>::try_from_val(&env, &value).unwrap();
+/// assert_eq!(value.get(0), Some(1));
+/// assert_eq!(value.get(1), Some(-1));
+/// ```
+pub fn decode(env: &Env, bytes: &Bytes) -> Result<Val, ConversionError> {
+    let mut decoder = Decoder {
+        env,
+        bytes,
+        pos: 0,
+        depth: 0,
+    };
+    let value = decoder.item()?;
+    if decoder.pos != bytes.len() {
+        return Err(ConversionError);
+    }
+    Ok(value)
+}
+
+fn encode_into(env: &Env, out: &mut Bytes, value: Val) -> Result<(), ConversionError> {
+    match value.get_tag() {
+        Tag::False => write_head(out, SIMPLE, FALSE.into()),
+        Tag::True => write_head(out, SIMPLE, TRUE.into()),
+        Tag::Void => write_head(out, SIMPLE, NULL.into()),
+        Tag::U32Val => write_int(out, u32::try_from_val(env, &value)?.into())?,
+        Tag::I32Val => write_int(out, i32::try_from_val(env, &value)?.into())?,
+        Tag::U64Small | Tag::U64Object => {
+            write_head(out, UNSIGNED, u64::try_from_val(env, &value)?)
+        }
+        Tag::I64Small | Tag::I64Object => write_int(out, i64::try_from_val(env, &value)?.into())?,
+        Tag::U128Small | Tag::U128Object => {
+            let u = u128::try_from_val(env, &value)?;
+            write_head(out, UNSIGNED, u.try_into().map_err(|_| ConversionError)?)
+        }
+        Tag::I128Small | Tag::I128Object => write_int(out, i128::try_from_val(env, &value)?)?,
+        Tag::BytesObject => {
+            let b = Bytes::try_from_val(env, &value)?;
+            write_head(out, BYTES, b.len().into());
+            out.append(&b);
+        }
+        Tag::StringObject => {
+            let b = String::try_from_val(env, &value)?.to_bytes();
+            // Text strings must be valid UTF-8.
+            String::try_from_bytes(env, &b)?;
+            write_head(out, TEXT, b.len().into());
+            out.append(&b);
+        }
+        Tag::VecObject => {
+            let vec = Vec::<Val>::try_from_val(env, &value)?;
+            write_head(out, ARRAY, vec.len().into());
+            for v in vec.iter() {
+                encode_into(env, out, v)?;
+            }
+        }
+        Tag::MapObject => {
+            let map = Map::<Val, Val>::try_from_val(env, &value)?;
+            // Order the entries by their encoded keys, which is the order a
+            // map of bytes keeps its keys in.
+            let mut entries = Map::<Bytes, Bytes>::new(env);
+            for (k, v) in map.iter() {
+                let k = encode(env, k)?;
+                if entries.contains_key(k.clone()) {
+                    return Err(ConversionError);
+                }
+                entries.set(k, encode(env, v)?);
+            }
+            write_head(out, MAP, entries.len().into());
+            for (k, v) in entries.iter() {
+                out.append(&k);
+                out.append(&v);
+            }
+        }
+        _ => return Err(ConversionError),
+    }
+    Ok(())
+}
+
+// Writes the integer as an unsigned integer if it is non-negative, otherwise
+// as a negative integer.
+fn write_int(out: &mut Bytes, i: i128) -> Result<(), ConversionError> {
+    let (major, n) = if i >= 0 {
+        (UNSIGNED, i)
+    } else {
+        (NEGATIVE, -1 - i)
+    };
+    write_head(out, major, n.try_into().map_err(|_| ConversionError)?);
+    Ok(())
+}
+
+// Writes the head of an item, which is the major type and an argument in the
+// fewest bytes.
+fn write_head(out: &mut Bytes, major: u8, n: u64) {
+    let major = major << 5;
+    if n < 24 {
+        out.push_back(major | n as u8);
+    } else if n <= u8::MAX.into() {
+        out.extend_from_array(&[major | 24, n as u8]);
+    } else if n <= u16::MAX.into() {
+        out.push_back(major | 25);
+        out.extend_from_array(&(n as u16).to_be_bytes());
+    } else if n <= u32::MAX.into() {
+        out.push_back(major | 26);
+        out.extend_from_array(&(n as u32).to_be_bytes());
+    } else {
+        out.push_back(major | 27);
+        out.extend_from_array(&n.to_be_bytes());
+    }
+}
+
+struct Decoder<'a> {
+    env: &'a Env,
+    bytes: &'a Bytes,
+    pos: u32,
+    // The number of arrays and maps the item being decoded is nested in.
+    depth: u32,
+}
+
+impl Decoder<'_> {
+    fn item(&mut self) -> Result<Val, ConversionError> {
+        let (major, n) = self.head()?;
+        let env = self.env;
+        Ok(match major {
+            UNSIGNED => n.into_val(env),
+            NEGATIVE => {
+                let n: i64 = n.try_into().map_err(|_| ConversionError)?;
+                (-1 - n).into_val(env)
+            }
+            BYTES => self.take(n)?.into_val(env),
+            TEXT => String::try_from_bytes(env, &self.take(n)?)?.into_val(env),
+            ARRAY => {
+                self.enter()?;
+                let mut vec = Vec::<Val>::new(env);
+                for _ in 0..n {
+                    vec.push_back(self.item()?);
+                }
+                self.depth -= 1;
+                vec.into_val(env)
+            }
+            MAP => {
+                self.enter()?;
+                let mut map = Map::<Val, Val>::new(env);
+                let mut prev_key: Option<Bytes> = None;
+                for _ in 0..n {
+                    let start = self.pos;
+                    let k = self.item()?;
+                    // Keys must be in strictly increasing order of their
+                    // encodings, which also means they are unique.
+                    let key = self.bytes.slice(start..self.pos);
+                    if prev_key.is_some_and(|prev| prev >= key) {
+                        return Err(ConversionError);
+                    }
+                    prev_key = Some(key);
+                    map.set(k, self.item()?);
+                }
+                self.depth -= 1;
+                map.into_val(env)
+            }
+            SIMPLE => match n {
+                n if n == FALSE.into() => false.into_val(env),
+                n if n == TRUE.into() => true.into_val(env),
+                n if n == NULL.into() => ().into_val(env),
+                _ => return Err(ConversionError),
+            },
+            _ => return Err(ConversionError),
+        })
+    }
+
+    // Enters an array or map, failing if that nests it too deep.
+    fn enter(&mut self) -> Result<(), ConversionError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(ConversionError);
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    // Reads the head of an item, and returns its major type and argument.
+    fn head(&mut self) -> Result<(u8, u64), ConversionError> {
+        let initial = self.byte()?;
+        let major = initial >> 5;
+        let info = initial & 0x1f;
+        let n = match info {
+            0..=23 => return Ok((major, info.into())),
+            24 => self.byte()?.into(),
+            25 => u16::from_be_bytes(self.array()?).into(),
+            26 => u32::from_be_bytes(self.array()?).into(),
+            27 => u64::from_be_bytes(self.array()?),
+            // Reserved, and indefinite lengths.
+            _ => return Err(ConversionError),
+        };
+        // The argument must be in the fewest bytes. Simple values other than
+        // those supported are rejected by the caller.
+        let min = [24, 0x100, 0x1_0000, 0x1_0000_0000][info as usize - 24];
+        if n < min {
+            return Err(ConversionError);
+        }
+        Ok((major, n))
+    }
+
+    fn byte(&mut self) -> Result<u8, ConversionError> {
+        let b = self.bytes.get(self.pos).ok_or(ConversionError)?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], ConversionError> {
+        self.take(N as u64)?.try_into()
+    }
+
+    fn take(&mut self, n: u64) -> Result<Bytes, ConversionError> {
+        let end = u32::try_from(n)
+            .ok()
+            .and_then(|n| self.pos.checked_add(n))
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(ConversionError)?;
+        let b = self.bytes.slice(self.pos..end);
+        self.pos = end;
+        Ok(b)
+    }
+}
@@ -0,0 +1,248 @@
+//! JSON contains functions for writing values as JSON (RFC 8259), such as for
+//! returning them to off-chain systems that display or index them.
+//!
+//! Values are written deterministically, so that every value has exactly one
+//! JSON text, with no whitespace, and with the members of objects sorted.
+//!
+//! ### Types
+//!
+//! | Value | JSON |
+//! |-------|------|
+//! | `bool` | `false`, `true` |
+//! | `()` | `null` |
+//! | `u32`, `i32`, `u64`, `i64`, `u128`, `i128` | number |
+//! | [`Timepoint`], [`Duration`] | number |
+//! | [`U256`], [`I256`] | string of the decimal number |
+//! | [`Bytes`] | string of the base64 encoding |
+//! | [`String`], [`Symbol`] | string |
+//! | [`Address`] | string of the strkey |
+//! | [`Vec`] | array |
+//! | [`Map`] | object |
+//!
+//! Integers are written in decimal. [`U256`] and [`I256`] are written as
+//! strings, as many JSON parsers lose precision on numbers that large.
+//!
+//! [`Bytes`] are encoded as base64, with the standard alphabet and padding of
+//! RFC 4648.
+//!
+//! The keys of a [`Map`] are written as strings. A key that is written as a
+//! JSON string is used as is, and a key of any other type is used as a string
+//! of its JSON text, so that the key `1u32` is written as `"1"`. The members
+//! of an object are sorted by the bytes of their encoded keys.
+//!
+//! Other types, such as [`Error`][crate::Error], are not supported.
+use crate::{
+    env::internal::{SymbolStr, Tag},
+    unwrap::UnwrapOptimized,
+    Address, Bytes, Duration, Env, Map, String, Symbol, Timepoint, TryFromVal, Val, Vec, I256,
+    U256,
+};
+
+/// Writes the value as JSON.
+///
+/// See the [module documentation][self] for how each type is written.
+///
+/// ### Panics
+///
+/// If the value, or a value nested in it, is of an unsupported type, or is a
+/// [`String`] that is not valid UTF-8. If two keys of a [`Map`] are written
+/// as the same string, such as `1u32` and `1u64`.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{bytes, codec::json, map, symbol_short, vec, Env, IntoVal, String};
+///
+/// let env = Env::default();
+/// let value = map![
+///     &env,
+///     (symbol_short!("to"), vec![&env, 1u32, 2u32].to_val()),
+///     (symbol_short!("data"), bytes!(&env, 0x0102).to_val()),
+///     (symbol_short!("amount"), 100i128.into_val(&env)),
+/// ];
+/// assert_eq!(
+///     json::to_json(&env, &value.to_val()),
+///     String::from_str(&env, r#"{"amount":100,"data":"AQI=","to":[1,2]}"#),
+/// );
+/// ```
+pub fn to_json(env: &Env, val: &Val) -> String {
+    let mut out = Bytes::new(env);
+    write_value(env, &mut out, *val);
+    out.to_string()
+}
+
+fn write_value(env: &Env, out: &mut Bytes, val: Val) {
+    match val.get_tag() {
+        Tag::False => out.extend_from_slice(b"false"),
+        Tag::True => out.extend_from_slice(b"true"),
+        Tag::Void => out.extend_from_slice(b"null"),
+        Tag::U32Val => write_unsigned(out, convert::<u32>(env, val).into()),
+        Tag::I32Val => write_signed(out, convert::<i32>(env, val).into()),
+        Tag::U64Small | Tag::U64Object => write_unsigned(out, convert::<u64>(env, val).into()),
+        Tag::I64Small | Tag::I64Object => write_signed(out, convert::<i64>(env, val).into()),
+        Tag::TimepointSmall | Tag::TimepointObject => {
+            write_unsigned(out, convert::<Timepoint>(env, val).to_unix().into())
+        }
+        Tag::DurationSmall | Tag::DurationObject => {
+            write_unsigned(out, convert::<Duration>(env, val).to_seconds().into())
+        }
+        Tag::U128Small | Tag::U128Object => write_unsigned(out, convert::<u128>(env, val)),
+        Tag::I128Small | Tag::I128Object => write_signed(out, convert::<i128>(env, val)),
+        Tag::U256Small | Tag::U256Object => {
+            out.push_back(b'"');
+            out.append(&convert::<U256>(env, val).to_decimal_string().to_bytes());
+            out.push_back(b'"');
+        }
+        Tag::I256Small | Tag::I256Object => {
+            out.push_back(b'"');
+            out.append(&convert::<I256>(env, val).to_decimal_string().to_bytes());
+            out.push_back(b'"');
+        }
+        Tag::BytesObject => {
+            out.push_back(b'"');
+            out.append(&convert::<Bytes>(env, val).to_base64().to_bytes());
+            out.push_back(b'"');
+        }
+        Tag::StringObject => {
+            let b = convert::<String>(env, val).to_bytes();
+            // Strings must be valid UTF-8.
+            if String::try_from_bytes(env, &b).is_err() {
+                sdk_panic!("string is not valid utf-8");
+            }
+            write_string(out, &b);
+        }
+        Tag::SymbolSmall | Tag::SymbolObject => {
+            let s = convert::<Symbol>(env, val);
+            let s = SymbolStr::try_from_val(env, &s.to_symbol_val()).unwrap_optimized();
+            // Symbols are made only of characters that need no escaping.
+            out.push_back(b'"');
+            out.extend_from_slice(AsRef::<[u8]>::as_ref(&s));
+            out.push_back(b'"');
+        }
+        Tag::AddressObject => {
+            out.push_back(b'"');
+            out.append(&convert::<Address>(env, val).to_string().to_bytes());
+            out.push_back(b'"');
+        }
+        Tag::VecObject => {
+            out.push_back(b'[');
+            for (i, v) in convert::<Vec<Val>>(env, val).iter().enumerate() {
+                if i > 0 {
+                    out.push_back(b',');
+                }
+                write_value(env, out, v);
+            }
+            out.push_back(b']');
+        }
+        Tag::MapObject => {
+            // Order the members by their encoded keys, which is the order a
+            // map of bytes keeps its keys in.
+            let mut members = Map::<Bytes, Bytes>::new(env);
+            for (k, v) in convert::<Map<Val, Val>>(env, val).iter() {
+                let mut key = Bytes::new(env);
+                write_value(env, &mut key, k);
+                if key.first() != Some(b'"') {
+                    let text = key;
+                    key = Bytes::new(env);
+                    write_string(&mut key, &text);
+                }
+                if members.contains_key(key.clone()) {
+                    sdk_panic!("map has two keys written as the same string");
+                }
+                let mut value = Bytes::new(env);
+                write_value(env, &mut value, v);
+                members.set(key, value);
+            }
+            out.push_back(b'{');
+            for (i, (k, v)) in members.iter().enumerate() {
+                if i > 0 {
+                    out.push_back(b',');
+                }
+                out.append(&k);
+                out.push_back(b':');
+                out.append(&v);
+            }
+            out.push_back(b'}');
+        }
+        _ => sdk_panic!("value of an unsupported type"),
+    }
+}
+
+fn convert<T>(env: &Env, val: Val) -> T
+where
+    T: TryFromVal<Env, Val>,
+    T::Error: core::fmt::Debug,
+{
+    T::try_from_val(env, &val).unwrap_optimized()
+}
+
+// Writes the bytes as a string, escaping the quotation mark, reverse solidus,
+// and control characters. The bytes are copied into the guest in fixed size
+// chunks to be escaped.
+fn write_string(out: &mut Bytes, b: &Bytes) {
+    const CHUNK_SIZE: u32 = 64;
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut buf = [0u8; CHUNK_SIZE as usize];
+    // Room for every byte to be escaped as `\u00XX`.
+    let mut escaped = [0u8; 6 * CHUNK_SIZE as usize];
+    out.push_back(b'"');
+    for chunk in b.chunks(CHUNK_SIZE) {
+        let n = chunk.len() as usize;
+        chunk.copy_into_slice(&mut buf[..n]);
+        let mut len = 0;
+        for &c in &buf[..n] {
+            let short = match c {
+                b'"' => b'"',
+                b'\\' => b'\\',
+                b'\n' => b'n',
+                b'\r' => b'r',
+                b'\t' => b't',
+                0x08 => b'b',
+                0x0c => b'f',
+                0x00..=0x1f => {
+                    escaped[len..len + 6].copy_from_slice(&[
+                        b'\\',
+                        b'u',
+                        b'0',
+                        b'0',
+                        HEX_DIGITS[(c >> 4) as usize],
+                        HEX_DIGITS[(c & 0xf) as usize],
+                    ]);
+                    len += 6;
+                    continue;
+                }
+                _ => {
+                    escaped[len] = c;
+                    len += 1;
+                    continue;
+                }
+            };
+            escaped[len..len + 2].copy_from_slice(&[b'\\', short]);
+            len += 2;
+        }
+        out.extend_from_slice(&escaped[..len]);
+    }
+    out.push_back(b'"');
+}
+
+fn write_signed(out: &mut Bytes, i: i128) {
+    if i < 0 {
+        out.push_back(b'-');
+    }
+    write_unsigned(out, i.unsigned_abs());
+}
+
+fn write_unsigned(out: &mut Bytes, mut u: u128) {
+    // Room for the 39 digits of `u128::MAX`.
+    let mut buf = [0u8; 39];
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (u % 10) as u8;
+        u /= 10;
+        if u == 0 {
+            break;
+        }
+    }
+    out.extend_from_slice(&buf[start..]);
+}
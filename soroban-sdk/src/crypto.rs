@@ -3,17 +3,20 @@
 use crate::{
     env::internal::{self, BytesObject},
     unwrap::UnwrapInfallible,
-    Bytes, BytesN, ConversionError, Env, IntoVal, TryFromVal, Val,
+    Bytes, BytesN, ConversionError, Env, IntoVal, TryFromVal, Val, Vec,
 };
 
 pub mod bls12_381;
 pub mod bn254;
+pub mod merkle;
+mod sha3;
 /// A `BytesN<N>` generated by a cryptographic hash function.
 ///
 /// The `Hash<N>` type contains a `BytesN<N>` and can only be constructed in
 /// contexts where the value has been generated by a secure cryptographic
 /// function. As a result, the type is only found as a return value of calling
-/// [`sha256`][Crypto::sha256], [`keccak256`][Crypto::keccak256], or via
+/// [`sha256`][Crypto::sha256], [`keccak256`][Crypto::keccak256],
+/// [`sha3_256`][Crypto::sha3_256], or via
 /// implementing [`CustomAccountInterface`][crate::auth::CustomAccountInterface]
 /// since the `__check_auth` is guaranteed to receive a hash from a secure
 /// cryptographic hash function as its first parameter.
@@ -131,6 +134,132 @@ impl Crypto {
         unsafe { Hash(BytesN::unchecked_new(env.clone(), bin)) }
     }
 
+    /// Returns the SHA3-256 hash of the data, as standardized by NIST in
+    /// FIPS 202.
+    ///
+    /// **__Note:__** SHA3-256 is not the same function as
+    /// [`keccak256`][Crypto::keccak256]. Ethereum's "sha3" is Keccak-256,
+    /// which predates the standard and pads its input differently, so the two
+    /// produce different hashes for the same data.
+    ///
+    /// The host does not provide SHA3-256, so the hash is computed in the
+    /// contract and costs more than the other hash functions in this module.
+    pub fn sha3_256(&self, data: &Bytes) -> Hash<32> {
+        let env = self.env();
+        let hash = sha3::sha3_256(data);
+        Hash(BytesN::from_array(env, &hash))
+    }
+
+    /// Returns a [Keccak256] hasher for hashing data incrementally.
+    ///
+    /// The hash returned by [`finalize`][Keccak256::finalize] is identical to
+    /// calling [`keccak256`][Crypto::keccak256] with the concatenation of all
+    /// the data passed to [`update`][Keccak256::update].
+    pub fn keccak256_init(&self) -> Keccak256 {
+        Keccak256 {
+            buf: Bytes::new(self.env()),
+        }
+    }
+
+    /// Returns the HMAC-SHA256 of the message with the key, as specified in
+    /// RFC 2104.
+    ///
+    /// Keys longer than the 64-byte block size of SHA-256 are hashed first,
+    /// and shorter keys are padded with zeros.
+    ///
+    /// Compare a computed HMAC with one provided by the invoker using
+    /// [`constant_time_eq`][Crypto::constant_time_eq].
+    pub fn hmac_sha256(&self, key: &Bytes, msg: &Bytes) -> BytesN<32> {
+        const BLOCK_SIZE: usize = 64;
+        let env = self.env();
+        let mut block = [0u8; BLOCK_SIZE];
+        if key.len() as usize > BLOCK_SIZE {
+            block[..32].copy_from_slice(&self.sha256(key).to_array());
+        } else {
+            key.copy_into_slice(&mut block[..key.len() as usize]);
+        }
+
+        let mut inner_pad = block;
+        for b in inner_pad.iter_mut() {
+            *b ^= 0x36;
+        }
+        let mut inner = Bytes::from_array(env, &inner_pad);
+        inner.append(msg);
+        let inner_hash = self.sha256(&inner);
+
+        let mut outer_pad = block;
+        for b in outer_pad.iter_mut() {
+            *b ^= 0x5c;
+        }
+        let mut outer = Bytes::from_array(env, &outer_pad);
+        outer.append(&Bytes::from(inner_hash));
+        self.sha256(&outer).to_bytes()
+    }
+
+    /// Derives `length` bytes of key material from the input key material
+    /// with HKDF-SHA256, as specified in RFC 5869.
+    ///
+    /// The input key material is extracted into a pseudorandom key with
+    /// [`hmac_sha256`][Crypto::hmac_sha256] keyed by the salt, which is then
+    /// expanded into the output with the info. An empty salt is the same as
+    /// the 32 zero bytes the spec uses when no salt is provided. Different
+    /// info derives independent keys from the same input key material.
+    ///
+    /// ### Panics
+    ///
+    /// If `length` is greater than 8160, which is 255 times the 32 byte hash
+    /// length.
+    pub fn hkdf_sha256(&self, salt: &Bytes, ikm: &Bytes, info: &Bytes, length: u32) -> Bytes {
+        const HASH_LEN: u32 = 32;
+        const MAX_LENGTH: u32 = 255 * HASH_LEN;
+        if length > MAX_LENGTH {
+            sdk_panic!("hkdf_sha256 length is greater than 8160");
+        }
+        let env = self.env();
+        let prk = Bytes::from(self.hmac_sha256(salt, ikm));
+
+        let mut okm = Bytes::new(env);
+        let mut t = Bytes::new(env);
+        for i in 1..=length.div_ceil(HASH_LEN) {
+            t.append(info);
+            t.push_back(i as u8);
+            t = self.hmac_sha256(&prk, &t).into();
+            okm.append(&t);
+        }
+        okm.slice(..length)
+    }
+
+    /// Returns true if `a` and `b` contain the same bytes, comparing them in
+    /// time that does not depend on where they differ.
+    ///
+    /// Use it rather than `==` when comparing a secret value, such as a MAC or
+    /// a preimage, with a value provided by the invoker, so that the time
+    /// taken does not reveal how many leading bytes were correct. The lengths
+    /// are not secret, and inputs of different lengths return false without
+    /// comparing the bytes.
+    ///
+    /// **__Note:__** The comparison does not exit early, but Wasm runtimes
+    /// make no timing guarantees, so this documents intent rather than
+    /// guaranteeing constant time execution.
+    pub fn constant_time_eq(&self, a: &Bytes, b: &Bytes) -> bool {
+        const CHUNK_SIZE: u32 = 64;
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut buf_a = [0u8; CHUNK_SIZE as usize];
+        let mut buf_b = [0u8; CHUNK_SIZE as usize];
+        let mut diff = 0u8;
+        for (chunk_a, chunk_b) in a.chunks(CHUNK_SIZE).zip(b.chunks(CHUNK_SIZE)) {
+            let n = chunk_a.len() as usize;
+            chunk_a.copy_into_slice(&mut buf_a[..n]);
+            chunk_b.copy_into_slice(&mut buf_b[..n]);
+            for (x, y) in buf_a[..n].iter().zip(&buf_b[..n]) {
+                diff = core::hint::black_box(diff | (x ^ y));
+            }
+        }
+        diff == 0
+    }
+
     /// Verifies an ed25519 signature.
     ///
     /// The signature is verified as a valid signature of the message by the
@@ -149,6 +278,33 @@ impl Crypto {
         );
     }
 
+    /// Verifies a batch of ed25519 signatures.
+    ///
+    /// Each signature is verified as a valid signature of the message at the
+    /// same index by the ed25519 public key at the same index.
+    ///
+    /// ### Panics
+    ///
+    /// If the public keys, messages and signatures are not all the same
+    /// length, or if any signature verification fails.
+    pub fn ed25519_verify_batch(
+        &self,
+        public_keys: &Vec<BytesN<32>>,
+        messages: &Vec<Bytes>,
+        signatures: &Vec<BytesN<64>>,
+    ) {
+        if public_keys.len() != messages.len() || public_keys.len() != signatures.len() {
+            sdk_panic!("ed25519_verify_batch with mismatched lengths");
+        }
+        for ((public_key, message), signature) in public_keys
+            .iter()
+            .zip(messages.iter())
+            .zip(signatures.iter())
+        {
+            self.ed25519_verify(&public_key, &message, &signature);
+        }
+    }
+
     /// Recovers the ECDSA secp256k1 public key.
     ///
     /// The public key returned is the SEC-1-encoded ECDSA secp256k1 public key
@@ -164,10 +320,52 @@ impl Crypto {
         CryptoHazmat::new(env).secp256k1_recover(&message_digest.0, signature, recorvery_id)
     }
 
+    /// Recovers the Ethereum address of the ECDSA secp256k1 public key.
+    ///
+    /// The public key is recovered as with
+    /// [`secp256k1_recover`][Crypto::secp256k1_recover], and the address
+    /// derived from it as with [`secp256k1_eth_address`][Crypto::secp256k1_eth_address].
+    pub fn secp256k1_recover_eth_address(
+        &self,
+        message_digest: &Hash<32>,
+        signature: &BytesN<64>,
+        recovery_id: u32,
+    ) -> BytesN<20> {
+        let public_key = self.secp256k1_recover(message_digest, signature, recovery_id);
+        self.secp256k1_eth_address(&public_key)
+    }
+
+    /// Returns the Ethereum address of the ECDSA secp256k1 public key.
+    ///
+    /// The public key must be SEC-1-encoded and uncompressed, as returned by
+    /// [`secp256k1_recover`][Crypto::secp256k1_recover]. The address is the
+    /// last 20 bytes of the Keccak-256 hash of the public key's 64-byte x and
+    /// y coordinates.
+    pub fn secp256k1_eth_address(&self, public_key: &BytesN<65>) -> BytesN<20> {
+        let env = self.env();
+        let coordinates = Bytes::from(public_key.clone()).slice(1..);
+        let hash = Bytes::from(self.keccak256(&coordinates));
+        let address = hash.slice(12..);
+        unsafe { BytesN::unchecked_new(env.clone(), address.to_object()) }
+    }
+
     /// Verifies the ECDSA secp256r1 signature.
     ///
     /// The SEC-1-encoded public key is provided along with the message,
     /// verifies the 64-byte signature.
+    ///
+    /// The public key must be in the 65-byte uncompressed form, a `0x04`
+    /// prefix followed by the 32-byte big-endian x and y coordinates. The
+    /// signature is the 32-byte big-endian `r` followed by the 32-byte
+    /// big-endian `s`, and `s` must be in the lower half of the curve order.
+    /// Signatures produced by WebAuthn authenticators are DER-encoded and must
+    /// be converted to this form, and normalized to a low `s`, before being
+    /// verified.
+    ///
+    /// ### Panics
+    ///
+    /// If the public key or signature are malformed, or if the signature
+    /// verification fails.
     pub fn secp256r1_verify(
         &self,
         public_key: &BytesN<65>,
@@ -190,6 +388,48 @@ impl Crypto {
     }
 }
 
+/// Keccak256 hashes data incrementally.
+///
+/// Created with [`Crypto::keccak256_init`]. The host does not provide an
+/// incremental hash function, so updates are accumulated into a buffer in the
+/// host and hashed once on [`finalize`][Keccak256::finalize].
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{Bytes, Env};
+///
+/// # let env = Env::default();
+/// let mut hasher = env.crypto().keccak256_init();
+/// hasher.update(&Bytes::from_slice(&env, b"hello "));
+/// hasher.update(&Bytes::from_slice(&env, b"world"));
+/// let hash = hasher.finalize();
+///
+/// assert_eq!(
+///     hash.to_bytes(),
+///     env.crypto()
+///         .keccak256(&Bytes::from_slice(&env, b"hello world"))
+///         .to_bytes()
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Keccak256 {
+    buf: Bytes,
+}
+
+impl Keccak256 {
+    /// Adds the data to the input being hashed.
+    pub fn update(&mut self, data: &Bytes) {
+        self.buf.append(data);
+    }
+
+    /// Returns the Keccak-256 hash of all data added with
+    /// [`update`][Keccak256::update].
+    pub fn finalize(self) -> Hash<32> {
+        self.buf.env().crypto().keccak256(&self.buf)
+    }
+}
+
 /// # ⚠️ Hazardous Materials
 ///
 /// Cryptographic functions under [CryptoHazmat] are low-leveled which can be
@@ -1,3 +1,4 @@
+use super::bls12_381::BigInt;
 #[cfg(not(target_family = "wasm"))]
 use crate::xdr::ScVal;
 use crate::{
@@ -6,7 +7,10 @@ use crate::{
     unwrap::{UnwrapInfallible, UnwrapOptimized},
     Bytes, BytesN, ConversionError, Env, IntoVal, TryFromVal, Val, Vec, U256,
 };
-use core::{cmp::Ordering, fmt::Debug};
+use core::{cmp::Ordering, fmt::Debug, ops::Neg};
+use field::{Fq, Fq2};
+
+mod field;
 
 // BN254 (aka altbn128) serialization sizes (uncompressed)
 pub const FP_SERIALIZED_SIZE: usize = 32;
@@ -14,14 +18,89 @@ pub const FP2_SERIALIZED_SIZE: usize = FP_SERIALIZED_SIZE * 2;
 pub const G1_SERIALIZED_SIZE: usize = FP_SERIALIZED_SIZE * 2; // X || Y
 pub const G2_SERIALIZED_SIZE: usize = FP2_SERIALIZED_SIZE * 2; // X(c1||c0) || Y(c1||c0)
 
+// BN254 compressed serialization sizes, only the X coordinate is stored
+pub const G1_COMPRESSED_SIZE: usize = FP_SERIALIZED_SIZE; // X
+pub const G2_COMPRESSED_SIZE: usize = FP2_SERIALIZED_SIZE; // X(c1||c0)
+
+// Bytes per field element produced by hash_to_field, `L = ceil((254 + 128) / 8)`
+const HASH_TO_FIELD_SIZE: usize = 48;
+
+// The field modulus is 254 bits, leaving the top two bits of a compressed
+// point's first byte free for flags.
+const COMPRESSED_SIGN_FLAG: u8 = 0x80;
+const COMPRESSED_INFINITY_FLAG: u8 = 0x40;
+const COMPRESSED_FLAGS_MASK: u8 = COMPRESSED_SIGN_FLAG | COMPRESSED_INFINITY_FLAG;
+
+// BN254 base field modulus
+const BN254_FP_MODULUS: [u64; 4] = [
+    4332616871279656263,
+    10917124144477883021,
+    13281191951274694749,
+    3486998266802970665,
+];
+
+// BN254 scalar field modulus `r`, as big-endian u64 pieces.
+const BN254_FR_MODULUS: [u64; 4] = [
+    0x30644e72e131a029,
+    0xb85045b68181585d,
+    0x2833e84879b97091,
+    0x43e1f593f0000001,
+];
+
+// The standard generator of G1, (1, 2)
+const G1_GENERATOR: [u8; G1_SERIALIZED_SIZE] = {
+    let mut g = [0u8; G1_SERIALIZED_SIZE];
+    g[FP_SERIALIZED_SIZE - 1] = 1;
+    g[G1_SERIALIZED_SIZE - 1] = 2;
+    g
+};
+
+// The standard generator of G2, as defined in EIP-197
+const G2_GENERATOR: [u8; G2_SERIALIZED_SIZE] = [
+    0x19, 0x8e, 0x93, 0x93, 0x92, 0x0d, 0x48, 0x3a, 0x72, 0x60, 0xbf, 0xb7, 0x31, 0xfb, 0x5d, 0x25,
+    0xf1, 0xaa, 0x49, 0x33, 0x35, 0xa9, 0xe7, 0x12, 0x97, 0xe4, 0x85, 0xb7, 0xae, 0xf3, 0x12, 0xc2,
+    0x18, 0x00, 0xde, 0xef, 0x12, 0x1f, 0x1e, 0x76, 0x42, 0x6a, 0x00, 0x66, 0x5e, 0x5c, 0x44, 0x79,
+    0x67, 0x43, 0x22, 0xd4, 0xf7, 0x5e, 0xda, 0xdd, 0x46, 0xde, 0xbd, 0x5c, 0xd9, 0x92, 0xf6, 0xed,
+    0x09, 0x06, 0x89, 0xd0, 0x58, 0x5f, 0xf0, 0x75, 0xec, 0x9e, 0x99, 0xad, 0x69, 0x0c, 0x33, 0x95,
+    0xbc, 0x4b, 0x31, 0x33, 0x70, 0xb3, 0x8e, 0xf3, 0x55, 0xac, 0xda, 0xdc, 0xd1, 0x22, 0x97, 0x5b,
+    0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71, 0x80, 0x8d, 0xcb, 0x40, 0x8f,
+    0xe3, 0xd1, 0xe7, 0x69, 0x0c, 0x43, 0xd3, 0x7b, 0x4c, 0xe6, 0xcc, 0x01, 0x66, 0xfa, 0x7d, 0xaa,
+];
+
 pub struct Bn254 {
     env: Env,
 }
 
+/// `G1Affine` is a point in the G1 group of the BN254 elliptic curve
+///
+/// # Serialization:
+/// - The 64 bytes represent the **uncompressed encoding** of a point in G1. The
+///   bytes consist of `be_bytes(X) || be_bytes(Y)` (`||` is concatenation),
+///   where 'X' and 'Y' are the two coordinates, each being a base field element
+///   `Fp`.
+/// - The point at infinity is encoded as all zero bytes.
+///
+/// # Equality:
+/// - Affine encodings are unique, so two points are equal if and only if their
+///   bytes are equal. This includes the point at infinity, which has a single
+///   encoding.
 #[derive(Clone)]
 #[repr(transparent)]
 pub struct G1Affine(BytesN<G1_SERIALIZED_SIZE>);
 
+/// `G2Affine` is a point in the G2 group of the BN254 elliptic curve
+///
+/// # Serialization:
+/// - The 128 bytes represent the **uncompressed encoding** of a point in G2.
+///   The bytes consist of `be_bytes(X_c1) || be_bytes(X_c0) || be_bytes(Y_c1)
+///   || be_bytes(Y_c0)` (`||` is concatenation), where 'X' and 'Y' are the two
+///   coordinates, each being an extension field element `Fp2` and `c0`, `c1`
+///   are components of `Fp2` (each being `Fp`).
+/// - The point at infinity is encoded as all zero bytes.
+///
+/// # Equality:
+/// - As with [`G1Affine`], two points are equal if and only if their bytes are
+///   equal.
 #[derive(Clone)]
 #[repr(transparent)]
 pub struct G2Affine(BytesN<G2_SERIALIZED_SIZE>);
@@ -43,13 +122,384 @@ impl_bytesn_repr!(G2Affine, G2_SERIALIZED_SIZE);
 impl_bytesn_repr!(Fp, FP_SERIALIZED_SIZE);
 impl_bytesn_repr!(Fp2, FP2_SERIALIZED_SIZE);
 
+impl Fp {
+    pub fn env(&self) -> &Env {
+        self.0.env()
+    }
+
+    // For an element a ∈ Fp, its negation `-a` is defined as:
+    //   a + (-a) = 0 (mod p)
+    // where `p` is the field modulus. Returns None if `a` is not within the
+    // field range (i.e., 0 ≤ a < p).
+    fn checked_neg(&self) -> Option<Fp> {
+        let fp_bigint: BigInt<4> = (&self.0).into();
+        if fp_bigint.is_zero() {
+            return Some(self.clone());
+        }
+
+        let mut res = BigInt(BN254_FP_MODULUS);
+        let borrow = res.sub_with_borrow(&fp_bigint);
+        if borrow {
+            return None;
+        }
+
+        let mut bytes = [0u8; FP_SERIALIZED_SIZE];
+        res.copy_into_array(&mut bytes);
+        Some(Fp::from_array(self.env(), &bytes))
+    }
+}
+
+impl Neg for &Fp {
+    type Output = Fp;
+
+    fn neg(self) -> Self::Output {
+        match self.checked_neg() {
+            Some(v) => v,
+            None => sdk_panic!("invalid input - Fp is larger than the field modulus"),
+        }
+    }
+}
+
+impl Neg for Fp {
+    type Output = Fp;
+
+    fn neg(self) -> Self::Output {
+        (&self).neg()
+    }
+}
+
+impl Fp2 {
+    pub fn env(&self) -> &Env {
+        self.0.env()
+    }
+
+    // An Fp2 element is represented as c0 + c1 * u, its negation is
+    // (-c0) + (-c1) * u.
+    fn checked_neg(&self) -> Option<Fp2> {
+        let mut inner = self.to_array();
+        let mut slice0 = [0; FP_SERIALIZED_SIZE];
+        let mut slice1 = [0; FP_SERIALIZED_SIZE];
+        slice0.copy_from_slice(&inner[0..FP_SERIALIZED_SIZE]);
+        slice1.copy_from_slice(&inner[FP_SERIALIZED_SIZE..FP2_SERIALIZED_SIZE]);
+
+        let neg_c0 = Fp::from_array(self.env(), &slice0).checked_neg()?;
+        let neg_c1 = Fp::from_array(self.env(), &slice1).checked_neg()?;
+
+        inner[0..FP_SERIALIZED_SIZE].copy_from_slice(&neg_c0.to_array());
+        inner[FP_SERIALIZED_SIZE..FP2_SERIALIZED_SIZE].copy_from_slice(&neg_c1.to_array());
+        Some(Fp2::from_array(self.env(), &inner))
+    }
+}
+
+impl Neg for &Fp2 {
+    type Output = Fp2;
+
+    fn neg(self) -> Self::Output {
+        match self.checked_neg() {
+            Some(v) => v,
+            None => sdk_panic!("invalid input - Fp2 component is larger than the field modulus"),
+        }
+    }
+}
+
+impl Neg for Fp2 {
+    type Output = Fp2;
+
+    fn neg(self) -> Self::Output {
+        (&self).neg()
+    }
+}
+
+impl G1Affine {
+    pub fn env(&self) -> &Env {
+        self.0.env()
+    }
+    /// Returns the point at infinity, encoded as all-zero bytes.
+    pub fn identity(env: &Env) -> Self {
+        Self::from_array(env, &[0u8; G1_SERIALIZED_SIZE])
+    }
+    /// Returns the standard generator of G1.
+    pub fn generator(env: &Env) -> Self {
+        Self::from_array(env, &G1_GENERATOR)
+    }
+    pub fn is_identity(&self) -> bool {
+        self.to_array() == [0u8; G1_SERIALIZED_SIZE]
+    }
+
+    /// Serializes the point in compressed form: the big-endian X coordinate,
+    /// with the most significant bit set if Y is odd. The point at infinity
+    /// is encoded with only the second most significant bit set.
+    pub fn to_compressed(&self) -> BytesN<G1_COMPRESSED_SIZE> {
+        let mut res = [0u8; G1_COMPRESSED_SIZE];
+        if self.is_identity() {
+            res[0] = COMPRESSED_INFINITY_FLAG;
+        } else {
+            let inner = self.to_array();
+            res.copy_from_slice(&inner[..FP_SERIALIZED_SIZE]);
+            if inner[G1_SERIALIZED_SIZE - 1] & 1 == 1 {
+                res[0] |= COMPRESSED_SIGN_FLAG;
+            }
+        }
+        BytesN::from_array(self.env(), &res)
+    }
+
+    /// Deserializes a point from the compressed form produced by
+    /// [`G1Affine::to_compressed`], recovering Y from the curve equation
+    /// `y^2 = x^3 + 3`.
+    ///
+    /// Note: the recovered point is on the curve, which for G1 implies it is
+    /// in the subgroup.
+    ///
+    /// # Panics:
+    /// - If the encoding is malformed or X is not less than the field modulus.
+    /// - If there is no point on the curve with the given X coordinate.
+    pub fn from_compressed(env: &Env, bytes: BytesN<G1_COMPRESSED_SIZE>) -> Self {
+        let mut x = bytes.to_array();
+        let flags = x[0] & COMPRESSED_FLAGS_MASK;
+        x[0] &= !COMPRESSED_FLAGS_MASK;
+        if flags & COMPRESSED_INFINITY_FLAG != 0 {
+            if flags != COMPRESSED_INFINITY_FLAG || x != [0u8; G1_COMPRESSED_SIZE] {
+                sdk_panic!("invalid input - malformed compressed point at infinity");
+            }
+            return Self::identity(env);
+        }
+
+        let x_fq = match Fq::from_be_bytes(&x) {
+            Some(v) => v,
+            None => sdk_panic!("invalid input - Fp is larger than the field modulus"),
+        };
+        let rhs = x_fq.square().mul(&x_fq).add(&Fq::from_u64(3));
+        let mut y = match rhs.sqrt() {
+            Some(v) => v,
+            None => sdk_panic!("invalid input - no point on G1 with the given X coordinate"),
+        };
+        if y.is_odd() != (flags & COMPRESSED_SIGN_FLAG != 0) {
+            y = y.neg();
+        }
+
+        Self::from_fq(env, &x_fq, &y)
+    }
+
+    fn from_fq(env: &Env, x: &Fq, y: &Fq) -> Self {
+        let mut inner = [0u8; G1_SERIALIZED_SIZE];
+        inner[..FP_SERIALIZED_SIZE].copy_from_slice(&x.to_be_bytes());
+        inner[FP_SERIALIZED_SIZE..].copy_from_slice(&y.to_be_bytes());
+        Self::from_array(env, &inner)
+    }
+}
+
+impl G2Affine {
+    pub fn env(&self) -> &Env {
+        self.0.env()
+    }
+    /// Returns the point at infinity, encoded as all-zero bytes.
+    pub fn identity(env: &Env) -> Self {
+        Self::from_array(env, &[0u8; G2_SERIALIZED_SIZE])
+    }
+    /// Returns the standard generator of G2.
+    pub fn generator(env: &Env) -> Self {
+        Self::from_array(env, &G2_GENERATOR)
+    }
+    pub fn is_identity(&self) -> bool {
+        self.to_array() == [0u8; G2_SERIALIZED_SIZE]
+    }
+
+    /// Serializes the point in compressed form: the X coordinate encoded as
+    /// `c1 || c0`, with the most significant bit set if Y is odd. The parity
+    /// of Y = c0 + c1 * u is that of c0, or of c1 when c0 is zero. The point
+    /// at infinity is encoded with only the second most significant bit set.
+    pub fn to_compressed(&self) -> BytesN<G2_COMPRESSED_SIZE> {
+        let mut res = [0u8; G2_COMPRESSED_SIZE];
+        if self.is_identity() {
+            res[0] = COMPRESSED_INFINITY_FLAG;
+        } else {
+            let inner = self.to_array();
+            res.copy_from_slice(&inner[..FP2_SERIALIZED_SIZE]);
+            let y_c1 = &inner[FP2_SERIALIZED_SIZE..G2_SERIALIZED_SIZE - FP_SERIALIZED_SIZE];
+            let y_c0 = &inner[G2_SERIALIZED_SIZE - FP_SERIALIZED_SIZE..];
+            let y_c0_is_zero = y_c0.iter().all(|b| *b == 0);
+            if y_c0[FP_SERIALIZED_SIZE - 1] & 1 == 1
+                || (y_c0_is_zero && y_c1[FP_SERIALIZED_SIZE - 1] & 1 == 1)
+            {
+                res[0] |= COMPRESSED_SIGN_FLAG;
+            }
+        }
+        BytesN::from_array(self.env(), &res)
+    }
+
+    /// Deserializes a point from the compressed form produced by
+    /// [`G2Affine::to_compressed`], recovering Y from the twist equation
+    /// `y^2 = x^3 + 3 / (9 + u)`.
+    ///
+    /// Note: this does not check that the point is in the correct subgroup,
+    /// use [`Bn254::g2_is_in_subgroup`] for untrusted inputs.
+    ///
+    /// # Panics:
+    /// - If the encoding is malformed or a component of X is not less than
+    ///   the field modulus.
+    /// - If there is no point on the curve with the given X coordinate.
+    pub fn from_compressed(env: &Env, bytes: BytesN<G2_COMPRESSED_SIZE>) -> Self {
+        let mut x = bytes.to_array();
+        let flags = x[0] & COMPRESSED_FLAGS_MASK;
+        x[0] &= !COMPRESSED_FLAGS_MASK;
+        if flags & COMPRESSED_INFINITY_FLAG != 0 {
+            if flags != COMPRESSED_INFINITY_FLAG || x != [0u8; G2_COMPRESSED_SIZE] {
+                sdk_panic!("invalid input - malformed compressed point at infinity");
+            }
+            return Self::identity(env);
+        }
+
+        let mut x_c1 = [0u8; FP_SERIALIZED_SIZE];
+        let mut x_c0 = [0u8; FP_SERIALIZED_SIZE];
+        x_c1.copy_from_slice(&x[..FP_SERIALIZED_SIZE]);
+        x_c0.copy_from_slice(&x[FP_SERIALIZED_SIZE..]);
+        let x_fq2 = match (Fq::from_be_bytes(&x_c0), Fq::from_be_bytes(&x_c1)) {
+            (Some(c0), Some(c1)) => Fq2 { c0, c1 },
+            _ => sdk_panic!("invalid input - Fp2 component is larger than the field modulus"),
+        };
+        let rhs = x_fq2.square().mul(&x_fq2).add(&g2_twist_b());
+        let mut y = match rhs.sqrt() {
+            Some(v) => v,
+            None => sdk_panic!("invalid input - no point on G2 with the given X coordinate"),
+        };
+        if y.is_odd() != (flags & COMPRESSED_SIGN_FLAG != 0) {
+            y = y.neg();
+        }
+
+        let mut inner = [0u8; G2_SERIALIZED_SIZE];
+        inner[..FP2_SERIALIZED_SIZE].copy_from_slice(&x);
+        inner[FP2_SERIALIZED_SIZE..G2_SERIALIZED_SIZE - FP_SERIALIZED_SIZE]
+            .copy_from_slice(&y.c1.to_be_bytes());
+        inner[G2_SERIALIZED_SIZE - FP_SERIALIZED_SIZE..].copy_from_slice(&y.c0.to_be_bytes());
+        Self::from_array(env, &inner)
+    }
+}
+
+// Maps a base field element to a point on G1 with the Shallue-van de
+// Woestijne method for Z = 1, following RFC 9380 section 6.6.1. BN254 G1 has
+// cofactor 1, so the result is always in the subgroup.
+fn map_to_g1(u: &Fq) -> (Fq, Fq) {
+    // c2 = -Z / 2
+    let c2 = Fq::from_be_bytes(&[
+        0x18, 0x32, 0x27, 0x39, 0x70, 0x98, 0xd0, 0x14, 0xdc, 0x28, 0x22, 0xdb, 0x40, 0xc0, 0xac,
+        0x2e, 0xcb, 0xc0, 0xb5, 0x48, 0xb4, 0x38, 0xe5, 0x46, 0x9e, 0x10, 0x46, 0x0b, 0x6c, 0x3e,
+        0x7e, 0xa3,
+    ])
+    .unwrap_optimized();
+    // c3 = sqrt(-g(Z) * 3 * Z^2), with sgn0(c3) = 0
+    let c3 = Fq::from_be_bytes(&[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x67, 0x89, 0xaf, 0x3a, 0x83, 0x52, 0x2e,
+        0xb3, 0x53, 0xc9, 0x8f, 0xc6, 0xb3, 0x6d, 0x71, 0x3d, 0x5d, 0x8d, 0x1c, 0xc5, 0xdf, 0xff,
+        0xff, 0xfa,
+    ])
+    .unwrap_optimized();
+    // c4 = -4 * g(Z) / (3 * Z^2)
+    let c4 = Fq::from_be_bytes(&[
+        0x10, 0x21, 0x6f, 0x7b, 0xa0, 0x65, 0xe0, 0x0d, 0xe8, 0x1a, 0xc1, 0xe7, 0x80, 0x80, 0x72,
+        0xc9, 0xdd, 0x2b, 0x23, 0x85, 0xcd, 0x7b, 0x43, 0x84, 0x69, 0x60, 0x2e, 0xb2, 0x48, 0x29,
+        0xa9, 0xbd,
+    ])
+    .unwrap_optimized();
+    let one = Fq::from_u64(1);
+    let g = |x: &Fq| x.square().mul(x).add(&Fq::from_u64(3));
+
+    // c1 = g(Z) = 4
+    let tv1 = u.square().mul(&Fq::from_u64(4));
+    let tv2 = one.add(&tv1);
+    let tv1 = one.sub(&tv1);
+    let tv3 = tv1.mul(&tv2).inv();
+    let tv4 = u.mul(&tv1).mul(&tv3).mul(&c3);
+
+    let x1 = c2.sub(&tv4);
+    let x2 = c2.add(&tv4);
+    let (x, y) = if let Some(y) = g(&x1).sqrt() {
+        (x1, y)
+    } else if let Some(y) = g(&x2).sqrt() {
+        (x2, y)
+    } else {
+        let x3 = tv2.square().mul(&tv3).square().mul(&c4).add(&one);
+        // one of g(x1), g(x2) and g(x3) is always a square
+        (x3, g(&x3).sqrt().unwrap_optimized())
+    };
+    if u.is_odd() != y.is_odd() {
+        (x, y.neg())
+    } else {
+        (x, y)
+    }
+}
+
+// The constant term `3 / (9 + u)` of the G2 twist equation.
+fn g2_twist_b() -> Fq2 {
+    let c0 = Fq::from_be_bytes(&[
+        0x2b, 0x14, 0x9d, 0x40, 0xce, 0xb8, 0xaa, 0xae, 0x81, 0xbe, 0x18, 0x99, 0x1b, 0xe0, 0x6a,
+        0xc3, 0xb5, 0xb4, 0xc5, 0xe5, 0x59, 0xdb, 0xef, 0xa3, 0x32, 0x67, 0xe6, 0xdc, 0x24, 0xa1,
+        0x38, 0xe5,
+    ]);
+    let c1 = Fq::from_be_bytes(&[
+        0x00, 0x97, 0x13, 0xb0, 0x3a, 0xf0, 0xfe, 0xd4, 0xcd, 0x2c, 0xaf, 0xad, 0xee, 0xd8, 0xfd,
+        0xf4, 0xa7, 0x4f, 0xa0, 0x84, 0xe5, 0x2d, 0x18, 0x52, 0xe4, 0xa2, 0xbd, 0x06, 0x85, 0xc3,
+        0x15, 0xd2,
+    ]);
+    Fq2 {
+        c0: c0.unwrap_optimized(),
+        c1: c1.unwrap_optimized(),
+    }
+}
+
+impl Neg for &G1Affine {
+    type Output = G1Affine;
+
+    fn neg(self) -> Self::Output {
+        self.env().crypto().bn254().g1_neg(self)
+    }
+}
+
+impl Neg for G1Affine {
+    type Output = G1Affine;
+
+    fn neg(self) -> Self::Output {
+        (&self).neg()
+    }
+}
+
+impl Neg for &G2Affine {
+    type Output = G2Affine;
+
+    fn neg(self) -> Self::Output {
+        self.env().crypto().bn254().g2_neg(self)
+    }
+}
+
+impl Neg for G2Affine {
+    type Output = G2Affine;
+
+    fn neg(self) -> Self::Output {
+        (&self).neg()
+    }
+}
+
 impl Fr {
     pub fn env(&self) -> &Env {
         self.0.env()
     }
+    /// Creates an `Fr` from a `U256` without reducing it. The value is
+    /// expected to already be less than the scalar field modulus `r`, use
+    /// [`Fr::from_u256_reduce`] for arbitrary values.
     pub fn from_u256(value: U256) -> Self {
         value.into()
     }
+    /// Creates an `Fr` from a `U256`, reducing it modulo the scalar field
+    /// modulus `r`.
+    pub fn from_u256_reduce(value: U256) -> Self {
+        let modulus = U256::from_parts(
+            value.env(),
+            BN254_FR_MODULUS[0],
+            BN254_FR_MODULUS[1],
+            BN254_FR_MODULUS[2],
+            BN254_FR_MODULUS[3],
+        );
+        value.rem_euclid(&modulus).into()
+    }
     pub fn to_u256(&self) -> U256 {
         self.0.clone()
     }
@@ -80,6 +530,9 @@ impl Fr {
         let v = internal::Env::bn254_fr_inv(env, self.into()).unwrap_infallible();
         U256::try_from_val(env, &v).unwrap_infallible().into()
     }
+    fn is_zero(&self) -> bool {
+        self.0 == U256::from_u32(self.env(), 0)
+    }
 }
 
 impl From<U256> for Fr {
@@ -111,6 +564,17 @@ impl TryFromVal<Env, &Fr> for Val {
         Ok(fr.to_val())
     }
 }
+impl Eq for Fr {}
+impl PartialEq for Fr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_u256().partial_cmp(other.as_u256()) == Some(Ordering::Equal)
+    }
+}
+impl Debug for Fr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Fr({:?})", self.as_u256())
+    }
+}
 
 impl Bn254 {
     pub(crate) fn new(env: &Env) -> Bn254 {
@@ -127,6 +591,20 @@ impl Bn254 {
             internal::Env::bn254_check_g1_is_in_subgroup(env, p.to_object()).unwrap_infallible();
         res.into()
     }
+    /// Checks whether every point in `points` is in the G1 subgroup,
+    /// stopping at the first point that is not. Returns true if `points` is
+    /// empty.
+    ///
+    /// The host has no batched subgroup check, so each point is checked with
+    /// a separate call to [`g1_is_in_subgroup`][Bn254::g1_is_in_subgroup],
+    /// and the cost is the same as checking the points one at a time.
+    ///
+    /// G1 has a cofactor of 1, so every point on the curve is in the
+    /// subgroup. Points that are not on the curve are rejected by the host,
+    /// which panics rather than returning false.
+    pub fn g1_batch_in_subgroup(&self, points: &Vec<G1Affine>) -> bool {
+        points.iter().all(|p| self.g1_is_in_subgroup(&p))
+    }
     pub fn g1_add(&self, p0: &G1Affine, p1: &G1Affine) -> G1Affine {
         let env = self.env();
         let bin =
@@ -139,6 +617,94 @@ impl Bn254 {
             internal::Env::bn254_g1_mul(env, p0.to_object(), scalar.into()).unwrap_infallible();
         unsafe { G1Affine::from_bytes(BytesN::unchecked_new(env.clone(), bin)) }
     }
+    /// Negates a point `p` in G1. A point (X, Y) is negated as (X, -Y), the
+    /// point at infinity is returned unchanged.
+    pub fn g1_neg(&self, p: &G1Affine) -> G1Affine {
+        let env = self.env();
+        let mut inner = p.to_array();
+        let mut y = [0u8; FP_SERIALIZED_SIZE];
+        y.copy_from_slice(&inner[FP_SERIALIZED_SIZE..]);
+        let neg_y = -Fp::from_array(env, &y);
+        inner[FP_SERIALIZED_SIZE..].copy_from_slice(&neg_y.to_array());
+        G1Affine::from_array(env, &inner)
+    }
+    /// Maps an element in the base field `Fp` to a point in G1, using the
+    /// Shallue-van de Woestijne method of RFC 9380.
+    ///
+    /// # Panics:
+    /// - If `fp` is not less than the field modulus.
+    pub fn map_fp_to_g1(&self, fp: &Fp) -> G1Affine {
+        let u = match Fq::from_be_bytes(&fp.to_array()) {
+            Some(v) => v,
+            None => sdk_panic!("invalid input - Fp is larger than the field modulus"),
+        };
+        let (x, y) = map_to_g1(&u);
+        G1Affine::from_fq(self.env(), &x, &y)
+    }
+    /// Hashes a message `msg` to a point in G1, using a domain separation tag
+    /// `domain`. This implements the `BN254G1_XMD:SHA-256_SVDW_RO_` suite of
+    /// RFC 9380, the result is always in the subgroup.
+    ///
+    /// # Panics:
+    /// - If `domain` is empty or longer than 255 bytes.
+    pub fn hash_to_g1(&self, domain: &Bytes, msg: &Bytes) -> G1Affine {
+        let uniform = self.expand_message_xmd(domain, msg);
+        let mut u0 = [0u8; HASH_TO_FIELD_SIZE];
+        let mut u1 = [0u8; HASH_TO_FIELD_SIZE];
+        u0.copy_from_slice(&uniform[..HASH_TO_FIELD_SIZE]);
+        u1.copy_from_slice(&uniform[HASH_TO_FIELD_SIZE..]);
+
+        let (x0, y0) = map_to_g1(&Fq::from_be_bytes_wide(&u0));
+        let (x1, y1) = map_to_g1(&Fq::from_be_bytes_wide(&u1));
+        let env = self.env();
+        self.g1_add(
+            &G1Affine::from_fq(env, &x0, &y0),
+            &G1Affine::from_fq(env, &x1, &y1),
+        )
+    }
+    // expand_message_xmd with SHA-256, as specified in RFC 9380 section
+    // 5.3.1, producing the bytes for two field elements.
+    fn expand_message_xmd(&self, dst: &Bytes, msg: &Bytes) -> [u8; HASH_TO_FIELD_SIZE * 2] {
+        if dst.is_empty() || dst.len() > 255 {
+            sdk_panic!("invalid input - dst must be between 1 and 255 bytes");
+        }
+        let env = self.env();
+        let mut dst_prime = dst.clone();
+        dst_prime.push_back(dst.len() as u8);
+
+        // Z_pad || msg || I2OSP(len_in_bytes, 2) || I2OSP(0, 1) || DST_prime
+        let mut msg_prime = Bytes::from_array(env, &[0u8; 64]);
+        msg_prime.append(msg);
+        msg_prime.extend_from_array(&[0, (HASH_TO_FIELD_SIZE * 2) as u8, 0]);
+        msg_prime.append(&dst_prime);
+        let b0 = env.crypto().sha256(&msg_prime).to_array();
+
+        // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime), where
+        // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+        let mut res = [0u8; HASH_TO_FIELD_SIZE * 2];
+        let mut prev = [0u8; 32];
+        for (i, chunk) in res.chunks_mut(32).enumerate() {
+            let mut block = b0;
+            for (b, p) in block.iter_mut().zip(prev.iter()) {
+                *b ^= p;
+            }
+            let mut input = Bytes::from_array(env, &block);
+            input.push_back(i as u8 + 1);
+            input.append(&dst_prime);
+            prev = env.crypto().sha256(&input).to_array();
+            chunk.copy_from_slice(&prev);
+        }
+        res
+    }
+    /// Performs a multi-scalar multiplication (MSM) operation in G1.
+    ///
+    /// # Panics:
+    /// - If the lengths of `vp` and `vs` are not equal.
+    pub fn g1_msm(&self, vp: Vec<G1Affine>, vs: Vec<Fr>) -> G1Affine {
+        let env = self.env();
+        let bin = internal::Env::bn254_g1_msm(env, vp.into(), vs.into()).unwrap_infallible();
+        unsafe { G1Affine::from_bytes(BytesN::unchecked_new(env.clone(), bin)) }
+    }
 
     // g2
     pub fn g2_is_in_subgroup(&self, p: &G2Affine) -> bool {
@@ -147,6 +713,16 @@ impl Bn254 {
             internal::Env::bn254_check_g2_is_in_subgroup(env, p.to_object()).unwrap_infallible();
         res.into()
     }
+    /// Checks whether every point in `points` is in the G2 subgroup,
+    /// stopping at the first point that is not. Returns true if `points` is
+    /// empty.
+    ///
+    /// The host has no batched subgroup check, so each point is checked with
+    /// a separate call to [`g2_is_in_subgroup`][Bn254::g2_is_in_subgroup],
+    /// and the cost is the same as checking the points one at a time.
+    pub fn g2_batch_in_subgroup(&self, points: &Vec<G2Affine>) -> bool {
+        points.iter().all(|p| self.g2_is_in_subgroup(&p))
+    }
     pub fn g2_add(&self, p0: &G2Affine, p1: &G2Affine) -> G2Affine {
         let env = self.env();
         let bin =
@@ -159,6 +735,26 @@ impl Bn254 {
             internal::Env::bn254_g2_mul(env, p0.to_object(), scalar.into()).unwrap_infallible();
         unsafe { G2Affine::from_bytes(BytesN::unchecked_new(env.clone(), bin)) }
     }
+    /// Negates a point `p` in G2. A point (X, Y) is negated as (X, -Y), the
+    /// point at infinity is returned unchanged.
+    pub fn g2_neg(&self, p: &G2Affine) -> G2Affine {
+        let env = self.env();
+        let mut inner = p.to_array();
+        let mut y = [0u8; FP2_SERIALIZED_SIZE];
+        y.copy_from_slice(&inner[FP2_SERIALIZED_SIZE..]);
+        let neg_y = -Fp2::from_array(env, &y);
+        inner[FP2_SERIALIZED_SIZE..].copy_from_slice(&neg_y.to_array());
+        G2Affine::from_array(env, &inner)
+    }
+    /// Performs a multi-scalar multiplication (MSM) operation in G2.
+    ///
+    /// # Panics:
+    /// - If the lengths of `vp` and `vs` are not equal.
+    pub fn g2_msm(&self, vp: Vec<G2Affine>, vs: Vec<Fr>) -> G2Affine {
+        let env = self.env();
+        let bin = internal::Env::bn254_g2_msm(env, vp.into(), vs.into()).unwrap_infallible();
+        unsafe { G2Affine::from_bytes(BytesN::unchecked_new(env.clone(), bin)) }
+    }
 
     // pairing
     pub fn pairing_check(&self, vp1: Vec<G1Affine>, vp2: Vec<G2Affine>) -> bool {
@@ -184,4 +780,20 @@ impl Bn254 {
         let v = internal::Env::bn254_fr_mul(env, lhs.into(), rhs.into()).unwrap_infallible();
         U256::try_from_val(env, &v).unwrap_infallible().into()
     }
+    /// Divides one scalar by another in the BN254 scalar field `Fr`, computed
+    /// as `lhs * rhs^-1`.
+    ///
+    /// # Panics:
+    /// - If `rhs` is zero.
+    pub fn fr_div(&self, lhs: &Fr, rhs: &Fr) -> Fr {
+        if rhs.is_zero() {
+            sdk_panic!("division by zero in Fr");
+        }
+        self.fr_mul(lhs, &rhs.inv())
+    }
+    /// Computes the additive inverse of a scalar in the BN254 scalar field `Fr`.
+    pub fn fr_neg(&self, x: &Fr) -> Fr {
+        let env = self.env();
+        self.fr_sub(&U256::from_u32(env, 0).into(), x)
+    }
 }
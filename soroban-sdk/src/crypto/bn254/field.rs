@@ -0,0 +1,285 @@
+//! Arithmetic over the BN254 base field and its quadratic extension,
+//! performed in the guest for operations the host does not provide (such as
+//! recovering a point from its compressed encoding).
+
+// BN254 base field modulus `q`, as little-endian u64 limbs.
+const FQ_MODULUS: [u64; 4] = [
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+// -q^-1 mod 2^64
+const FQ_INV: u64 = 0x87d20782e4866389;
+// R^2 mod q, with R = 2^256
+const FQ_R2: [u64; 4] = [
+    0xf32cfc5b538afa89,
+    0xb5e71911d44501fb,
+    0x47ab1eff0a417ff6,
+    0x06d89f71cab8351f,
+];
+// (q + 1) / 4
+const FQ_SQRT_EXP: [u64; 4] = [
+    0x4f082305b61f3f52,
+    0x65e05aa45a1c72a3,
+    0x6e14116da0605617,
+    0x0c19139cb84c680a,
+];
+// q - 2
+const FQ_INV_EXP: [u64; 4] = [
+    0x3c208c16d87cfd45,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+// An element of the BN254 base field, in Montgomery form.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) struct Fq([u64; 4]);
+
+impl Fq {
+    pub(super) const ZERO: Fq = Fq([0; 4]);
+
+    // Reduces `a < 2q` into the range `[0, q)`.
+    fn reduce(mut a: [u64; 4], carry: bool) -> [u64; 4] {
+        let mut t = a;
+        let borrow = sub_limbs(&mut t, &FQ_MODULUS);
+        if carry || !borrow {
+            a = t;
+        }
+        a
+    }
+
+    pub(super) fn from_u64(v: u64) -> Fq {
+        Fq([v, 0, 0, 0]).mul(&Fq(FQ_R2))
+    }
+
+    // Returns None if the big-endian encoding is not less than `q`.
+    pub(super) fn from_be_bytes(bytes: &[u8; 32]) -> Option<Fq> {
+        let limbs = limbs_from_be_bytes(bytes);
+        let mut t = limbs;
+        if !sub_limbs(&mut t, &FQ_MODULUS) {
+            return None;
+        }
+        Some(Fq(limbs).mul(&Fq(FQ_R2)))
+    }
+
+    // Reduces a 48-byte big-endian integer modulo `q`, as done by
+    // hash_to_field in RFC 9380.
+    pub(super) fn from_be_bytes_wide(bytes: &[u8; 48]) -> Fq {
+        let mut hi = [0u8; 32];
+        let mut lo = [0u8; 32];
+        hi[16..].copy_from_slice(&bytes[..16]);
+        lo.copy_from_slice(&bytes[16..]);
+        // hi * 2^256 + lo, where R^2 mod q is 2^256 in Montgomery form. The
+        // Montgomery multiplication reduces `lo` even if it exceeds `q`.
+        let hi = Fq(limbs_from_be_bytes(&hi)).mul(&Fq(FQ_R2)).mul(&Fq(FQ_R2));
+        let lo = Fq(limbs_from_be_bytes(&lo)).mul(&Fq(FQ_R2));
+        hi.add(&lo)
+    }
+
+    pub(super) fn to_be_bytes(&self) -> [u8; 32] {
+        let limbs = self.mul(&Fq([1, 0, 0, 0])).0;
+        let mut bytes = [0u8; 32];
+        for (i, limb) in limbs.iter().enumerate() {
+            bytes[24 - i * 8..32 - i * 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    pub(super) fn is_zero(&self) -> bool {
+        *self == Fq::ZERO
+    }
+
+    pub(super) fn add(&self, rhs: &Fq) -> Fq {
+        let mut res = self.0;
+        let carry = add_limbs(&mut res, &rhs.0);
+        Fq(Fq::reduce(res, carry))
+    }
+
+    pub(super) fn sub(&self, rhs: &Fq) -> Fq {
+        let mut res = self.0;
+        if sub_limbs(&mut res, &rhs.0) {
+            add_limbs(&mut res, &FQ_MODULUS);
+        }
+        Fq(res)
+    }
+
+    pub(super) fn neg(&self) -> Fq {
+        Fq::ZERO.sub(self)
+    }
+
+    // Montgomery multiplication (CIOS).
+    #[allow(clippy::needless_range_loop)]
+    pub(super) fn mul(&self, rhs: &Fq) -> Fq {
+        let (a, b) = (&self.0, &rhs.0);
+        let mut t = [0u64; 6];
+        for i in 0..4 {
+            let mut carry = 0u64;
+            for j in 0..4 {
+                let v = t[j] as u128 + a[j] as u128 * b[i] as u128 + carry as u128;
+                t[j] = v as u64;
+                carry = (v >> 64) as u64;
+            }
+            let v = t[4] as u128 + carry as u128;
+            t[4] = v as u64;
+            t[5] = (v >> 64) as u64;
+
+            let m = t[0].wrapping_mul(FQ_INV);
+            let v = t[0] as u128 + m as u128 * FQ_MODULUS[0] as u128;
+            let mut carry = (v >> 64) as u64;
+            for j in 1..4 {
+                let v = t[j] as u128 + m as u128 * FQ_MODULUS[j] as u128 + carry as u128;
+                t[j - 1] = v as u64;
+                carry = (v >> 64) as u64;
+            }
+            let v = t[4] as u128 + carry as u128;
+            t[3] = v as u64;
+            t[4] = t[5] + (v >> 64) as u64;
+        }
+        Fq(Fq::reduce([t[0], t[1], t[2], t[3]], t[4] != 0))
+    }
+
+    pub(super) fn square(&self) -> Fq {
+        self.mul(self)
+    }
+
+    // Raises to the power `exp`, given as little-endian u64 limbs.
+    pub(super) fn pow(&self, exp: &[u64; 4]) -> Fq {
+        let mut res = Fq::from_u64(1);
+        for limb in exp.iter().rev() {
+            for i in (0..64).rev() {
+                res = res.square();
+                if (limb >> i) & 1 == 1 {
+                    res = res.mul(self);
+                }
+            }
+        }
+        res
+    }
+
+    // Returns zero for zero.
+    pub(super) fn inv(&self) -> Fq {
+        self.pow(&FQ_INV_EXP)
+    }
+
+    // Since q = 3 mod 4, a square root of `a` (if any) is a^((q+1)/4).
+    pub(super) fn sqrt(&self) -> Option<Fq> {
+        let root = self.pow(&FQ_SQRT_EXP);
+        if root.square() == *self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn is_odd(&self) -> bool {
+        self.to_be_bytes()[31] & 1 == 1
+    }
+}
+
+// An element c0 + c1 * u of the quadratic extension Fq2 = Fq[u] / (u^2 + 1).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) struct Fq2 {
+    pub(super) c0: Fq,
+    pub(super) c1: Fq,
+}
+
+impl Fq2 {
+    pub(super) fn add(&self, rhs: &Fq2) -> Fq2 {
+        Fq2 {
+            c0: self.c0.add(&rhs.c0),
+            c1: self.c1.add(&rhs.c1),
+        }
+    }
+
+    pub(super) fn neg(&self) -> Fq2 {
+        Fq2 {
+            c0: self.c0.neg(),
+            c1: self.c1.neg(),
+        }
+    }
+
+    pub(super) fn mul(&self, rhs: &Fq2) -> Fq2 {
+        let aa = self.c0.mul(&rhs.c0);
+        let bb = self.c1.mul(&rhs.c1);
+        let cross = self.c0.add(&self.c1).mul(&rhs.c0.add(&rhs.c1));
+        Fq2 {
+            c0: aa.sub(&bb),
+            c1: cross.sub(&aa).sub(&bb),
+        }
+    }
+
+    pub(super) fn square(&self) -> Fq2 {
+        self.mul(self)
+    }
+
+    // Square root via the norm: for a = a0 + a1 * u with a square root
+    // x0 + x1 * u, we have x0^2 = (a0 + sqrt(a0^2 + a1^2)) / 2 and
+    // x1 = a1 / (2 * x0). The result is verified before being returned.
+    pub(super) fn sqrt(&self) -> Option<Fq2> {
+        let two_inv = Fq::from_u64(2).inv();
+        let root = if self.c1.is_zero() {
+            match self.c0.sqrt() {
+                Some(c0) => Fq2 { c0, c1: Fq::ZERO },
+                None => Fq2 {
+                    c0: Fq::ZERO,
+                    c1: self.c0.neg().sqrt()?,
+                },
+            }
+        } else {
+            let norm = self.c0.square().add(&self.c1.square()).sqrt()?;
+            let half = self.c0.add(&norm).mul(&two_inv);
+            let x0 = match half.sqrt() {
+                Some(x0) => x0,
+                None => self.c0.sub(&norm).mul(&two_inv).sqrt()?,
+            };
+            let x1 = self.c1.mul(&x0.add(&x0).inv());
+            Fq2 { c0: x0, c1: x1 }
+        };
+        if root.square() == *self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    // The "sign" of an Fq2 element, as defined by `sgn0` in RFC 9380.
+    pub(super) fn is_odd(&self) -> bool {
+        self.c0.is_odd() || (self.c0.is_zero() && self.c1.is_odd())
+    }
+}
+
+fn limbs_from_be_bytes(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let mut chunk = [0u8; 8];
+        chunk.copy_from_slice(&bytes[24 - i * 8..32 - i * 8]);
+        *limb = u64::from_be_bytes(chunk);
+    }
+    limbs
+}
+
+// Adds `b` to `a` in place, returning the carry.
+fn add_limbs(a: &mut [u64; 4], b: &[u64; 4]) -> bool {
+    let mut carry = false;
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        let (s1, c1) = x.overflowing_add(*y);
+        let (s2, c2) = s1.overflowing_add(carry as u64);
+        *x = s2;
+        carry = c1 | c2;
+    }
+    carry
+}
+
+// Subtracts `b` from `a` in place, returning the borrow.
+fn sub_limbs(a: &mut [u64; 4], b: &[u64; 4]) -> bool {
+    let mut borrow = false;
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        let (d1, b1) = x.overflowing_sub(*y);
+        let (d2, b2) = d1.overflowing_sub(borrow as u64);
+        *x = d2;
+        borrow = b1 | b2;
+    }
+    borrow
+}
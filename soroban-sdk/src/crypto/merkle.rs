@@ -0,0 +1,99 @@
+//! Merkle contains functions for verifying Merkle proofs, such as those used
+//! for airdrops and allowlists.
+//!
+//! ### Tree
+//!
+//! The tree is a binary tree of SHA-256 hashes. Hashes of leaves and of
+//! interior nodes are domain separated by a one byte prefix, so that an
+//! interior node can never be passed off as a leaf, which would otherwise
+//! allow a second-preimage attack:
+//!
+//! - Leaf: `sha256(0x00 || leaf)`
+//! - Node: `sha256(0x01 || left || right)`
+//!
+//! A tree of `2^depth` leaves has the leaves at indexes `0` to `2^depth - 1`
+//! from left to right. A tree with a number of leaves that is not a power of
+//! two must be padded with leaves up to the next power of two, for example
+//! with a leaf value that cannot be claimed.
+//!
+//! ### Proof
+//!
+//! A proof for the leaf at `index` is the list of sibling hashes on the path
+//! from the leaf to the root, starting with the leaf's sibling. At each level
+//! the bit of `index` for that level, starting from the least significant bit,
+//! decides the order the hashes are combined in:
+//!
+//! - Bit `0`: the current hash is the left input, `node(current, sibling)`.
+//! - Bit `1`: the current hash is the right input, `node(sibling, current)`.
+use crate::{Bytes, BytesN, Env, Vec};
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Returns the hash of a leaf, `sha256(0x00 || leaf)`.
+pub fn leaf_hash(env: &Env, leaf: &BytesN<32>) -> BytesN<32> {
+    let mut buf = [0u8; 33];
+    buf[0] = LEAF_PREFIX;
+    buf[1..].copy_from_slice(&leaf.to_array());
+    env.crypto()
+        .sha256(&Bytes::from_slice(env, &buf))
+        .to_bytes()
+}
+
+/// Returns the hash of an interior node, `sha256(0x01 || left || right)`.
+pub fn node_hash(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut buf = [0u8; 65];
+    buf[0] = NODE_PREFIX;
+    buf[1..33].copy_from_slice(&left.to_array());
+    buf[33..].copy_from_slice(&right.to_array());
+    env.crypto()
+        .sha256(&Bytes::from_slice(env, &buf))
+        .to_bytes()
+}
+
+/// Returns true if `proof` proves that `leaf` is the leaf at `index` of the
+/// tree with the given `root`.
+///
+/// See the [module documentation][self] for how the tree and the proof are
+/// constructed. The length of the proof is the depth of the tree, so an empty
+/// proof verifies a tree with a single leaf.
+///
+/// Returns false if `index` does not fit within a tree of the proof's
+/// depth.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{crypto::merkle, vec, BytesN, Env};
+///
+/// let env = Env::default();
+/// let leaves = [[1u8; 32], [2u8; 32]].map(|l| BytesN::from_array(&env, &l));
+/// let hashes = leaves.clone().map(|l| merkle::leaf_hash(&env, &l));
+/// let root = merkle::node_hash(&env, &hashes[0], &hashes[1]);
+///
+/// let proof = vec![&env, hashes[0].clone()];
+/// assert!(merkle::verify_proof(&env, &root, &leaves[1], &proof, 1));
+/// assert!(!merkle::verify_proof(&env, &root, &leaves[1], &proof, 0));
+/// ```
+pub fn verify_proof(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+    index: u32,
+) -> bool {
+    if proof.len() < u32::BITS && index >> proof.len() != 0 {
+        return false;
+    }
+    let mut hash = leaf_hash(env, leaf);
+    let mut index = index;
+    for sibling in proof.iter() {
+        hash = if index & 1 == 0 {
+            node_hash(env, &hash, &sibling)
+        } else {
+            node_hash(env, &sibling, &hash)
+        };
+        index >>= 1;
+    }
+    &hash == root
+}
@@ -0,0 +1,121 @@
+//! SHA3-256 (FIPS 202), performed in the guest because the host only
+//! provides the original Keccak-256 padding.
+
+use crate::Bytes;
+
+// The rate of SHA3-256 in bytes, (1600 - 2 * 256) / 8.
+const RATE: usize = 136;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+// Rotation offsets and lane positions for the combined rho and pi steps,
+// visiting lanes along the pi permutation starting from lane 1.
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+// The Keccak-f[1600] permutation.
+fn keccak_f(state: &mut [u64; 25]) {
+    for rc in ROUND_CONSTANTS.iter() {
+        // Theta.
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+        // Rho and pi.
+        let mut last = state[1];
+        for (r, p) in RHO.iter().zip(PI.iter()) {
+            let t = state[*p];
+            state[*p] = last.rotate_left(*r);
+            last = t;
+        }
+        // Chi.
+        for y in 0..5 {
+            let row = [
+                state[5 * y],
+                state[5 * y + 1],
+                state[5 * y + 2],
+                state[5 * y + 3],
+                state[5 * y + 4],
+            ];
+            for x in 0..5 {
+                state[5 * y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+        // Iota.
+        state[0] ^= rc;
+    }
+}
+
+// XORs a full block into the state and permutes it.
+fn absorb(state: &mut [u64; 25], block: &[u8; RATE]) {
+    for (lane, chunk) in state.iter_mut().zip(block.chunks(8)) {
+        let mut b = [0u8; 8];
+        b.copy_from_slice(chunk);
+        *lane ^= u64::from_le_bytes(b);
+    }
+    keccak_f(state);
+}
+
+pub(super) fn sha3_256(data: &Bytes) -> [u8; 32] {
+    let mut state = [0u64; 25];
+    let len = data.len() as usize;
+    let mut block = [0u8; RATE];
+    let mut i = 0;
+    while i + RATE <= len {
+        data.slice(i as u32..(i + RATE) as u32)
+            .copy_into_slice(&mut block);
+        absorb(&mut state, &block);
+        i += RATE;
+    }
+
+    // Pad the final block with the SHA3 domain separator (0x06) and the
+    // trailing bit of pad10*1. Keccak-256 differs only in using 0x01.
+    let rem = len - i;
+    block = [0u8; RATE];
+    data.slice(i as u32..len as u32)
+        .copy_into_slice(&mut block[..rem]);
+    block[rem] ^= 0x06;
+    block[RATE - 1] ^= 0x80;
+    absorb(&mut state, &block);
+
+    let mut out = [0u8; 32];
+    for (chunk, lane) in out.chunks_mut(8).zip(state.iter()) {
+        chunk.copy_from_slice(&lane.to_le_bytes());
+    }
+    out
+}
@@ -0,0 +1,218 @@
+//! Decimal contains functions for parsing decimal numbers from strings, such
+//! as amounts entered by people in a frontend.
+//!
+//! Integers too large for an `i128` can be parsed with
+//! [`U256::from_decimal_string`][crate::U256::from_decimal_string] and
+//! [`I256::from_decimal_string`][crate::I256::from_decimal_string], which
+//! return the same [`ParseError`].
+use crate::String;
+
+/// ParseError is returned when a [String] is not a valid decimal number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string has no digits, such as `""` or `"-"`.
+    Empty,
+    /// The string contains a character that is not allowed where it is, such
+    /// as a letter, whitespace, a second sign, or a second decimal point.
+    InvalidChar,
+    /// The number has more fractional digits than the scale.
+    TooManyFractionalDigits,
+    /// The scaled number does not fit in the type.
+    Overflow,
+}
+
+/// Parses an optionally signed decimal number, and returns it multiplied by
+/// `10^scale`.
+///
+/// The number is a `-` or `+` sign, followed by one or more digits,
+/// optionally followed by a `.` and one or more fractional digits. Leading
+/// and trailing whitespace, and any other characters, are rejected rather
+/// than ignored, so that input that is not exactly a number is never
+/// accepted.
+///
+/// The number must have at most `scale` fractional digits, including any
+/// trailing zeros, so that the result is exact.
+///
+/// ### Errors
+///
+/// If the string is not a decimal number, has more than `scale` fractional
+/// digits, or if the scaled number does not fit in an `i128`.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{decimal::{self, ParseError}, Env, String};
+///
+/// let env = Env::default();
+/// let s = |s| String::from_str(&env, s);
+///
+/// // An amount with 7 decimals, the same as Stellar assets.
+/// assert_eq!(decimal::parse_i128(&s("12.34"), 7), Ok(123_400_000));
+/// assert_eq!(decimal::parse_i128(&s("-0.5"), 7), Ok(-5_000_000));
+/// assert_eq!(
+///     decimal::parse_i128(&s("0.00000001"), 7),
+///     Err(ParseError::TooManyFractionalDigits),
+/// );
+/// ```
+pub fn parse_i128(s: &String, scale: u32) -> Result<i128, ParseError> {
+    let mut negative = false;
+    let mut magnitude = 0u128;
+    let mut digits = 0u32;
+    let mut fraction_digits: Option<u32> = None;
+
+    try_for_each_byte(s, |i, c| {
+        match c {
+            b'-' | b'+' if i == 0 => negative = c == b'-',
+            b'.' if fraction_digits.is_none() && digits > 0 => fraction_digits = Some(0),
+            b'0'..=b'9' => {
+                magnitude = magnitude
+                    .checked_mul(10)
+                    .and_then(|m| m.checked_add((c - b'0').into()))
+                    .ok_or(ParseError::Overflow)?;
+                digits += 1;
+                if let Some(f) = &mut fraction_digits {
+                    *f += 1;
+                    if *f > scale {
+                        return Err(ParseError::TooManyFractionalDigits);
+                    }
+                }
+            }
+            _ => return Err(ParseError::InvalidChar),
+        }
+        Ok(())
+    })?;
+    match fraction_digits {
+        _ if digits == 0 => return Err(ParseError::Empty),
+        // A decimal point must be followed by a digit.
+        Some(0) => return Err(ParseError::InvalidChar),
+        _ => {}
+    }
+
+    // Zero stays zero at any scale, every other magnitude overflows an i128
+    // when scaled by more than 10^38.
+    if magnitude != 0 {
+        let exp = scale - fraction_digits.unwrap_or(0);
+        magnitude = 10u128
+            .checked_pow(exp)
+            .and_then(|p| magnitude.checked_mul(p))
+            .ok_or(ParseError::Overflow)?;
+    }
+    if negative {
+        if magnitude > i128::MIN.unsigned_abs() {
+            return Err(ParseError::Overflow);
+        }
+        Ok((magnitude as i128).wrapping_neg())
+    } else {
+        i128::try_from(magnitude).map_err(|_| ParseError::Overflow)
+    }
+}
+
+// Calls `f` with the index and value of each byte of the string, stopping at
+// the first error. The string is copied into the guest in fixed size chunks.
+pub(crate) fn try_for_each_byte(
+    s: &String,
+    mut f: impl FnMut(u32, u8) -> Result<(), ParseError>,
+) -> Result<(), ParseError> {
+    const CHUNK_SIZE: u32 = 64;
+    let mut buf = [0u8; CHUNK_SIZE as usize];
+    let mut i = 0u32;
+    for chunk in s.to_bytes().chunks(CHUNK_SIZE) {
+        let n = chunk.len() as usize;
+        chunk.copy_into_slice(&mut buf[..n]);
+        for &c in &buf[..n] {
+            f(i, c)?;
+            i += 1;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Env;
+
+    fn parse(s: &str, scale: u32) -> Result<i128, ParseError> {
+        let env = Env::default();
+        parse_i128(&String::from_str(&env, s), scale)
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(parse("0", 0), Ok(0));
+        assert_eq!(parse("0", 7), Ok(0));
+        assert_eq!(parse("-0", 2), Ok(0));
+        assert_eq!(parse("+12", 2), Ok(1200));
+        assert_eq!(parse("007", 0), Ok(7));
+        assert_eq!(parse("-42", 3), Ok(-42_000));
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_eq!(parse("-1.5", 1), Ok(-15));
+        assert_eq!(parse("-1.5", 2), Ok(-150));
+        assert_eq!(parse("12.34", 2), Ok(1234));
+        assert_eq!(parse("0.001", 3), Ok(1));
+        assert_eq!(parse("1.10", 2), Ok(110));
+    }
+
+    #[test]
+    fn test_too_many_fractional_digits() {
+        assert_eq!(parse("12.345", 2), Err(ParseError::TooManyFractionalDigits));
+        assert_eq!(parse("1.5", 0), Err(ParseError::TooManyFractionalDigits));
+        // Trailing zeros are digits too.
+        assert_eq!(parse("1.100", 2), Err(ParseError::TooManyFractionalDigits));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(parse("", 2), Err(ParseError::Empty));
+        assert_eq!(parse("-", 2), Err(ParseError::Empty));
+        for s in [
+            " 1", "1 ", "\t1", "1\n", "1,000", "1.2.3", "--1", "+-1", "1-", ".5", "5.", "-.5",
+            "1e5", "abc", "0x10", "1_000", "١",
+        ] {
+            assert_eq!(parse(s, 2), Err(ParseError::InvalidChar), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_limits() {
+        assert_eq!(
+            parse("170141183460469231731687303715884105727", 0),
+            Ok(i128::MAX)
+        );
+        assert_eq!(
+            parse("-170141183460469231731687303715884105728", 0),
+            Ok(i128::MIN)
+        );
+        assert_eq!(
+            parse("170141183460469231731687303715884105728", 0),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(
+            parse("-170141183460469231731687303715884105729", 0),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(
+            parse("-17014118346046923173168730371588410572.8", 1),
+            Ok(i128::MIN)
+        );
+        // Overflows from scaling.
+        assert_eq!(
+            parse("17014118346046923173168730371588410573", 1),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(parse("1", 39), Err(ParseError::Overflow));
+        assert_eq!(parse("1", 38), Ok(10i128.pow(38)));
+        assert_eq!(parse("0", 100), Ok(0));
+        // Large scales are rejected or accepted without scaling digit by digit.
+        assert_eq!(parse("0", u32::MAX), Ok(0));
+        assert_eq!(parse("0.5", u32::MAX), Err(ParseError::Overflow));
+        assert_eq!(parse("-1", u32::MAX), Err(ParseError::Overflow));
+        // Overflows while parsing.
+        assert_eq!(parse(&"9".repeat(100), 0), Err(ParseError::Overflow));
+        // Leading zeros beyond a chunk are fine.
+        assert_eq!(parse(&std::format!("{}1.5", "0".repeat(100)), 1), Ok(15));
+    }
+}
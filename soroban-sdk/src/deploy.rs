@@ -125,7 +125,7 @@
 
 use crate::{
     env::internal::Env as _, unwrap::UnwrapInfallible, Address, Bytes, BytesN, ConstructorArgs,
-    Env, IntoVal,
+    Env, IntoVal, Val, Vec,
 };
 
 /// Deployer provides access to deploying contracts.
@@ -155,6 +155,17 @@ impl Deployer {
         }
     }
 
+    /// Returns the address that a contract deployed by the current contract
+    /// with the salt will have, without deploying it.
+    ///
+    /// The address is the same as the one returned by
+    /// [`deployed_address`](DeployerWithAddress::deployed_address) on
+    /// [`with_current_contract`](Self::with_current_contract), and by
+    /// deploying with it, with the same salt.
+    pub fn predict_address(&self, salt: impl IntoVal<Env, BytesN<32>>) -> Address {
+        self.with_current_contract(salt).deployed_address()
+    }
+
     /// Get a deployer that deploys contracts that derive the contract ID
     /// from the provided address and salt.
     ///
@@ -186,6 +197,39 @@ impl Deployer {
         }
     }
 
+    /// Deploy multiple contracts that use the Wasm executable with provided
+    /// hash, with contract IDs derived from the current contract and the salts.
+    ///
+    /// Each item is a salt and the constructor args for one contract. Pass an
+    /// empty `Vec` as the constructor args for contracts with no constructor
+    /// or a constructor with zero arguments.
+    ///
+    /// Returns the deployed contracts' addresses in the order of the items.
+    /// Each address is the same as the one that would be returned by
+    /// [`deploy_v2`](DeployerWithAddress::deploy_v2) on
+    /// [`with_current_contract`](Self::with_current_contract) with the same
+    /// salt.
+    ///
+    /// ### Panics
+    ///
+    /// If a contract already exists at any of the addresses, including when
+    /// the same salt appears more than once.
+    pub fn deploy_many(
+        &self,
+        wasm_hash: impl IntoVal<Env, BytesN<32>>,
+        items: Vec<(BytesN<32>, Vec<Val>)>,
+    ) -> Vec<Address> {
+        let wasm_hash: BytesN<32> = wasm_hash.into_val(&self.env);
+        let mut addresses = Vec::new(&self.env);
+        for (salt, constructor_args) in items.iter() {
+            let address = self
+                .with_current_contract(salt)
+                .deploy_v2(wasm_hash.clone(), constructor_args);
+            addresses.push_back(address);
+        }
+        addresses
+    }
+
     /// Upload the contract Wasm code to the network.
     ///
     /// Returns the hash of the uploaded Wasm that can be then used for
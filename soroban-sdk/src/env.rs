@@ -246,6 +246,17 @@ struct EnvTestState {
     generators: Rc<RefCell<Generators>>,
     auth_snapshot: Rc<RefCell<AuthSnapshot>>,
     snapshot: Option<Rc<LedgerSnapshot>>,
+    prng_seed: Rc<RefCell<PrngSeed>>,
+}
+
+// The seed last set with Env::set_prng_seed, and the number of top-level
+// invocations made since, each of which reseeds the base PRNG with a seed
+// derived from the two.
+#[cfg(any(test, feature = "testutils"))]
+#[derive(Clone, Default)]
+struct PrngSeed {
+    seed: Option<[u8; 32]>,
+    invocations: u64,
 }
 
 /// Config for changing the default behavior of the Env when used in tests.
@@ -288,6 +299,31 @@ impl Env {
         Storage::new(self)
     }
 
+    /// Extend the TTL of the currently executing contract's instance and
+    /// code, if it is below `threshold` ledgers, to `extend_to` ledgers.
+    ///
+    /// Unlike [`Instance::extend_ttl`][crate::storage::Instance::extend_ttl],
+    /// the values are limited to the maximum TTL of the network, and
+    /// `threshold` is limited to `extend_to`, so that it does not panic if
+    /// the network's maximum TTL is lowered below a value hard coded in the
+    /// contract. This makes it suitable for calling at the start of every
+    /// function of a contract, which is what
+    /// [`#[contractimpl(extend_instance)]`][crate::contractimpl] does.
+    ///
+    /// ### Read-only functions
+    ///
+    /// Extending a TTL is a write to the ledger, so a function that calls
+    /// this requires the instance and code to be in the read-write footprint
+    /// of the transaction, even if the function otherwise only reads. Rent is
+    /// only charged when the TTL is below `threshold` and is extended.
+    pub fn extend_instance_ttl_auto(&self, threshold: u32, extend_to: u32) {
+        let storage = self.storage();
+        let extend_to = extend_to.min(storage.max_ttl());
+        storage
+            .instance()
+            .extend_ttl(threshold.min(extend_to), extend_to);
+    }
+
     /// Get [Events] for publishing events associated with the
     /// currently executing contract.
     #[inline(always)]
@@ -338,6 +374,15 @@ impl Env {
     }
 
     /// Get the Address object corresponding to the current executing contract.
+    ///
+    /// There is no way to get the address of the contract that invoked the
+    /// current contract, if any. A contract that needs to know its invoker
+    /// takes the invoker's address as an argument and calls
+    /// [`require_auth`][Address::require_auth] on it. A contract that invokes
+    /// another contract directly is authorized without any signatures, so
+    /// passing [`current_contract_address`][Env::current_contract_address] to
+    /// the callee is enough, while any other contract or account must have
+    /// authorized the call.
     pub fn current_contract_address(&self) -> Address {
         let address = internal::Env::get_current_contract_address(self).unwrap_infallible();
         unsafe { Address::unchecked_new(self.clone(), address) }
@@ -356,6 +401,12 @@ impl Env {
 
     /// Invokes a function of a contract that is registered in the [Env].
     ///
+    /// Contracts do not have access to the call stack, so a contract can't
+    /// read how deeply it has been invoked. The host limits the depth of the
+    /// call stack itself, and does not allow a contract to be invoked while it
+    /// is already on the call stack, so a contract A calling B calling A
+    /// fails.
+    ///
     /// # Panics
     ///
     /// Will panic if the `contract_id` does not match a registered contract,
@@ -376,6 +427,8 @@ impl Env {
     where
         T: TryFromVal<Env, Val>,
     {
+        #[cfg(any(test, feature = "testutils"))]
+        self.reseed_prng_for_invocation();
         let rv = internal::Env::call(
             self,
             contract_address.to_object(),
@@ -390,6 +443,23 @@ impl Env {
 
     /// Invokes a function of a contract that is registered in the [Env],
     /// returns an error if the invocation fails for any reason.
+    ///
+    /// A failed invocation does not abort the calling contract, which can
+    /// handle the error and continue, for example to skip one failing call in
+    /// a batch. Any changes made by the failed invocation are rolled back.
+    ///
+    /// The returned value is:
+    /// - `Ok(Ok(T))` if the invocation succeeded.
+    /// - `Ok(Err(T::Error))` if the invocation succeeded, but the returned
+    /// value cannot be converted into the type `T`.
+    /// - `Err(Ok(E))` if the invoked contract returned or panicked with a
+    /// contract error that can be converted into the type `E`, such as a
+    /// [`contracterror`][crate::contracterror] type.
+    /// - `Err(Err(InvokeError))` if the invoked contract trapped, or returned
+    /// an error that cannot be converted into the type `E`.
+    ///
+    /// See also [`invoke_contract_checked`][Env::invoke_contract_checked] for
+    /// a variant with the errors flattened into one [InvokeError].
     pub fn try_invoke_contract<T, E>(
         &self,
         contract_address: &Address,
@@ -401,6 +471,8 @@ impl Env {
         E: TryFrom<Error>,
         E::Error: Into<InvokeError>,
     {
+        #[cfg(any(test, feature = "testutils"))]
+        self.reseed_prng_for_invocation();
         let rv = internal::Env::try_call(
             self,
             contract_address.to_object(),
@@ -414,6 +486,68 @@ impl Env {
         }
     }
 
+    /// Invokes a function of a contract that is registered in the [Env],
+    /// returns an error instead of panicking if the invocation fails or the
+    /// returned value cannot be converted into the type `T`.
+    ///
+    /// Useful for calling contracts whose interface is not known in advance,
+    /// where the caller wants to handle every kind of failure:
+    /// - `Err(InvokeError::Abort)` if the invoked contract trapped, such as
+    /// with a [`panic!`], or a host function failed.
+    /// - `Err(InvokeError::Contract(code))` if the invoked contract returned
+    /// or panicked with a contract error.
+    /// - `Ok(Err(ConversionError))` if the invocation succeeded, but the
+    /// returned value is not of type `T`.
+    ///
+    /// This is equivalent to [`try_invoke_contract`][Env::try_invoke_contract]
+    /// with [InvokeError] as the error type.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{contract, contractimpl, symbol_short, vec, Env, IntoVal};
+    ///
+    /// #[contract]
+    /// pub struct Contract;
+    ///
+    /// #[contractimpl]
+    /// impl Contract {
+    ///     pub fn add(a: u32, b: u32) -> u32 {
+    ///         a + b
+    ///     }
+    /// }
+    ///
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    /// let env = Env::default();
+    /// let contract_id = env.register(Contract, ());
+    /// let args = vec![&env, 1u32.into_val(&env), 2u32.into_val(&env)];
+    ///
+    /// let sum = env.invoke_contract_checked::<u32>(&contract_id, &symbol_short!("add"), args.clone());
+    /// assert_eq!(sum, Ok(Ok(3)));
+    ///
+    /// // The return value is not a bool.
+    /// let sum = env.invoke_contract_checked::<bool>(&contract_id, &symbol_short!("add"), args);
+    /// assert!(matches!(sum, Ok(Err(_))));
+    /// # }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    pub fn invoke_contract_checked<T>(
+        &self,
+        contract_address: &Address,
+        func: &crate::Symbol,
+        args: Vec<Val>,
+    ) -> Result<Result<T, ConversionError>, InvokeError>
+    where
+        T: TryFromVal<Env, Val>,
+    {
+        match self.try_invoke_contract::<T, InvokeError>(contract_address, func, args) {
+            Ok(v) => Ok(v.map_err(|_| ConversionError)),
+            Err(Ok(e) | Err(e)) => Err(e),
+        }
+    }
+
     /// Authorizes sub-contract calls on behalf of the current contract.
     ///
     /// All the direct calls that the current contract performs are always
@@ -428,7 +562,19 @@ impl Env {
     /// calls contract B again, then `authorize_as_current_contract` has to be
     /// called again with the respective entries.
     ///
+    /// The entries form a tree: each [`SubContractInvocation`] authorizes one
+    /// call with exactly the given contract, function name and arguments, and
+    /// its `sub_invocations` authorize calls made deeper from that call. Calls
+    /// that don't match an entry are not authorized, so the tree can be used to
+    /// restrict a delegated call to a specific downstream function and
+    /// arguments.
     ///
+    /// The host doesn't expose the authorized tree to contracts for reading.
+    /// Custom accounts see the calls being authorized as the `auth_contexts`
+    /// argument of `__check_auth`, and tests can inspect recorded
+    /// authorizations with `Env::auths` from testutils.
+    ///
+    /// [`SubContractInvocation`]: crate::auth::SubContractInvocation
     pub fn authorize_as_current_contract(&self, auth_entries: Vec<InvokerContractAuthEntry>) {
         internal::Env::authorize_as_curr_contract(self, auth_entries.to_object())
             .unwrap_infallible();
@@ -463,11 +609,11 @@ use crate::testutils::cost_estimate::CostEstimate;
 use crate::{
     auth,
     testutils::{
-        budget::Budget, Address as _, AuthSnapshot, AuthorizedInvocation, ContractFunctionSet,
-        EventsSnapshot, Generators, Ledger as _, MockAuth, MockAuthContract, Register, Snapshot,
-        StellarAssetContract, StellarAssetIssuer,
+        budget::Budget, storage::StorageSnapshot, Address as _, AuthSnapshot, AuthorizedInvocation,
+        ContractFunctionSet, EventsSnapshot, Generators, Ledger as _, MockAuth, MockAuthContract,
+        PrngSnapshot, Register, Snapshot, StellarAssetContract, StellarAssetIssuer,
     },
-    Bytes, BytesN, ConstructorArgs,
+    Bytes, BytesN, ConstructorArgs, Map,
 };
 #[cfg(any(test, feature = "testutils"))]
 use core::{cell::RefCell, cell::RefMut};
@@ -532,6 +678,87 @@ impl Env {
         self.test_state.config = config;
     }
 
+    /// Sets the seed of the base PRNG of the Env.
+    ///
+    /// The base PRNG seeds the local PRNG of each contract invocation, so the
+    /// seed determines the values returned by [`Env::prng`] in all following
+    /// invocations. Two Envs given the same seed that perform the same
+    /// sequence of invocations see the same PRNG values.
+    ///
+    /// Once a seed is set, each top-level invocation made through the Env,
+    /// with a contract client, [`invoke_contract`](Self::invoke_contract),
+    /// [`try_invoke_contract`](Self::try_invoke_contract) or
+    /// [`as_contract`](Self::as_contract), first reseeds the base PRNG with a
+    /// seed derived from this seed and the number of top-level invocations
+    /// made since, so that the PRNG state can be snapshot with
+    /// [`prng_snapshot`](Self::prng_snapshot).
+    ///
+    /// This only affects the test environment. On-chain the base PRNG is
+    /// seeded by the network and cannot be controlled by contracts or their
+    /// callers.
+    pub fn set_prng_seed(&self, seed: BytesN<32>) {
+        let seed = seed.to_array();
+        self.env_impl.set_base_prng_seed(seed).unwrap();
+        *self.test_state.prng_seed.borrow_mut() = PrngSeed {
+            seed: Some(seed),
+            invocations: 0,
+        };
+    }
+
+    /// Takes a snapshot of the base PRNG of the Env, that can be restored with
+    /// [`restore_prng`](Self::restore_prng).
+    ///
+    /// Taking a snapshot does not change the PRNG state, the values seen by
+    /// later invocations are the same whether or not a snapshot is taken. The
+    /// snapshot records the seed set with [`set_prng_seed`](Self::set_prng_seed)
+    /// and the number of top-level invocations made since. The PRNG values
+    /// seen after a restore repeat the values seen after the snapshot was
+    /// taken, given the same sequence of invocations.
+    ///
+    /// This only affects the test environment.
+    ///
+    /// ### Panics
+    ///
+    /// If no seed has been set with [`set_prng_seed`](Self::set_prng_seed).
+    pub fn prng_snapshot(&self) -> PrngSnapshot {
+        let state = self.test_state.prng_seed.borrow();
+        let Some(seed) = state.seed else {
+            panic!("prng_snapshot requires a seed set with set_prng_seed");
+        };
+        PrngSnapshot {
+            seed,
+            invocations: state.invocations,
+        }
+    }
+
+    /// Restores the base PRNG of the Env to a snapshot taken with
+    /// [`prng_snapshot`](Self::prng_snapshot).
+    ///
+    /// This only affects the test environment.
+    pub fn restore_prng(&self, snapshot: &PrngSnapshot) {
+        *self.test_state.prng_seed.borrow_mut() = PrngSeed {
+            seed: Some(snapshot.seed),
+            invocations: snapshot.invocations,
+        };
+    }
+
+    /// Reseeds the base PRNG before a top-level invocation, if a seed has been
+    /// set with [`set_prng_seed`](Self::set_prng_seed).
+    fn reseed_prng_for_invocation(&self) {
+        if self.in_contract() {
+            return;
+        }
+        let mut state = self.test_state.prng_seed.borrow_mut();
+        let Some(mut seed) = state.seed else {
+            return;
+        };
+        for (b, i) in seed[24..].iter_mut().zip(state.invocations.to_be_bytes()) {
+            *b ^= i;
+        }
+        state.invocations = state.invocations.checked_add(1).unwrap();
+        self.env_impl.set_base_prng_seed(seed).unwrap();
+    }
+
     /// Used by multiple constructors to configure test environments consistently.
     fn new_for_testutils(
         config: EnvTestConfig,
@@ -579,6 +806,7 @@ impl Env {
                 generators: generators.unwrap_or_default(),
                 snapshot,
                 auth_snapshot,
+                prng_seed: Default::default(),
             },
         };
 
@@ -1501,6 +1729,7 @@ impl Env {
         let id = id.contract_id();
         let func = Symbol::from_small_str("");
         let mut t: Option<T> = None;
+        self.reseed_prng_for_invocation();
         self.env_impl
             .with_test_contract_frame(id, func, || {
                 t = Some(f());
@@ -1510,6 +1739,97 @@ impl Env {
         t.unwrap()
     }
 
+    /// Get the metadata of the currently executing contract, the key-value
+    /// pairs declared with [`contractmeta!`][crate::contractmeta].
+    ///
+    /// See [`contract_metadata`][Env::contract_metadata].
+    ///
+    /// ### Panics
+    ///
+    /// If not called while executing a contract, or if the contract is not a
+    /// Wasm contract.
+    pub fn current_contract_metadata(&self) -> Map<crate::String, crate::String> {
+        self.contract_metadata(&self.current_contract_address())
+    }
+
+    /// Get the metadata of the contract, the key-value pairs declared with
+    /// [`contractmeta!`][crate::contractmeta].
+    ///
+    /// The metadata is read from the `contractmetav0` custom section of the
+    /// contract's Wasm, which is where `contractmeta!` writes it. The host
+    /// provides no access to a contract's Wasm, so metadata can only be read
+    /// in tests, and only of contracts registered from a Wasm file. Contracts
+    /// registered from Rust code have no Wasm to read the metadata from.
+    ///
+    /// If a key is declared more than once, the last value is returned.
+    ///
+    /// ### Panics
+    ///
+    /// If the contract does not exist, or is not a Wasm contract. If the
+    /// contract's metadata is malformed.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{Env, String};
+    ///
+    /// const WASM: &[u8] = include_bytes!("../doctest_fixtures/contract.wasm");
+    ///
+    /// # fn main() {
+    /// let env = Env::default();
+    /// let id = env.register(WASM, ());
+    /// let meta = env.contract_metadata(&id);
+    /// assert!(meta.contains_key(String::from_str(&env, "rssdkver")));
+    /// # }
+    /// ```
+    pub fn contract_metadata(&self, contract: &Address) -> Map<crate::String, crate::String> {
+        let instance_key = Rc::new(LedgerKey::ContractData(LedgerKeyContractData {
+            contract: xdr::ScAddress::Contract(contract.contract_id()),
+            key: xdr::ScVal::LedgerKeyContractInstance,
+            durability: xdr::ContractDataDurability::Persistent,
+        }));
+        let Some((instance, _)) = self.host().get_ledger_entry(&instance_key).unwrap() else {
+            panic!("contract {contract:?} does not exist");
+        };
+        let hash = match &instance.data {
+            xdr::LedgerEntryData::ContractData(xdr::ContractDataEntry {
+                val:
+                    xdr::ScVal::ContractInstance(xdr::ScContractInstance {
+                        executable: xdr::ContractExecutable::Wasm(hash),
+                        ..
+                    }),
+                ..
+            }) => hash.clone(),
+            _ => panic!("contract {contract:?} is not a wasm contract"),
+        };
+        let code_key = Rc::new(LedgerKey::ContractCode(xdr::LedgerKeyContractCode { hash }));
+        let code = match self.host().get_ledger_entry(&code_key).unwrap() {
+            Some((entry, _)) => match &entry.data {
+                xdr::LedgerEntryData::ContractCode(code) => code.code.to_vec(),
+                _ => panic!("expected contract code entry but got {:?}", entry.data),
+            },
+            None => std::vec::Vec::new(),
+        };
+        // Contracts registered from Rust code have a Wasm executable with no
+        // Wasm code.
+        if !code.starts_with(b"\0asm") {
+            panic!("contract {contract:?} is not a wasm contract");
+        }
+
+        let mut meta = Map::new(self);
+        for section in wasm_custom_sections(&code, "contractmetav0") {
+            let mut reader = xdr::Limited::new(std::io::Cursor::new(section), xdr::Limits::none());
+            for entry in <xdr::ScMetaEntry as xdr::ReadXdr>::read_xdr_iter(&mut reader) {
+                let xdr::ScMetaEntry::ScMetaV0(entry) = entry.unwrap();
+                meta.set(
+                    crate::String::from_bytes(self, entry.key.as_slice()),
+                    crate::String::from_bytes(self, entry.val.as_slice()),
+                );
+            }
+        }
+        meta
+    }
+
     /// Creates a new Env loaded with the [`Snapshot`].
     ///
     /// The ledger info and state in the snapshot are loaded into the Env.
@@ -1580,6 +1900,20 @@ impl Env {
     }
 
     /// Create a snapshot from the Env's current state.
+    ///
+    /// The snapshot contains the ledger info, such as the sequence number and
+    /// timestamp, and every ledger entry with its TTL. Loading it with
+    /// [`from_ledger_snapshot`][Env::from_ledger_snapshot], or writing it
+    /// with [`to_ledger_snapshot_file`][Env::to_ledger_snapshot_file] and
+    /// loading it with
+    /// [`from_ledger_snapshot_file`][Env::from_ledger_snapshot_file], restores
+    /// that state, so a ledger set up once can be shared by many tests as a
+    /// fixture.
+    ///
+    /// Contracts registered with [`register`][Env::register] from Rust code
+    /// are not stored in the snapshot as code, and must be registered again
+    /// at the same address with [`register_at`][Env::register_at] to be
+    /// invoked.
     pub fn to_ledger_snapshot(&self) -> LedgerSnapshot {
         let snapshot = self.test_state.snapshot.clone().unwrap_or_default();
         let mut snapshot = (*snapshot).clone();
@@ -1588,6 +1922,37 @@ impl Env {
         snapshot
     }
 
+    /// Create a snapshot of the values in the storage of every contract in
+    /// the Env.
+    ///
+    /// Compare two snapshots with [`StorageSnapshot::diff`] or
+    /// [`assert_storage_unchanged`][crate::testutils::storage::assert_storage_unchanged]
+    /// to check which keys a call modified.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{contract, Env, testutils::storage::assert_storage_unchanged};
+    ///
+    /// #[contract]
+    /// pub struct Contract;
+    ///
+    /// # fn main() {
+    /// let env = Env::default();
+    /// let id = env.register(Contract, ());
+    ///
+    /// let before = env.storage_snapshot();
+    /// env.as_contract(&id, || env.storage().persistent().set(&1, &2));
+    /// let after = env.storage_snapshot();
+    /// assert_eq!(before.diff(&after).len(), 1);
+    ///
+    /// assert_storage_unchanged(&after, &env.storage_snapshot());
+    /// # }
+    /// ```
+    pub fn storage_snapshot(&self) -> StorageSnapshot {
+        StorageSnapshot::from_ledger_snapshot(&self.to_ledger_snapshot())
+    }
+
     /// Create a snapshot file from the Env's current state.
     ///
     /// ### Panics
@@ -1972,3 +2337,41 @@ macro_rules! impl_env_for_sdk {
 
 // Here we invoke the x-macro passing generate_env_trait as its callback macro.
 internal::call_macro_with_all_host_functions! { impl_env_for_sdk }
+
+// Returns the contents of the custom sections with the name in the Wasm, in
+// the order they appear.
+#[cfg(any(test, feature = "testutils"))]
+fn wasm_custom_sections<'a>(wasm: &'a [u8], name: &str) -> std::vec::Vec<&'a [u8]> {
+    fn leb128(wasm: &[u8], pos: &mut usize) -> usize {
+        let mut n = 0;
+        let mut shift = 0;
+        loop {
+            let b = wasm[*pos];
+            *pos += 1;
+            n |= ((b & 0x7f) as usize) << shift;
+            if b & 0x80 == 0 {
+                return n;
+            }
+            shift += 7;
+        }
+    }
+
+    // Skip the magic number and version.
+    let mut pos = 8;
+    let mut sections = std::vec::Vec::new();
+    while pos < wasm.len() {
+        let id = wasm[pos];
+        pos += 1;
+        let size = leb128(wasm, &mut pos);
+        let end = pos + size;
+        // Custom sections have the id 0, and start with their name.
+        if id == 0 {
+            let name_len = leb128(wasm, &mut pos);
+            if &wasm[pos..pos + name_len] == name.as_bytes() {
+                sections.push(&wasm[pos + name_len..end]);
+            }
+        }
+        pos = end;
+    }
+    sections
+}
@@ -8,6 +8,9 @@ use crate::{env::internal, unwrap::UnwrapInfallible, Env, IntoVal, Val, Vec};
 // TODO: consolidate with host::events::TOPIC_BYTES_LENGTH_LIMIT
 const TOPIC_BYTES_LENGTH_LIMIT: u32 = 32;
 
+// Maximum number of topics an event can have.
+const MAX_TOPICS: u32 = 4;
+
 /// Events publishes events for the currently executing contract.
 ///
 /// ```
@@ -90,6 +93,19 @@ impl Events {
             .unwrap_infallible();
     }
 
+    /// Returns an [`EventBuilder`] for building and publishing an event one
+    /// topic at a time.
+    ///
+    /// Consider using [`contractevent`][crate::contractevent] instead when the
+    /// topics of the event are known up front.
+    #[inline(always)]
+    pub fn builder(&self) -> EventBuilder {
+        EventBuilder {
+            env: self.env().clone(),
+            topics: Vec::new(self.env()),
+        }
+    }
+
     /// Publish an event.
     ///
     /// Consider using [`contractevent`][crate::contractevent] instead of this function.
@@ -116,6 +132,86 @@ impl Events {
     }
 }
 
+/// EventBuilder builds an event one topic at a time and publishes it.
+///
+/// Created with [`Events::builder`]. The event published is identical to one
+/// published with the same topics and data as a tuple.
+///
+/// ```
+/// use soroban_sdk::{symbol_short, Address, Env};
+///
+/// # use soroban_sdk::{contract, contractimpl};
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env, to: Option<Address>) {
+/// let mut event = env.events().builder();
+/// event.topic(symbol_short!("transfer"));
+/// if let Some(to) = to {
+///     event.topic(to);
+/// }
+/// event.publish_data(100i128);
+/// #     }
+/// # }
+///
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = env.register(Contract, ());
+/// #     ContractClient::new(&env, &contract_id).f(&None);
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+#[derive(Clone)]
+pub struct EventBuilder {
+    env: Env,
+    topics: Vec<Val>,
+}
+
+impl Debug for EventBuilder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EventBuilder")
+    }
+}
+
+impl EventBuilder {
+    /// Adds a topic to the event.
+    ///
+    /// Event topics must not contain:
+    ///
+    /// - [Vec]
+    /// - [Map]
+    /// - [Bytes]/[BytesN][crate::BytesN] longer than 32 bytes
+    /// - [contracttype]
+    ///
+    /// ### Panics
+    ///
+    /// If the event already has 4 topics.
+    pub fn topic(&mut self, topic: impl IntoVal<Env, Val>) -> &mut Self {
+        if self.topics.len() >= MAX_TOPICS {
+            sdk_panic!("event topics are limited to 4");
+        }
+        self.topics.push_back(topic.into_val(&self.env));
+        self
+    }
+
+    /// Returns the number of topics added to the event.
+    pub fn topics_len(&self) -> u32 {
+        self.topics.len()
+    }
+
+    /// Publishes the event with the topics added and the given data.
+    pub fn publish_data(&self, data: impl IntoVal<Env, Val>) {
+        let env = &self.env;
+        internal::Env::contract_event(env, self.topics.to_object(), data.into_val(env))
+            .unwrap_infallible();
+    }
+}
+
 #[cfg(any(test, feature = "testutils"))]
 use crate::{testutils, xdr, Address, TryIntoVal};
 
@@ -0,0 +1,279 @@
+use crate::num::{mul_div_u128, Rounding};
+
+/// FixedPoint is a signed decimal number with a fixed number of decimal
+/// places, stored as an `i128` scaled by `10^DECIMALS`.
+///
+/// For example a `FixedPoint<7>` with the raw value `12_500_000` represents
+/// `1.25`, the same way token amounts with 7 decimals are represented.
+///
+/// ### Rounding
+///
+/// Operations that cannot be represented exactly round half up: the result
+/// is rounded to the nearest representable value, and ties are rounded away
+/// from zero. For example with zero decimals `5 / 2` is `3` and `-5 / 2` is
+/// `-3`.
+///
+/// Products and quotients are computed with a 256-bit intermediate value, so
+/// they only overflow if the final result does not fit.
+///
+/// ### Panics
+///
+/// Operations panic if the result overflows an `i128`, or on division by
+/// zero.
+///
+/// ### Storage
+///
+/// FixedPoint is a plain Rust value, and is not a contract type. Store and
+/// pass the raw value returned by [`to_raw`][FixedPoint::to_raw] instead.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::FixedPoint;
+///
+/// type Price = FixedPoint<7>;
+///
+/// let price = Price::from_raw(12_500_000); // 1.25
+/// let amount = Price::from_integer(10);
+/// assert_eq!(amount.mul(&price), Price::from_raw(125_000_000)); // 12.5
+/// assert_eq!(amount.mul(&price).to_integer(), 13);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedPoint<const DECIMALS: u32>(i128);
+
+impl<const DECIMALS: u32> FixedPoint<DECIMALS> {
+    /// The raw value that represents one.
+    pub const SCALE: i128 = 10i128.pow(DECIMALS);
+
+    /// The fixed point value zero.
+    pub const ZERO: Self = FixedPoint(0);
+
+    /// Create a FixedPoint from its raw value, which is the value multiplied
+    /// by [`SCALE`][FixedPoint::SCALE].
+    #[inline(always)]
+    pub const fn from_raw(raw: i128) -> Self {
+        FixedPoint(raw)
+    }
+
+    /// Returns the raw value, which is the value multiplied by
+    /// [`SCALE`][FixedPoint::SCALE].
+    #[inline(always)]
+    pub const fn to_raw(&self) -> i128 {
+        self.0
+    }
+
+    /// Create a FixedPoint from an integer.
+    ///
+    /// ### Panics
+    ///
+    /// If the integer multiplied by [`SCALE`][FixedPoint::SCALE] overflows.
+    pub fn from_integer(i: i128) -> Self {
+        match i.checked_mul(Self::SCALE) {
+            Some(raw) => FixedPoint(raw),
+            None => sdk_panic!("fixed point overflow"),
+        }
+    }
+
+    /// Returns the value rounded half up to an integer.
+    pub fn to_integer(&self) -> i128 {
+        mul_div(self.0, 1, Self::SCALE)
+    }
+
+    /// Returns the sum of self and other.
+    ///
+    /// ### Panics
+    ///
+    /// If the sum overflows.
+    pub fn add(&self, other: &Self) -> Self {
+        match self.0.checked_add(other.0) {
+            Some(raw) => FixedPoint(raw),
+            None => sdk_panic!("fixed point overflow"),
+        }
+    }
+
+    /// Returns self minus other.
+    ///
+    /// ### Panics
+    ///
+    /// If the difference overflows.
+    pub fn sub(&self, other: &Self) -> Self {
+        match self.0.checked_sub(other.0) {
+            Some(raw) => FixedPoint(raw),
+            None => sdk_panic!("fixed point overflow"),
+        }
+    }
+
+    /// Returns the product of self and other, rounded half up.
+    ///
+    /// ### Panics
+    ///
+    /// If the product overflows.
+    pub fn mul(&self, other: &Self) -> Self {
+        FixedPoint(mul_div(self.0, other.0, Self::SCALE))
+    }
+
+    /// Returns self divided by other, rounded half up.
+    ///
+    /// ### Panics
+    ///
+    /// If other is zero, or if the quotient overflows.
+    pub fn div(&self, other: &Self) -> Self {
+        FixedPoint(mul_div(self.0, Self::SCALE, other.0))
+    }
+}
+
+// Returns `a * b / c` rounded half away from zero.
+fn mul_div(a: i128, b: i128, c: i128) -> i128 {
+    if c == 0 {
+        sdk_panic!("fixed point division by zero");
+    }
+    let negative = (a < 0) ^ (b < 0) ^ (c < 0);
+    let magnitude = mul_div_u128(
+        a.unsigned_abs(),
+        b.unsigned_abs(),
+        c.unsigned_abs(),
+        Rounding::HalfUp,
+    );
+    match magnitude {
+        Some(m) if negative && m <= i128::MIN.unsigned_abs() => (m as i128).wrapping_neg(),
+        Some(m) if !negative && m <= i128::MAX as u128 => m as i128,
+        _ => sdk_panic!("fixed point overflow"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type F0 = FixedPoint<0>;
+    type F7 = FixedPoint<7>;
+    type F18 = FixedPoint<18>;
+
+    #[test]
+    fn test_integer_round_trip() {
+        assert_eq!(F7::SCALE, 10_000_000);
+        assert_eq!(F7::from_integer(42).to_raw(), 420_000_000);
+        assert_eq!(F7::from_integer(42).to_integer(), 42);
+        assert_eq!(F7::from_integer(-42).to_integer(), -42);
+        assert_eq!(F0::from_integer(i128::MAX).to_integer(), i128::MAX);
+        assert_eq!(F0::from_integer(i128::MIN).to_integer(), i128::MIN);
+    }
+
+    #[test]
+    fn test_to_integer_rounds_half_up() {
+        assert_eq!(F7::from_raw(14_999_999).to_integer(), 1);
+        assert_eq!(F7::from_raw(15_000_000).to_integer(), 2);
+        assert_eq!(F7::from_raw(-14_999_999).to_integer(), -1);
+        assert_eq!(F7::from_raw(-15_000_000).to_integer(), -2);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed point overflow")]
+    fn test_from_integer_overflow() {
+        F18::from_integer(i128::MAX / 10i128.pow(18) + 1);
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let a = F7::from_raw(12_500_000);
+        let b = F7::from_raw(2_500_000);
+        assert_eq!(a.add(&b), F7::from_raw(15_000_000));
+        assert_eq!(a.sub(&b), F7::from_integer(1));
+        assert_eq!(b.sub(&a), F7::from_integer(-1));
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed point overflow")]
+    fn test_add_overflow() {
+        F7::from_raw(i128::MAX).add(&F7::from_raw(1));
+    }
+
+    #[test]
+    fn test_mul() {
+        let a = F7::from_raw(12_500_000); // 1.25
+        let b = F7::from_raw(-30_000_000); // -3
+        assert_eq!(a.mul(&b), F7::from_raw(-37_500_000));
+        assert_eq!(a.mul(&F7::ZERO), F7::ZERO);
+
+        // 0.0000001 * 0.5 is exactly half the smallest unit, and rounds up.
+        let min = F7::from_raw(1);
+        let half = F7::from_raw(5_000_000);
+        assert_eq!(min.mul(&half), F7::from_raw(1));
+        assert_eq!(min.mul(&half.sub(&min)), F7::ZERO);
+        assert_eq!(F7::from_raw(-1).mul(&half), F7::from_raw(-1));
+    }
+
+    #[test]
+    fn test_mul_wide_intermediate() {
+        // The raw product of 10^20 and 10^22 overflows an i128, but the raw
+        // result 10^24 fits.
+        let a = F18::from_integer(100);
+        let b = F18::from_integer(10_000);
+        assert_eq!(a.mul(&b), F18::from_integer(1_000_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed point overflow")]
+    fn test_mul_overflow() {
+        F18::from_raw(i128::MAX).mul(&F18::from_integer(2));
+    }
+
+    #[test]
+    fn test_div_exact() {
+        assert_eq!(
+            F7::from_integer(10).div(&F7::from_integer(4)),
+            F7::from_raw(25_000_000)
+        );
+        assert_eq!(
+            F7::from_integer(-10).div(&F7::from_integer(4)),
+            F7::from_raw(-25_000_000)
+        );
+        assert_eq!(
+            F0::from_integer(6).div(&F0::from_integer(3)),
+            F0::from_integer(2)
+        );
+    }
+
+    #[test]
+    fn test_div_rounded() {
+        // 1 / 3 = 0.33333333..., rounded down.
+        assert_eq!(
+            F7::from_integer(1).div(&F7::from_integer(3)),
+            F7::from_raw(3_333_333)
+        );
+        // 2 / 3 = 0.66666666..., rounded up.
+        assert_eq!(
+            F7::from_integer(2).div(&F7::from_integer(3)),
+            F7::from_raw(6_666_667)
+        );
+        // Ties round away from zero.
+        assert_eq!(
+            F0::from_integer(5).div(&F0::from_integer(2)),
+            F0::from_integer(3)
+        );
+        assert_eq!(
+            F0::from_integer(-5).div(&F0::from_integer(2)),
+            F0::from_integer(-3)
+        );
+        assert_eq!(
+            F0::from_integer(7).div(&F0::from_integer(2)),
+            F0::from_integer(4)
+        );
+        assert_eq!(
+            F0::from_integer(7).div(&F0::from_integer(3)),
+            F0::from_integer(2)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed point division by zero")]
+    fn test_div_by_zero() {
+        F7::from_integer(1).div(&F7::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed point overflow")]
+    fn test_div_overflow() {
+        F7::from_raw(i128::MAX).div(&F7::from_raw(1));
+    }
+}
@@ -0,0 +1,5 @@
+//! Interop contains functions for working with data from other blockchains,
+//! such as Ethereum.
+
+pub mod abi;
+pub mod rlp;
@@ -0,0 +1,260 @@
+//! ABI contains functions for encoding and decoding data in the Ethereum
+//! contract ABI, such as the calldata of a call to an EVM contract.
+//!
+//! The supported types are `uint256`, `address`, `bool`, and the dynamic type
+//! `bytes`. Values are encoded in order as a head of one 32 byte word per
+//! value, followed by a tail holding the contents of the dynamic values:
+//!
+//! - `uint256`: the value as big-endian.
+//! - `address`: the 20 byte address, left padded with zeros.
+//! - `bool`: `0` or `1` as a `uint256`.
+//! - `bytes`: the offset of its contents from the start of the head, as a
+//!   `uint256`. The contents in the tail are the length as a `uint256`,
+//!   followed by the bytes right padded with zeros to a multiple of 32 bytes.
+//!
+//! Calldata is the 4 byte [selector] of the function, followed by the encoded
+//! arguments.
+use crate::{Bytes, BytesN, ConversionError, Env, Vec, U256};
+
+const WORD: u32 = 32;
+
+/// Returns the selector of the function with the given signature, which is
+/// the first 4 bytes of the keccak256 hash of the signature.
+///
+/// The signature is the function name followed by its parameter types, with
+/// no spaces or parameter names, for example `transfer(address,uint256)`.
+pub fn selector(env: &Env, signature: &str) -> BytesN<4> {
+    let hash = env
+        .crypto()
+        .keccak256(&Bytes::from_slice(env, signature.as_bytes()))
+        .to_array();
+    BytesN::from_array(env, &[hash[0], hash[1], hash[2], hash[3]])
+}
+
+/// Encoder encodes values in the ABI encoding.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{interop::abi::{self, Encoder}, BytesN, Env, U256};
+///
+/// let env = Env::default();
+/// let to = BytesN::from_array(&env, &[0x11; 20]);
+/// let calldata = Encoder::new_call(&env, &abi::selector(&env, "transfer(address,uint256)"))
+///     .address(&to)
+///     .uint(&U256::from_u32(&env, 1000))
+///     .build();
+/// assert_eq!(calldata.len(), 4 + 2 * 32);
+/// ```
+#[derive(Clone)]
+pub struct Encoder {
+    prefix: Bytes,
+    head: Bytes,
+    tail: Bytes,
+    // The position in the head and the offset in the tail of each dynamic
+    // value, as the position of the tail is not known until all the values
+    // are encoded.
+    dynamic: Vec<(u32, u32)>,
+}
+
+impl Encoder {
+    /// Create an encoder with no values.
+    pub fn new(env: &Env) -> Self {
+        Encoder {
+            prefix: Bytes::new(env),
+            head: Bytes::new(env),
+            tail: Bytes::new(env),
+            dynamic: Vec::new(env),
+        }
+    }
+
+    /// Create an encoder for the calldata of a call to the function with the
+    /// selector.
+    pub fn new_call(env: &Env, selector: &BytesN<4>) -> Self {
+        let mut encoder = Self::new(env);
+        encoder.prefix.append(selector.as_ref());
+        encoder
+    }
+
+    /// Appends a `uint256`.
+    pub fn uint(&mut self, value: &U256) -> &mut Self {
+        self.head.append(&value.to_be_bytes());
+        self
+    }
+
+    /// Appends an `address`.
+    pub fn address(&mut self, address: &BytesN<20>) -> &mut Self {
+        self.head.extend_from_array(&[0u8; 12]);
+        self.head.append(address.as_ref());
+        self
+    }
+
+    /// Appends a `bool`.
+    pub fn bool(&mut self, value: bool) -> &mut Self {
+        self.head.extend_from_array(&u32_word(value as u32));
+        self
+    }
+
+    /// Appends a dynamic `bytes`.
+    pub fn bytes(&mut self, bytes: &Bytes) -> &mut Self {
+        self.dynamic.push_back((self.head.len(), self.tail.len()));
+        // The offset is filled in when the encoding is built.
+        self.head.extend_from_array(&[0u8; WORD as usize]);
+        self.tail.extend_from_array(&u32_word(bytes.len()));
+        self.tail.append(bytes);
+        let padding = (WORD - bytes.len() % WORD) % WORD;
+        self.tail
+            .extend_from_slice(&[0u8; WORD as usize][..padding as usize]);
+        self
+    }
+
+    /// Returns the encoded values.
+    #[must_use]
+    pub fn build(&self) -> Bytes {
+        let mut head = self.head.clone();
+        for (position, offset) in self.dynamic.iter() {
+            head.copy_from_slice(position, &u32_word(self.head.len() + offset));
+        }
+        let mut bytes = self.prefix.clone();
+        bytes.append(&head);
+        bytes.append(&self.tail);
+        bytes
+    }
+}
+
+/// Decoder decodes values in the ABI encoding.
+///
+/// Values are decoded by their position in the encoding, so that `0` is the
+/// first value. The decoder must be told the type of each value, as the
+/// encoding does not contain the types.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{interop::abi::{self, Decoder, Encoder}, BytesN, Env, U256};
+///
+/// let env = Env::default();
+/// let transfer = abi::selector(&env, "transfer(address,uint256)");
+/// let to = BytesN::from_array(&env, &[0x11; 20]);
+/// let amount = U256::from_u32(&env, 1000);
+/// let calldata = Encoder::new_call(&env, &transfer).address(&to).uint(&amount).build();
+///
+/// let decoder = Decoder::new_call(&calldata, &transfer).unwrap();
+/// assert_eq!(decoder.address(0), Ok(to));
+/// assert_eq!(decoder.uint(1), Ok(amount));
+/// ```
+#[derive(Clone)]
+pub struct Decoder {
+    data: Bytes,
+}
+
+impl Decoder {
+    /// Create a decoder for the encoded values.
+    pub fn new(data: &Bytes) -> Self {
+        Decoder { data: data.clone() }
+    }
+
+    /// Create a decoder for the arguments in the calldata of a call to the
+    /// function with the selector.
+    ///
+    /// ### Errors
+    ///
+    /// If the calldata does not start with the selector.
+    pub fn new_call(calldata: &Bytes, selector: &BytesN<4>) -> Result<Self, ConversionError> {
+        if calldata.len() < 4 || calldata.slice(..4) != *selector.as_ref() {
+            return Err(ConversionError);
+        }
+        Ok(Decoder {
+            data: calldata.slice(4..),
+        })
+    }
+
+    /// Returns the `uint256` at the position.
+    ///
+    /// ### Errors
+    ///
+    /// If the position is out-of-bounds.
+    pub fn uint(&self, i: u32) -> Result<U256, ConversionError> {
+        let word = self.word(head_position(i)?)?;
+        Ok(U256::from_be_bytes(
+            self.data.env(),
+            &Bytes::from_array(self.data.env(), &word),
+        ))
+    }
+
+    /// Returns the `address` at the position.
+    ///
+    /// ### Errors
+    ///
+    /// If the position is out-of-bounds, or the address is not padded with
+    /// zeros.
+    pub fn address(&self, i: u32) -> Result<BytesN<20>, ConversionError> {
+        let word = self.word(head_position(i)?)?;
+        if word[..12] != [0u8; 12] {
+            return Err(ConversionError);
+        }
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&word[12..]);
+        Ok(BytesN::from_array(self.data.env(), &address))
+    }
+
+    /// Returns the `bool` at the position.
+    ///
+    /// ### Errors
+    ///
+    /// If the position is out-of-bounds, or the value is not `0` or `1`.
+    pub fn bool(&self, i: u32) -> Result<bool, ConversionError> {
+        match self.u32(head_position(i)?)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ConversionError),
+        }
+    }
+
+    /// Returns the dynamic `bytes` at the position.
+    ///
+    /// ### Errors
+    ///
+    /// If the position is out-of-bounds, or the offset or length of the
+    /// contents are out-of-bounds.
+    pub fn bytes(&self, i: u32) -> Result<Bytes, ConversionError> {
+        let offset = self.u32(head_position(i)?)?;
+        let len = self.u32(offset)?;
+        let start = offset + WORD;
+        let end = start.checked_add(len).ok_or(ConversionError)?;
+        if end > self.data.len() {
+            return Err(ConversionError);
+        }
+        Ok(self.data.slice(start..end))
+    }
+
+    // Returns the word at the position in the data.
+    fn word(&self, position: u32) -> Result<[u8; 32], ConversionError> {
+        let end = position.checked_add(WORD).ok_or(ConversionError)?;
+        if end > self.data.len() {
+            return Err(ConversionError);
+        }
+        let mut word = [0u8; 32];
+        self.data.slice(position..end).copy_into_slice(&mut word);
+        Ok(word)
+    }
+
+    // Returns the word at the position in the data, as a `u32`.
+    fn u32(&self, position: u32) -> Result<u32, ConversionError> {
+        let word = self.word(position)?;
+        if word[..28] != [0u8; 28] {
+            return Err(ConversionError);
+        }
+        Ok(u32::from_be_bytes([word[28], word[29], word[30], word[31]]))
+    }
+}
+
+fn head_position(i: u32) -> Result<u32, ConversionError> {
+    i.checked_mul(WORD).ok_or(ConversionError)
+}
+
+fn u32_word(value: u32) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[28..].copy_from_slice(&value.to_be_bytes());
+    word
+}
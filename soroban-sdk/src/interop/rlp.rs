@@ -0,0 +1,239 @@
+//! RLP contains functions for decoding Ethereum's Recursive Length Prefix
+//! encoding, such as used by transactions, receipts, and Merkle-Patricia
+//! proofs.
+//!
+//! An RLP item is either a byte string or a list of items. Decoding is strict,
+//! and only accepts the canonical encoding of each item:
+//!
+//! - A single byte below `0x80` is encoded as itself, not as a string of
+//!   length one.
+//! - Lengths below 56 are encoded in the prefix, not in the long form.
+//! - Lengths in the long form have no leading zero bytes.
+//!
+//! Lists can be nested at most [`MAX_DEPTH`] levels deep.
+use crate::{Bytes, BytesN, ConversionError};
+
+/// The maximum depth that lists can be nested to in an item passed to
+/// [`decode`].
+pub const MAX_DEPTH: u32 = 64;
+
+/// An item decoded from RLP.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RlpItem {
+    /// A byte string.
+    Bytes(Bytes),
+    /// A list of items.
+    List(RlpList),
+}
+
+/// A list of items decoded from RLP.
+///
+/// The list holds the encoded items, and decodes each item as it is accessed.
+/// The items were validated when the list was decoded, so accessing them does
+/// not fail.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RlpList {
+    payload: Bytes,
+}
+
+/// Decodes the RLP encoded item that makes up all of `bytes`.
+///
+/// ### Errors
+///
+/// If the encoding is not the canonical encoding of an item, is truncated, is
+/// followed by trailing bytes, or nests lists more than [`MAX_DEPTH`] levels
+/// deep.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{interop::rlp, Bytes, Env};
+///
+/// let env = Env::default();
+/// // The list ["cat", "dog"].
+/// let encoded = Bytes::from_array(&env, &[0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g']);
+///
+/// let item = rlp::decode(&encoded).unwrap();
+/// let list = item.as_list().unwrap();
+/// assert_eq!(list.len(), 2);
+/// assert_eq!(
+///     list.get(1).unwrap().as_bytes().unwrap(),
+///     &Bytes::from_slice(&env, b"dog")
+/// );
+/// ```
+pub fn decode(bytes: &Bytes) -> Result<RlpItem, ConversionError> {
+    let (item, end) = read_item(bytes, 0)?;
+    if end != bytes.len() {
+        return Err(ConversionError);
+    }
+    validate(&item, 1)?;
+    Ok(item)
+}
+
+impl RlpItem {
+    /// Returns the byte string.
+    ///
+    /// ### Errors
+    ///
+    /// If the item is a list.
+    pub fn as_bytes(&self) -> Result<&Bytes, ConversionError> {
+        match self {
+            RlpItem::Bytes(b) => Ok(b),
+            RlpItem::List(_) => Err(ConversionError),
+        }
+    }
+
+    /// Returns the list.
+    ///
+    /// ### Errors
+    ///
+    /// If the item is a byte string.
+    pub fn as_list(&self) -> Result<&RlpList, ConversionError> {
+        match self {
+            RlpItem::Bytes(_) => Err(ConversionError),
+            RlpItem::List(l) => Ok(l),
+        }
+    }
+
+    /// Returns the byte string as a big-endian unsigned integer.
+    ///
+    /// ### Errors
+    ///
+    /// If the item is a list, is longer than 8 bytes, or has a leading zero
+    /// byte. The integer zero is encoded as the empty byte string.
+    pub fn to_u64(&self) -> Result<u64, ConversionError> {
+        let bytes = self.as_bytes()?;
+        let len = bytes.len() as usize;
+        if len > 8 || bytes.first() == Some(0) {
+            return Err(ConversionError);
+        }
+        let mut buf = [0u8; 8];
+        bytes.copy_into_slice(&mut buf[8 - len..]);
+        Ok(u64::from_be_bytes(buf))
+    }
+
+    /// Returns the byte string as an Ethereum address.
+    ///
+    /// ### Errors
+    ///
+    /// If the item is a list, or is not exactly 20 bytes.
+    pub fn to_address(&self) -> Result<BytesN<20>, ConversionError> {
+        self.as_bytes()?.try_into()
+    }
+}
+
+impl RlpList {
+    /// Returns the number of items in the list.
+    pub fn len(&self) -> u32 {
+        self.iter().count() as u32
+    }
+
+    /// Returns true if the list has no items.
+    pub fn is_empty(&self) -> bool {
+        self.payload.is_empty()
+    }
+
+    /// Returns the item at the position or None if out-of-bounds.
+    pub fn get(&self, i: u32) -> Option<RlpItem> {
+        self.iter().nth(i as usize)
+    }
+
+    /// Returns an iterator over the items in the list.
+    pub fn iter(&self) -> RlpListIter {
+        RlpListIter {
+            payload: self.payload.clone(),
+            offset: 0,
+        }
+    }
+}
+
+/// An iterator over the items of an [`RlpList`].
+#[derive(Clone)]
+pub struct RlpListIter {
+    payload: Bytes,
+    offset: u32,
+}
+
+impl Iterator for RlpListIter {
+    type Item = RlpItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.payload.len() {
+            return None;
+        }
+        let (item, end) = read_item(&self.payload, self.offset).ok()?;
+        self.offset = end;
+        Some(item)
+    }
+}
+
+// Reads the item at `offset`, and returns it with the offset of the byte after
+// it. Items nested in a returned list are not validated.
+fn read_item(bytes: &Bytes, offset: u32) -> Result<(RlpItem, u32), ConversionError> {
+    let prefix = bytes.get(offset).ok_or(ConversionError)?;
+    let (list, start, len) = match prefix {
+        0x00..=0x7f => return Ok((RlpItem::Bytes(bytes.slice(offset..=offset)), offset + 1)),
+        0x80..=0xb7 => (false, offset + 1, (prefix - 0x80) as u32),
+        0xb8..=0xbf => {
+            let (start, len) = read_long_len(bytes, offset, prefix - 0xb7)?;
+            (false, start, len)
+        }
+        0xc0..=0xf7 => (true, offset + 1, (prefix - 0xc0) as u32),
+        0xf8..=0xff => {
+            let (start, len) = read_long_len(bytes, offset, prefix - 0xf7)?;
+            (true, start, len)
+        }
+    };
+    let end = start.checked_add(len).ok_or(ConversionError)?;
+    if end > bytes.len() {
+        return Err(ConversionError);
+    }
+    let payload = bytes.slice(start..end);
+    if list {
+        Ok((RlpItem::List(RlpList { payload }), end))
+    } else if len == 1 && payload.first_unchecked() < 0x80 {
+        // A single byte below 0x80 must be encoded as itself.
+        Err(ConversionError)
+    } else {
+        Ok((RlpItem::Bytes(payload), end))
+    }
+}
+
+// Reads the long form length that follows the prefix at `offset`, and returns
+// the offset of the payload and its length.
+fn read_long_len(
+    bytes: &Bytes,
+    offset: u32,
+    len_of_len: u8,
+) -> Result<(u32, u32), ConversionError> {
+    let start = offset + 1 + len_of_len as u32;
+    let mut len: u64 = 0;
+    for i in offset + 1..start {
+        let b = bytes.get(i).ok_or(ConversionError)?;
+        if len == 0 && b == 0 {
+            return Err(ConversionError);
+        }
+        len = (len << 8) | b as u64;
+    }
+    if len < 56 {
+        return Err(ConversionError);
+    }
+    Ok((start, len.try_into().map_err(|_| ConversionError)?))
+}
+
+// Validates the items nested in a list, at every depth. `depth` is the depth
+// of `item`, starting at one for the outermost item.
+fn validate(item: &RlpItem, depth: u32) -> Result<(), ConversionError> {
+    if let RlpItem::List(list) = item {
+        if depth > MAX_DEPTH {
+            return Err(ConversionError);
+        }
+        let mut offset = 0;
+        while offset < list.payload.len() {
+            let (item, end) = read_item(&list.payload, offset)?;
+            validate(&item, depth + 1)?;
+            offset = end;
+        }
+    }
+    Ok(())
+}
@@ -91,6 +91,31 @@ impl Ledger {
             .unwrap()
     }
 
+    /// Returns the ledger timestamp plus `secs` seconds.
+    ///
+    /// Useful for computing deadlines relative to the current ledger, such as
+    /// `env.ledger().timestamp_plus(duration)`.
+    ///
+    /// ### Panics
+    ///
+    /// If the addition overflows a `u64`.
+    pub fn timestamp_plus(&self, secs: u64) -> u64 {
+        match self.timestamp().checked_add(secs) {
+            Some(t) => t,
+            None => sdk_panic!("ledger timestamp overflow"),
+        }
+    }
+
+    /// Returns true if the ledger timestamp is strictly after `t`.
+    pub fn is_after(&self, t: u64) -> bool {
+        self.timestamp() > t
+    }
+
+    /// Returns true if the ledger timestamp is strictly before `t`.
+    pub fn is_before(&self, t: u64) -> bool {
+        self.timestamp() < t
+    }
+
     /// Returns the network identifier.
     ///
     /// This is SHA-256 hash of the network passphrase, for example
@@ -106,6 +131,80 @@ impl Ledger {
     }
 }
 
+/// Clock provides the current ledger time.
+///
+/// Business logic that only depends on the time, such as vesting schedules or
+/// auction deadlines, can take a `&dyn Clock` or `impl Clock` instead of an
+/// [Env]. In contracts the clock is an [EnvClock]. In tests the logic can be
+/// unit tested without an [Env] by using a fixed clock, such as
+/// `testutils::FixedClock`.
+///
+/// Contracts that read the time from [Env] directly are unaffected.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::ledger::{Clock, EnvClock};
+///
+/// fn is_expired(clock: &impl Clock, deadline: u64) -> bool {
+///     clock.timestamp() > deadline
+/// }
+///
+/// # use soroban_sdk::{contract, contractimpl, Env};
+/// #
+/// # #[contract]
+/// # pub struct Contract;
+/// #
+/// # #[contractimpl]
+/// # impl Contract {
+/// #     pub fn f(env: Env) -> bool {
+/// let expired = is_expired(&EnvClock::new(&env), 1_000);
+/// #         expired
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     let env = Env::default();
+/// #     let contract_id = env.register(Contract, ());
+/// #     ContractClient::new(&env, &contract_id).f();
+/// # }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub trait Clock {
+    /// Returns a unix timestamp for when the ledger was closed.
+    fn timestamp(&self) -> u64;
+
+    /// Returns the sequence number of the ledger.
+    fn sequence(&self) -> u32;
+}
+
+/// EnvClock is a [Clock] that reads the time from the current ledger of an
+/// [Env].
+#[derive(Clone)]
+pub struct EnvClock(Env);
+
+impl EnvClock {
+    /// Create a clock for the current ledger of the [Env].
+    #[inline(always)]
+    pub fn new(env: &Env) -> EnvClock {
+        EnvClock(env.clone())
+    }
+}
+
+impl Clock for EnvClock {
+    #[inline(always)]
+    fn timestamp(&self) -> u64 {
+        self.0.ledger().timestamp()
+    }
+
+    #[inline(always)]
+    fn sequence(&self) -> u32 {
+        self.0.ledger().sequence()
+    }
+}
+
 #[cfg(any(test, feature = "testutils"))]
 use crate::testutils;
 
@@ -256,6 +256,25 @@ pub use soroban_sdk_macros::symbol_short;
 /// # #[cfg(not(feature = "testutils"))]
 /// # fn main() { }
 /// ```
+///
+/// ### Returning errors vs panicking
+///
+/// A contract function that returns `Result<T, E>`, where `E` is a
+/// [`contracterror`] type, can return `Err(E::Variant)` instead of calling
+/// [`panic_with_error!`]. Errors from deep in a call stack can be propagated
+/// to the contract function with `?`, without any extra plumbing.
+///
+/// The returned error is converted to the host error encoding in the same way
+/// as [`panic_with_error!`], so callers cannot tell the two apart:
+/// - The invocation fails, and any changes the invocation made are rolled
+/// back.
+/// - The `try_` client function returns the same `Err(Ok(E::Variant))`.
+/// - The invocation fails with the same `Error(Contract, #N)` diagnostic.
+///
+/// Returning the error is usually preferred, because the error is part of the
+/// function's signature and so is included in the contract spec. Panicking is
+/// useful where the function cannot return a `Result`, or to abort from code
+/// that does not return one.
 pub use soroban_sdk_macros::contracterror;
 
 /// Import a contract from its WASM file, generating a client, types, and
@@ -394,6 +413,36 @@ pub use soroban_sdk_macros::contract;
 /// # #[cfg(not(feature = "testutils"))]
 /// # fn main() { }
 /// ```
+///
+/// ### Extending the instance TTL
+///
+/// With `extend_instance`, every public function in the implementation
+/// starts by extending the TTL of the contract's instance and code with
+/// [`Env::extend_instance_ttl_auto`], so that a contract that is in use does
+/// not expire because a function forgot to extend it.
+///
+/// The TTL is extended to `extend_to` ledgers when below `threshold` ledgers.
+/// Without values, it is extended to the maximum TTL when below half of the
+/// maximum TTL.
+///
+/// Every function extends the TTL, including functions that only read, which
+/// then require the instance and code in the read-write footprint. See
+/// [`Env::extend_instance_ttl_auto`].
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, Env};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl(extend_instance(threshold = 17_280, extend_to = 518_400))]
+/// impl Contract {
+///     pub fn get(env: Env) -> u32 {
+///         env.storage().instance().get(&0).unwrap_or(0)
+///     }
+/// }
+/// # fn main() { }
+/// ```
 pub use soroban_sdk_macros::contractimpl;
 
 /// Adds a serialized SCMetaEntry::SCMetaV0 to the WASM contracts custom section
@@ -440,7 +489,9 @@ pub use soroban_sdk_macros::contractmeta;
 ///
 /// There are some constraints on the types that are supported:
 /// - Enums with integer values must have an explicit integer literal for every
-/// variant.
+/// variant. Each variant is encoded as its integer value, not its position, so
+/// variants can be reordered without changing the encoding. The values must be
+/// unique `u32`s.
 /// - Enums with unit variants are supported.
 /// - Enums with tuple-like variants with a maximum of one tuple field are
 /// supported. The tuple field must be of a type that is also convertible to and
@@ -454,6 +505,48 @@ pub use soroban_sdk_macros::contractmeta;
 /// Includes the type in the contract spec so that clients can generate bindings
 /// for the type.
 ///
+/// ### Default Fields
+///
+/// Fields of structs with named fields can be marked `#[default]`, so that
+/// values encoded before the field was added can still be converted into the
+/// struct. If the field is missing from the encoded map, it is set to
+/// [`Default::default()`], so the type of the field must implement
+/// [`Default`]. A field without `#[default]` that is missing, a key that is not
+/// a field, or a value of the wrong type, are still errors.
+///
+/// The encoding of the struct does not change, and the field is always
+/// written, so values written by a version of a contract with the field cannot
+/// be read by an earlier version without it. A struct with default fields
+/// looks up each field in the map, rather than unpacking the map in one call,
+/// which costs more to convert from a `Val`. The contract spec does not record
+/// which fields have defaults.
+///
+/// Put `#[contracttype]` before any `#[derive(Default)]` on the struct, because
+/// the `Default` derive does not allow `#[default]` on fields.
+///
+/// ```
+/// use soroban_sdk::{contracttype, Env, IntoVal, Val};
+///
+/// #[contracttype]
+/// #[derive(Clone, Debug, Eq, PartialEq)]
+/// pub struct ConfigV1 {
+///     pub admin_count: u32,
+/// }
+///
+/// #[contracttype]
+/// #[derive(Clone, Debug, Eq, PartialEq)]
+/// pub struct Config {
+///     pub admin_count: u32,
+///     #[default]
+///     pub paused: bool,
+/// }
+///
+/// let env = Env::default();
+/// let old: Val = ConfigV1 { admin_count: 2 }.into_val(&env);
+/// let config: Config = old.into_val(&env);
+/// assert_eq!(config, Config { admin_count: 2, paused: false });
+/// ```
+///
 /// ### Examples
 ///
 /// Defining a contract type that is a struct and use it in a contract.
@@ -594,6 +687,55 @@ pub use soroban_sdk_macros::contractmeta;
 /// ```
 pub use soroban_sdk_macros::contracttype;
 
+/// Generates a conversion from the struct into a [`Map`] of [`Symbol`] to
+/// [`Val`], keyed by the field names.
+///
+/// The struct must have named fields, of types that are convertible to
+/// [`Val`]. The conversion is used with [`IntoVal`] or [`TryIntoVal`]. Field
+/// names must be valid symbols, so raw identifiers such as `r#type` are
+/// rejected.
+///
+/// Unlike [`contracttype`], no type is added to the contract spec, so the map
+/// can be used as a dynamically typed value, such as for storing a struct in
+/// a map that other code also adds keys to.
+///
+/// See [`FromMap`] for the reverse conversion.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{symbol_short, Env, FromMap, IntoMap, IntoVal, Map, Symbol, TryIntoVal, Val};
+///
+/// #[derive(IntoMap, FromMap, Debug, PartialEq)]
+/// pub struct Config {
+///     pub fee: u32,
+///     pub admin_count: u32,
+/// }
+///
+/// let env = Env::default();
+/// let config = Config { fee: 5, admin_count: 2 };
+///
+/// let mut map: Map<Symbol, Val> = (&config).into_val(&env);
+/// assert_eq!(map.len(), 2);
+///
+/// // Keys that aren't fields are ignored when converting back.
+/// map.set(symbol_short!("other"), true.into_val(&env));
+/// let back: Config = map.try_into_val(&env).unwrap();
+/// assert_eq!(back, config);
+/// ```
+pub use soroban_sdk_macros::IntoMap;
+
+/// Generates a conversion from a [`Map`] of [`Symbol`] to [`Val`] into the
+/// struct, reading each field from the key of its name.
+///
+/// The struct must have named fields, of types that are convertible from
+/// [`Val`]. The conversion is used with [`TryIntoVal`], and fails with a
+/// [`ConversionError`] if a key for a field is missing, or if a value isn't
+/// of the type of its field. Keys that aren't fields are ignored.
+///
+/// See [`IntoMap`] for the reverse conversion, and for an example.
+pub use soroban_sdk_macros::FromMap;
+
 /// Generates conversions from the struct into a published event.
 ///
 /// Fields of the struct become topics and data parameters in the published event.
@@ -722,6 +864,54 @@ pub use soroban_sdk_macros::contracttype;
 /// # fn main() { }
 /// ```
 ///
+/// #### Topic Limit
+///
+/// An event can have at most four topics, which includes the prefix topics as well as the fields
+/// marked as topics. More topics is a compile error.
+///
+/// ```compile_fail
+/// #![no_std]
+/// use soroban_sdk::contractevent;
+///
+/// #[contractevent(topics = [])]
+/// pub struct MyEvent {
+///     #[topic]
+///     pub a: u32,
+///     #[topic]
+///     pub b: u32,
+///     #[topic]
+///     pub c: u32,
+///     #[topic]
+///     pub d: u32,
+///     #[topic]
+///     pub e: u32,
+/// }
+///
+/// # fn main() { }
+/// ```
+///
+/// The default prefix topic, the name of the event, counts towards the limit, so this event with
+/// four topic fields has five topics and is also a compile error.
+///
+/// ```compile_fail
+/// #![no_std]
+/// use soroban_sdk::contractevent;
+///
+/// #[contractevent]
+/// pub struct MyEvent {
+///     #[topic]
+///     pub a: u32,
+///     #[topic]
+///     pub b: u32,
+///     #[topic]
+///     pub c: u32,
+///     #[topic]
+///     pub d: u32,
+/// }
+///
+/// # fn main() { }
+/// ```
+///
 /// #### A Full Example
 ///
 /// Defining an event, publishing it in a contract, and testing it.
@@ -1006,30 +1196,48 @@ pub mod data {
     #[deprecated(note = "use storage::Storage")]
     pub use super::storage::Storage as Data;
 }
+pub mod access_control;
 pub mod auth;
 mod bytes;
+pub mod codec;
 pub mod crypto;
+pub mod decimal;
 pub mod deploy;
 mod error;
 pub use error::InvokeError;
 pub mod events;
 pub use events::{Event, Topics};
+pub mod interop;
 pub mod iter;
 pub mod ledger;
 pub mod logs;
 mod map;
+pub mod migration;
+pub mod ownable;
+pub mod pausable;
 pub mod prng;
+pub mod rate_limiter;
+pub mod reentrancy;
+pub mod sparse_merkle;
 pub mod storage;
 pub mod token;
+pub mod upgradeable;
 mod vec;
+pub mod vesting;
 pub use address::{Address, Executable};
-pub use bytes::{Bytes, BytesN};
+pub use bytes::{Bytes, BytesBuilder, BytesN};
 pub use map::Map;
 pub use muxed_address::MuxedAddress;
-pub use symbol::Symbol;
+pub use symbol::{Symbol, SymbolError};
 pub use vec::Vec;
 mod num;
 pub use num::{Duration, Timepoint, I256, U256};
+mod fixed_point;
+pub use fixed_point::FixedPoint;
+mod ring_buffer;
+pub use ring_buffer::RingBuffer;
+mod binary_heap;
+pub use binary_heap::BinaryHeap;
 mod string;
 pub use string::String;
 mod tuple;
@@ -1,9 +1,10 @@
 //! Logging contains types for logging debug events.
 //!
-//! See [`log`][crate::log] for how to conveniently log debug events.
+//! See [`log`][crate::log] for how to conveniently log debug events, and
+//! [`log_fields`][crate::log_fields] for debug events with named fields.
 use core::fmt::Debug;
 
-use crate::{env::internal::EnvBase, Env, Val};
+use crate::{env::internal::EnvBase, Env, Map, Symbol, Val};
 
 /// Log a debug event.
 ///
@@ -85,6 +86,71 @@ macro_rules! log {
     };
 }
 
+/// Log a debug event with named fields.
+///
+/// Takes a [Env], a literal string, and a trailing sequence of `name = value`
+/// fields, where each name is an identifier and each value may be any value
+/// that is convertible to [`Val`]. The fields are collected into a
+/// [`Map`][crate::Map] of [`Symbol`][crate::Symbol] to [`Val`], which is
+/// appended to the log after the string and the symbol `_fields`, so that
+/// tooling reading the diagnostic events can read each field by name rather
+/// than parse a string.
+///
+/// `log_fields!` statements are enabled in the same builds as
+/// [`log`][crate::log], and the fields are not evaluated in builds where they
+/// are not.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{log_fields, symbol_short, Env};
+///
+/// let env = Env::default();
+///
+/// let amount = 5i128;
+/// log_fields!(&env, "transfer", amount = amount, memo = symbol_short!("rent"));
+/// ```
+///
+/// Assert on the fields in tests:
+///
+/// ```
+/// # #[cfg(feature = "testutils")]
+/// # {
+/// use soroban_sdk::{log_fields, symbol_short, Env, IntoVal};
+///
+/// let env = Env::default();
+///
+/// log_fields!(&env, "transfer", amount = 5i128, memo = symbol_short!("rent"));
+///
+/// use soroban_sdk::testutils::Logs;
+/// let (msg, fields) = env.logs().all_fields().last().unwrap().clone();
+/// assert_eq!(msg, "transfer");
+/// let amount: i128 = fields.get(symbol_short!("amount")).unwrap().into_val(&env);
+/// assert_eq!(amount, 5);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! log_fields {
+    ($env:expr, $fmt:literal $(, $name:ident = $value:expr)* $(,)?) => {
+        if cfg!(debug_assertions) {
+            let env: &$crate::Env = $env;
+            #[allow(unused_mut)]
+            let mut fields = $crate::Map::<$crate::Symbol, $crate::Val>::new(env);
+            $(
+                fields.set(
+                    $crate::Symbol::new(env, stringify!($name)),
+                    <_ as $crate::IntoVal<$crate::Env, $crate::Val>>::into_val(&$value, env),
+                );
+            )*
+            env.logs().add_fields($fmt, &fields);
+        }
+    };
+}
+
+// The value logged between the message and the fields of a log entry added
+// with Logs::add_fields, that distinguishes it from a log! of a map.
+const FIELDS_MARKER: Symbol = soroban_sdk_macros::internal_symbol_short!("_fields");
+
 /// Logs logs debug events.
 ///
 /// See [`log`][crate::log] for how to conveniently log debug events.
@@ -133,6 +199,20 @@ impl Logs {
             }
         }
     }
+
+    /// Log a debug event with named fields.
+    ///
+    /// Takes a literal string and a map of field names to values to add as a
+    /// log entry in the diagnostic event stream. The string is followed by the
+    /// symbol `_fields`, which marks the entry as a fields entry, and then the
+    /// map.
+    ///
+    /// See [`log_fields`][crate::log_fields] for how to conveniently log debug
+    /// events with named fields.
+    #[inline(always)]
+    pub fn add_fields(&self, msg: &'static str, fields: &Map<Symbol, Val>) {
+        self.add(msg, &[FIELDS_MARKER.to_val(), fields.to_val()]);
+    }
 }
 
 #[cfg(any(test, feature = "testutils"))]
@@ -164,6 +244,45 @@ impl testutils::Logs for Logs {
             .collect::<std::vec::Vec<_>>()
     }
 
+    fn all_fields(&self) -> std::vec::Vec<(String, Map<Symbol, Val>)> {
+        use crate::{
+            xdr::{ContractEventBody, ContractEventType, ScSymbol, ScVal, ScVec, StringM, VecM},
+            TryFromVal,
+        };
+        let env = self.env();
+        let log_sym = ScSymbol(StringM::try_from("log").unwrap());
+        let log_topics = ScVec(VecM::try_from(vec![ScVal::Symbol(log_sym)]).unwrap());
+        env.host()
+            .get_diagnostic_events()
+            .unwrap()
+            .0
+            .into_iter()
+            .filter_map(|e| match (&e.event.type_, &e.event.body) {
+                (ContractEventType::Diagnostic, ContractEventBody::V0(ce))
+                    if &ce.topics == &log_topics.0 =>
+                {
+                    // Fields are logged as the message, the marker, and a map
+                    // with symbol keys.
+                    let ScVal::Vec(Some(data)) = &ce.data else {
+                        return None;
+                    };
+                    match data.0.as_slice() {
+                        [ScVal::String(msg), ScVal::Symbol(marker), fields @ ScVal::Map(Some(_))]
+                            if marker.0.as_slice() == b"_fields" =>
+                        {
+                            Some((
+                                msg.0.to_utf8_string_lossy(),
+                                Map::try_from_val(env, fields).unwrap(),
+                            ))
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect::<std::vec::Vec<_>>()
+    }
+
     fn print(&self) {
         std::println!("{}", self.all().join("\n"))
     }
@@ -358,6 +358,46 @@ where
         self.try_get(k).unwrap_optimized()
     }
 
+    /// Returns the value corresponding to the key or `default` if the map
+    /// does not contain a value with the specified key.
+    ///
+    /// The default is not inserted into the map.
+    ///
+    /// ### Panics
+    ///
+    /// If the value corresponding to the key cannot be converted to type V.
+    #[inline(always)]
+    pub fn get_or(&self, k: K, default: V) -> V {
+        self.get(k).unwrap_or(default)
+    }
+
+    /// Returns the value corresponding to the key or the default value of V
+    /// if the map does not contain a value with the specified key.
+    ///
+    /// The default is not inserted into the map.
+    ///
+    /// ### Panics
+    ///
+    /// If the value corresponding to the key cannot be converted to type V.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{map, Env, Map, Symbol};
+    ///
+    /// let env = Env::default();
+    /// let balances: Map<Symbol, i128> = map![&env, (Symbol::new(&env, "alice"), 100)];
+    /// assert_eq!(balances.get_or_default(Symbol::new(&env, "alice")), 100);
+    /// assert_eq!(balances.get_or_default(Symbol::new(&env, "bob")), 0);
+    /// ```
+    #[inline(always)]
+    pub fn get_or_default(&self, k: K) -> V
+    where
+        V: Default,
+    {
+        self.get(k).unwrap_or_default()
+    }
+
     /// Returns the value corresponding to the key or None if the map does not
     /// contain a value with the specified key.
     ///
@@ -417,6 +457,41 @@ where
             .unwrap_infallible();
     }
 
+    /// Update the value for the specified key with the given function.
+    ///
+    /// The function is called with the current value, or `None` if the map
+    /// does not contain a value with the specified key. If the function
+    /// returns a value it is set for the key, if it returns `None` the key is
+    /// removed.
+    ///
+    /// The function runs in the guest, reading the current value and writing
+    /// the new one are still calls to the host.
+    ///
+    /// ### Panics
+    ///
+    /// If the value corresponding to the key cannot be converted to type V.
+    pub fn update(&mut self, k: K, f: impl FnOnce(Option<V>) -> Option<V>) {
+        let env = self.env();
+        let k = k.into_val(env);
+        let has = env.map_has(self.obj, k).unwrap_infallible().into();
+        let current = if has {
+            let v = env.map_get(self.obj, k).unwrap_infallible();
+            Some(V::try_from_val(env, &v).unwrap_optimized())
+        } else {
+            None
+        };
+        match f(current) {
+            Some(v) => {
+                let v = v.into_val(env);
+                self.obj = env.map_put(self.obj, k, v).unwrap_infallible();
+            }
+            None if has => {
+                self.obj = env.map_del(self.obj, k).unwrap_infallible();
+            }
+            None => {}
+        }
+    }
+
     /// Remove the value corresponding to the key.
     ///
     /// Returns `None` if the map does not contain a value with the specified
@@ -446,6 +521,8 @@ where
     }
 
     /// Returns a [Vec] of all keys in the map.
+    ///
+    /// Keys are in the map's sorted order, the same order as [Map::iter].
     #[inline(always)]
     pub fn keys(&self) -> Vec<K> {
         let env = self.env();
@@ -454,12 +531,62 @@ where
     }
 
     /// Returns a [Vec] of all values in the map.
+    ///
+    /// Values are in the order of their keys, so the value at each index
+    /// corresponds to the key at the same index in [Map::keys].
     #[inline(always)]
     pub fn values(&self) -> Vec<V> {
         let env = self.env();
         let vec = env.map_values(self.obj).unwrap_infallible();
         Vec::<V>::try_from_val(env, &vec).unwrap()
     }
+
+    /// Returns a new map containing the key-value pairs of both self and
+    /// other.
+    ///
+    /// Keys present in only one of the maps are copied as is. For keys
+    /// present in both, `resolve` is called with the value from self and the
+    /// value from other, and its result is used. The resolver is called in
+    /// order of the keys.
+    ///
+    /// Like every [Map], the result is in the map's sorted key order,
+    /// regardless of the order of the inputs.
+    ///
+    /// ### Panics
+    ///
+    /// If a value corresponding to a key present in both maps cannot be
+    /// converted to type V.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{map, Env};
+    ///
+    /// let env = Env::default();
+    /// let a = map![&env, (1, 10), (2, 20)];
+    /// let b = map![&env, (2, 5), (3, 30)];
+    /// assert_eq!(a.merge(&b, |x, y| x + y), map![&env, (1, 10), (2, 25), (3, 30)]);
+    /// ```
+    pub fn merge(&self, other: &Map<K, V>, resolve: impl Fn(V, V) -> V) -> Map<K, V> {
+        let env = self.env();
+        let mut obj = self.obj;
+        for i in 0..other.len() {
+            let index_val: U32Val = i.into();
+            let k = env.map_key_by_pos(other.obj, index_val).unwrap_infallible();
+            let v = env.map_val_by_pos(other.obj, index_val).unwrap_infallible();
+            let has = env.map_has(obj, k).unwrap_infallible().into();
+            let v = if has {
+                let current = env.map_get(obj, k).unwrap_infallible();
+                let current = V::try_from_val(env, &current).unwrap_optimized();
+                let v = V::try_from_val(env, &v).unwrap_optimized();
+                resolve(current, v).into_val(env)
+            } else {
+                v
+            };
+            obj = env.map_put(obj, k, v).unwrap_infallible();
+        }
+        unsafe { Self::unchecked_new(env.clone(), obj) }
+    }
 }
 
 impl<K, V> Map<K, V> {
@@ -915,6 +1042,108 @@ mod test {
         assert_eq!(values, vec![&env, 0, 10, 20, 30, 40]);
     }
 
+    #[test]
+    fn test_update() {
+        let env = Env::default();
+
+        let mut map: Map<u32, u32> = map![&env, (1, 10), (2, 20)];
+
+        // insert new
+        map.update(3, |v| {
+            assert_eq!(v, None);
+            Some(30)
+        });
+        assert_eq!(map, map![&env, (1, 10), (2, 20), (3, 30)]);
+
+        // update existing
+        map.update(1, |v| v.map(|v| v + 1));
+        assert_eq!(map, map![&env, (1, 11), (2, 20), (3, 30)]);
+
+        // delete via None
+        map.update(2, |v| {
+            assert_eq!(v, Some(20));
+            None
+        });
+        assert_eq!(map, map![&env, (1, 11), (3, 30)]);
+
+        // None for a missing key leaves the map unchanged
+        map.update(4, |_| None);
+        assert_eq!(map, map![&env, (1, 11), (3, 30)]);
+    }
+
+    #[test]
+    fn test_merge_disjoint() {
+        let env = Env::default();
+
+        let a: Map<u32, u32> = map![&env, (1, 10), (3, 30)];
+        let b: Map<u32, u32> = map![&env, (0, 0), (2, 20), (4, 40)];
+        let merged = a.merge(&b, |_, _| panic!("resolver called for disjoint keys"));
+        assert_eq!(
+            merged,
+            map![&env, (0, 0), (1, 10), (2, 20), (3, 30), (4, 40)]
+        );
+        assert_eq!(merged.keys(), vec![&env, 0, 1, 2, 3, 4]);
+
+        // the inputs are unchanged
+        assert_eq!(a, map![&env, (1, 10), (3, 30)]);
+        assert_eq!(b, map![&env, (0, 0), (2, 20), (4, 40)]);
+
+        // merging with an empty map copies the other map
+        let empty: Map<u32, u32> = Map::new(&env);
+        assert_eq!(a.merge(&empty, |x, _| x), a);
+        assert_eq!(empty.merge(&a, |x, _| x), a);
+    }
+
+    #[test]
+    fn test_merge_overlapping() {
+        let env = Env::default();
+
+        let a: Map<u32, u32> = map![&env, (1, 10), (2, 20)];
+        let b: Map<u32, u32> = map![&env, (1, 1), (2, 2)];
+
+        // the resolver receives the value from self first
+        assert_eq!(a.merge(&b, |x, _| x), a);
+        assert_eq!(a.merge(&b, |_, y| y), b);
+        assert_eq!(a.merge(&b, |x, y| x - y), map![&env, (1, 9), (2, 18)]);
+    }
+
+    #[test]
+    fn test_merge_partial_overlap() {
+        let env = Env::default();
+
+        let a: Map<u32, u32> = map![&env, (1, 10), (2, 20), (3, 30)];
+        let b: Map<u32, u32> = map![&env, (2, 2), (3, 3), (4, 4)];
+
+        let resolved = core::cell::RefCell::new(std::vec::Vec::new());
+        let merged = a.merge(&b, |x, y| {
+            resolved.borrow_mut().push((x, y));
+            x + y
+        });
+        assert_eq!(merged, map![&env, (1, 10), (2, 22), (3, 33), (4, 4)]);
+        assert_eq!(resolved.into_inner(), [(20, 2), (30, 3)]);
+    }
+
+    #[test]
+    fn test_keys_and_values_aligned_with_iter() {
+        let env = Env::default();
+
+        // inserted out of order, the map keeps keys sorted
+        let mut map: Map<u32, u32> = Map::new(&env);
+        map.set(30, 3);
+        map.set(10, 1);
+        map.set(20, 2);
+
+        let keys = map.keys();
+        let values = map.values();
+        assert_eq!(keys, vec![&env, 10, 20, 30]);
+        assert_eq!(keys.len(), map.len());
+        assert_eq!(values.len(), map.len());
+        for (i, (k, v)) in map.iter().enumerate() {
+            assert_eq!(keys.get_unchecked(i as u32), k);
+            assert_eq!(values.get_unchecked(i as u32), v);
+        }
+    }
+
     #[test]
     fn test_from_array() {
         let env = Env::default();
@@ -963,6 +1192,38 @@ mod test {
         assert_eq!(map.get(2), None);
     }
 
+    #[test]
+    fn test_get_or() {
+        let env = Env::default();
+
+        let map: Map<u32, u32> = map![&env, (0, 0), (1, 10)];
+        assert_eq!(map.get_or(0, 5), 0);
+        assert_eq!(map.get_or(1, 5), 10);
+        assert_eq!(map.get_or(2, 5), 5);
+
+        // the default is not inserted
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.contains_key(2), false);
+        assert_eq!(map, map![&env, (0, 0), (1, 10)]);
+    }
+
+    #[test]
+    fn test_get_or_default() {
+        let env = Env::default();
+
+        let map: Map<u32, i128> = map![&env, (1, 10)];
+        assert_eq!(map.get_or_default(1), 10);
+        assert_eq!(map.get_or_default(2), 0);
+
+        let map: Map<u32, Option<u32>> = map![&env, (1, Some(10))];
+        assert_eq!(map.get_or_default(1), Some(10));
+        assert_eq!(map.get_or_default(2), None);
+
+        // the default is not inserted
+        assert_eq!(map.len(), 1);
+        assert_eq!(map, map![&env, (1, Some(10))]);
+    }
+
     #[test]
     fn test_get_none_on_key_type_mismatch() {
         let env = Env::default();
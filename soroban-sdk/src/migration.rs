@@ -0,0 +1,104 @@
+//! Migration contains a helper for running one-time data migrations after a
+//! contract is upgraded.
+use crate::{
+    storage::{ReservedKey, SdkKey},
+    Env,
+};
+
+/// Migration keeps track of the schema version of the data stored by the
+/// current contract, and runs each migration between versions exactly once.
+///
+/// The schema version is stored in the current contract's instance storage,
+/// and is `0` until it is set. It is independent of the number of upgrades
+/// counted by [`Upgradeable`][crate::upgradeable::Upgradeable], because not
+/// every upgrade needs a migration.
+///
+/// A contract that is deployed with the latest schema should set the version
+/// in its constructor with [`set_schema_version`][Migration::set_schema_version],
+/// so that migrations for earlier versions are not run on its data.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, migration::Migration, symbol_short, Env};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn __constructor(env: Env) {
+///         Migration::set_schema_version(&env, 2);
+///     }
+///
+///     /// Called after upgrading from the Wasm that stored schema version 1.
+///     pub fn migrate(env: Env) {
+///         Migration::run_if_needed(&env, 1, 2, |env| {
+///             let storage = env.storage().persistent();
+///             if let Some(count) = storage.get::<_, u32>(&symbol_short!("COUNT")) {
+///                 storage.set(&symbol_short!("COUNT"), &u64::from(count));
+///             }
+///         });
+///     }
+/// }
+/// # fn main() { }
+/// ```
+pub struct Migration;
+
+impl Migration {
+    /// Returns the schema version of the current contract's data, or `0` if
+    /// it has not been set.
+    pub fn schema_version(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Self::schema_version_key())
+            .unwrap_or(0)
+    }
+
+    /// Sets the schema version of the current contract's data, without
+    /// running any migration.
+    pub fn set_schema_version(env: &Env, version: u32) {
+        env.storage()
+            .instance()
+            .set(&Self::schema_version_key(), &version);
+    }
+
+    /// Runs `migrate` if the schema version is `from`, then sets the schema
+    /// version to `to`. Returns true if `migrate` was run.
+    ///
+    /// If the schema version is already `to` or later, the migration has
+    /// already been run, or the data was stored with a later schema, and
+    /// `migrate` is not run. A contract can therefore call every migration in
+    /// a chain, such as `0` to `1` then `1` to `2`, on each upgrade, and only
+    /// the migrations that are needed are run.
+    ///
+    /// The schema version is only stored if the invocation succeeds, so if
+    /// `migrate` panics the data and the version are both left unchanged.
+    ///
+    /// ### Panics
+    ///
+    /// If `to` is not greater than `from`.
+    ///
+    /// If the schema version is earlier than `to` but is not `from`, such as
+    /// when the migrations to get from the schema version to `from` have not
+    /// been run.
+    pub fn run_if_needed(env: &Env, from: u32, to: u32, migrate: impl FnOnce(&Env)) -> bool {
+        if to <= from {
+            sdk_panic!("migration must be to a greater schema version");
+        }
+        let version = Self::schema_version(env);
+        if version >= to {
+            return false;
+        }
+        if version != from {
+            sdk_panic!("unexpected schema version");
+        }
+        migrate(env);
+        Self::set_schema_version(env, to);
+        true
+    }
+
+    fn schema_version_key() -> ReservedKey {
+        SdkKey::SchemaVersion.into()
+    }
+}
@@ -180,6 +180,21 @@ impl From<&Address> for MuxedAddress {
     }
 }
 
+/// Converts to the `Address` part of the multiplexed address, dropping the
+/// multiplexing identifier if any.
+impl From<MuxedAddress> for Address {
+    fn from(address: MuxedAddress) -> Self {
+        address.address()
+    }
+}
+
+/// Same as `From<MuxedAddress> for Address`, without consuming the value.
+impl From<&MuxedAddress> for Address {
+    fn from(address: &MuxedAddress) -> Self {
+        address.address()
+    }
+}
+
 impl MuxedAddress {
     /// Returns the `Address` part of this multiplexed address.
     ///
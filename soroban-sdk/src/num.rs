@@ -5,12 +5,15 @@ use super::{
         DurationSmall, DurationVal, Env as _, I256Small, I256Val, TimepointSmall, TimepointVal,
         U256Small, U256Val,
     },
-    Bytes, ConversionError, Env, TryFromVal, TryIntoVal, Val,
+    Bytes, ConversionError, Env, String, TryFromVal, TryIntoVal, Val,
 };
 
 #[cfg(not(target_family = "wasm"))]
 use crate::env::internal::xdr::ScVal;
-use crate::unwrap::{UnwrapInfallible, UnwrapOptimized};
+use crate::{
+    decimal::{try_for_each_byte, ParseError},
+    unwrap::{UnwrapInfallible, UnwrapOptimized},
+};
 
 macro_rules! impl_num_wrapping_val_type {
     ($wrapper:ident, $val:ty, $small:ty) => {
@@ -311,6 +314,491 @@ impl U256 {
             val,
         }
     }
+
+    /// Returns `self + other`, or `None` if the result would overflow.
+    pub fn checked_add(&self, other: &U256) -> Option<U256> {
+        if *other > U256::max(&self.env).sub(self) {
+            None
+        } else {
+            Some(self.add(other))
+        }
+    }
+
+    /// Returns `self - other`, or `None` if the result would underflow.
+    pub fn checked_sub(&self, other: &U256) -> Option<U256> {
+        if other > self {
+            None
+        } else {
+            Some(self.sub(other))
+        }
+    }
+
+    /// Returns `self * other`, or `None` if the result would overflow.
+    pub fn checked_mul(&self, other: &U256) -> Option<U256> {
+        let zero = U256::from_u32(&self.env, 0);
+        if *self == zero || *other == zero {
+            return Some(zero);
+        }
+        if *other > U256::max(&self.env).div(self) {
+            None
+        } else {
+            Some(self.mul(other))
+        }
+    }
+
+    fn max(env: &Env) -> U256 {
+        U256::from_parts(env, u64::MAX, u64::MAX, u64::MAX, u64::MAX)
+    }
+
+    /// Returns the square root of `self`, rounded down.
+    ///
+    /// Computed with Newton's method, starting from a power of two no smaller
+    /// than the root so that the iterates decrease until they reach it.
+    pub fn isqrt(&self) -> U256 {
+        let env = &self.env;
+        if *self < U256::from_u32(env, 2) {
+            return self.clone();
+        }
+        let bits = 256 - self.leading_zeros();
+        let mut x = U256::from_u32(env, 1).shl(bits.div_ceil(2));
+        loop {
+            // x >= sqrt(self), so self / x <= x and the sum can't overflow.
+            let y = x.add(&self.div(&x)).shr(1);
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Computes `self * b / c`, rounded down.
+    ///
+    /// The product is computed in 512-bit precision in the guest, so the
+    /// result is correct whenever the final quotient fits in 256 bits, even if
+    /// the intermediate product does not. Values that fit in a `u128` can be
+    /// converted with [`from_u128`][U256::from_u128] and
+    /// [`to_u128`][U256::to_u128].
+    ///
+    /// ### Panics
+    ///
+    /// If `c` is zero, or if the quotient overflows.
+    pub fn mul_div(&self, b: &U256, c: &U256) -> U256 {
+        self.mul_div_floor(b, c)
+    }
+
+    /// Computes `self * b / c`, rounded down.
+    ///
+    /// See [`mul_div`][U256::mul_div].
+    ///
+    /// ### Panics
+    ///
+    /// If `c` is zero, or if the quotient overflows.
+    pub fn mul_div_floor(&self, b: &U256, c: &U256) -> U256 {
+        let (quot, _) = self.mul_div_limbs(b, c);
+        U256::from_limbs(&self.env, &quot)
+    }
+
+    /// Computes `self * b / c`, rounded up.
+    ///
+    /// See [`mul_div`][U256::mul_div].
+    ///
+    /// ### Panics
+    ///
+    /// If `c` is zero, or if the quotient overflows.
+    pub fn mul_div_ceil(&self, b: &U256, c: &U256) -> U256 {
+        let (mut quot, inexact) = self.mul_div_limbs(b, c);
+        if inexact {
+            if quot == [u64::MAX; 4] {
+                sdk_panic!("mul_div overflow");
+            }
+            for limb in quot.iter_mut() {
+                let (v, carry) = limb.overflowing_add(1);
+                *limb = v;
+                if !carry {
+                    break;
+                }
+            }
+        }
+        U256::from_limbs(&self.env, &quot)
+    }
+
+    fn mul_div_limbs(&self, b: &U256, c: &U256) -> ([u64; 4], bool) {
+        let c = c.to_limbs();
+        if c == [0; 4] {
+            sdk_panic!("mul_div division by zero");
+        }
+        match mul_div_limbs(&self.to_limbs(), &b.to_limbs(), &c) {
+            Some(res) => res,
+            None => sdk_panic!("mul_div overflow"),
+        }
+    }
+
+    /// Computes `self^exp mod modulus`.
+    ///
+    /// The exponentiation is done by square-and-multiply in the guest, so the
+    /// number of host calls doesn't depend on the size of `exp`.
+    ///
+    /// ### Panics
+    ///
+    /// If `modulus` is zero.
+    pub fn pow_mod(&self, exp: &U256, modulus: &U256) -> U256 {
+        let base = self.rem_euclid(modulus).to_limbs();
+        let res = pow_mod_limbs(&base, &exp.to_limbs(), &modulus.to_limbs());
+        U256::from_limbs(&self.env, &res)
+    }
+
+    /// Returns the bit at `index`, where index 0 is the least significant bit.
+    ///
+    /// ### Panics
+    ///
+    /// If `index` is 256 or greater.
+    pub fn bit(&self, index: u32) -> bool {
+        bit_limbs(&self.to_limbs(), index)
+    }
+
+    /// Sets the bit at `index` to `value`, where index 0 is the least
+    /// significant bit.
+    ///
+    /// ### Panics
+    ///
+    /// If `index` is 256 or greater.
+    pub fn set_bit(&mut self, index: u32, value: bool) {
+        let mut limbs = self.to_limbs();
+        set_bit_limbs(&mut limbs, index, value);
+        *self = U256::from_limbs(&self.env, &limbs);
+    }
+
+    /// Returns the number of leading zeros in the binary representation.
+    pub fn leading_zeros(&self) -> u32 {
+        leading_zeros_limbs(&self.to_limbs())
+    }
+
+    /// Returns the number of ones in the binary representation.
+    pub fn count_ones(&self) -> u32 {
+        self.to_limbs().iter().map(|l| l.count_ones()).sum()
+    }
+
+    /// Returns the value as a string of its decimal digits, without leading
+    /// zeros.
+    ///
+    /// The digits are computed in the guest.
+    pub fn to_decimal_string(&self) -> String {
+        decimal_string_from_limbs(&self.env, self.to_limbs(), false)
+    }
+
+    /// Parses a string of decimal digits, optionally preceded by a `+` sign.
+    ///
+    /// Leading zeros are accepted, so `"007"` parses as 7. Leading or
+    /// trailing whitespace, a `-` sign, digit separators, a decimal point, and
+    /// any other characters are rejected rather than ignored.
+    ///
+    /// ### Errors
+    ///
+    /// - [ParseError::Empty] if the string has no digits, such as `""` or
+    ///   `"+"`.
+    /// - [ParseError::InvalidChar] if the string contains any other
+    ///   character.
+    /// - [ParseError::Overflow] if the value does not fit in 256 bits.
+    pub fn from_decimal_string(env: &Env, s: &String) -> Result<U256, ParseError> {
+        let (limbs, _) = limbs_from_decimal_string(s, false)?;
+        Ok(U256::from_limbs(env, &limbs))
+    }
+
+    // Returns the value as little-endian u64 limbs.
+    fn to_limbs(&self) -> [u64; 4] {
+        limbs_from_be_bytes(&self.to_be_bytes())
+    }
+
+    fn from_limbs(env: &Env, limbs: &[u64; 4]) -> U256 {
+        U256::from_parts(env, limbs[3], limbs[2], limbs[1], limbs[0])
+    }
+}
+
+// Computes the 512-bit product `a * b` on little-endian u64 limbs.
+fn mul_limbs(a: &[u64; 4], b: &[u64; 4]) -> [u64; 8] {
+    // Schoolbook multiplication.
+    let mut product = [0u64; 8];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, y) in b.iter().enumerate() {
+            let t = product[i + j] as u128 + *x as u128 * *y as u128 + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + 4] = carry as u64;
+    }
+    product
+}
+
+// Computes `a * b / c` on little-endian u64 limbs, where `c` is non-zero.
+// Returns the quotient and whether the division was inexact, or None if the
+// quotient does not fit in 256 bits.
+fn mul_div_limbs(a: &[u64; 4], b: &[u64; 4], c: &[u64; 4]) -> Option<([u64; 4], bool)> {
+    let product = mul_limbs(a, b);
+    let mut rem = [product[4], product[5], product[6], product[7]];
+    if !lt_limbs(&rem, c) {
+        return None;
+    }
+    // Long division of the low half, one bit at a time. The remainder is
+    // always less than `c` before shifting, so it fits in 257 bits.
+    let mut quot = [0u64; 4];
+    for limb in product[..4].iter().rev() {
+        for i in (0..64).rev() {
+            let overflow = rem[3] >> 63 == 1;
+            rem[3] = (rem[3] << 1) | (rem[2] >> 63);
+            rem[2] = (rem[2] << 1) | (rem[1] >> 63);
+            rem[1] = (rem[1] << 1) | (rem[0] >> 63);
+            rem[0] = (rem[0] << 1) | ((limb >> i) & 1);
+            quot[3] = (quot[3] << 1) | (quot[2] >> 63);
+            quot[2] = (quot[2] << 1) | (quot[1] >> 63);
+            quot[1] = (quot[1] << 1) | (quot[0] >> 63);
+            quot[0] <<= 1;
+            if overflow || !lt_limbs(&rem, c) {
+                sub_limbs(&mut rem, c);
+                quot[0] |= 1;
+            }
+        }
+    }
+    Some((quot, rem != [0; 4]))
+}
+
+// Computes `a * b mod m` on little-endian u64 limbs, where `a, b < m`.
+fn mul_mod_limbs(a: &[u64; 4], b: &[u64; 4], m: &[u64; 4]) -> [u64; 4] {
+    let product = mul_limbs(a, b);
+
+    // Reduce bit by bit from the most significant end. The remainder is
+    // always less than `m` before shifting, so it fits in 257 bits.
+    let mut rem = [0u64; 4];
+    for limb in product.iter().rev() {
+        for i in (0..64).rev() {
+            let overflow = rem[3] >> 63 == 1;
+            rem[3] = (rem[3] << 1) | (rem[2] >> 63);
+            rem[2] = (rem[2] << 1) | (rem[1] >> 63);
+            rem[1] = (rem[1] << 1) | (rem[0] >> 63);
+            rem[0] = (rem[0] << 1) | ((limb >> i) & 1);
+            if overflow || !lt_limbs(&rem, m) {
+                sub_limbs(&mut rem, m);
+            }
+        }
+    }
+    rem
+}
+
+// Returns true if `a < b`.
+fn lt_limbs(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for (x, y) in a.iter().rev().zip(b.iter().rev()) {
+        if x != y {
+            return x < y;
+        }
+    }
+    false
+}
+
+// Subtracts `b` from `a` in place, wrapping on underflow.
+fn sub_limbs(a: &mut [u64; 4], b: &[u64; 4]) {
+    let mut borrow = false;
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        let (d1, b1) = x.overflowing_sub(*y);
+        let (d2, b2) = d1.overflowing_sub(borrow as u64);
+        *x = d2;
+        borrow = b1 | b2;
+    }
+}
+
+// Reads a 256-bit big-endian value into little-endian u64 limbs.
+fn limbs_from_be_bytes(bytes: &Bytes) -> [u64; 4] {
+    let mut buf = [0u8; 32];
+    bytes.copy_into_slice(&mut buf);
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().rev().zip(buf.chunks(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap_optimized());
+    }
+    limbs
+}
+
+fn bit_limbs(a: &[u64; 4], index: u32) -> bool {
+    if index >= 256 {
+        sdk_panic!("bit index out of range");
+    }
+    (a[(index / 64) as usize] >> (index % 64)) & 1 == 1
+}
+
+fn set_bit_limbs(a: &mut [u64; 4], index: u32, value: bool) {
+    if index >= 256 {
+        sdk_panic!("bit index out of range");
+    }
+    let mask = 1u64 << (index % 64);
+    let limb = &mut a[(index / 64) as usize];
+    if value {
+        *limb |= mask;
+    } else {
+        *limb &= !mask;
+    }
+}
+
+fn leading_zeros_limbs(a: &[u64; 4]) -> u32 {
+    let mut zeros = 0;
+    for limb in a.iter().rev() {
+        zeros += limb.leading_zeros();
+        if *limb != 0 {
+            break;
+        }
+    }
+    zeros
+}
+
+// Computes `base^exp mod m` by square-and-multiply, where `base < m`.
+fn pow_mod_limbs(base: &[u64; 4], exp: &[u64; 4], m: &[u64; 4]) -> [u64; 4] {
+    let mut res = if *m == [1, 0, 0, 0] {
+        [0; 4]
+    } else {
+        [1, 0, 0, 0]
+    };
+    for limb in exp.iter().rev() {
+        for i in (0..64).rev() {
+            res = mul_mod_limbs(&res, &res, m);
+            if (limb >> i) & 1 == 1 {
+                res = mul_mod_limbs(&res, base, m);
+            }
+        }
+    }
+    res
+}
+
+// Negates the two's complement value in place, wrapping on overflow.
+fn neg_limbs(a: &mut [u64; 4]) {
+    let mut carry = true;
+    for x in a.iter_mut() {
+        (*x, carry) = (!*x).overflowing_add(carry as u64);
+    }
+}
+
+// Writes the magnitude in little-endian u64 limbs in decimal, preceded by a
+// `-` sign if `negative`.
+fn decimal_string_from_limbs(env: &Env, mut limbs: [u64; 4], negative: bool) -> String {
+    // The largest power of ten that fits in a limb.
+    const DIVISOR: u64 = 10_000_000_000_000_000_000;
+    const DIGITS: usize = 19;
+    // Room for a sign and 5 groups of 19 digits, which hold the 78 digits of
+    // the largest magnitude.
+    let mut buf = [0u8; 1 + 5 * DIGITS];
+    let mut start = buf.len();
+    loop {
+        // Divide by 10^19, and write the remainder as the next group of digits.
+        let mut rem = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let cur = (rem << 64) | *limb as u128;
+            *limb = (cur / DIVISOR as u128) as u64;
+            rem = cur % DIVISOR as u128;
+        }
+        let mut rem = rem as u64;
+        for _ in 0..DIGITS {
+            start -= 1;
+            buf[start] = b'0' + (rem % 10) as u8;
+            rem /= 10;
+        }
+        if limbs == [0; 4] {
+            break;
+        }
+    }
+    // Strip the leading zeros of the most significant group, keeping at least
+    // one digit.
+    while start < buf.len() - 1 && buf[start] == b'0' {
+        start += 1;
+    }
+    if negative {
+        start -= 1;
+        buf[start] = b'-';
+    }
+    String::from_bytes(env, &buf[start..])
+}
+
+// Parses a decimal integer, with an optional `+` sign, or `-` sign if
+// `signed`, into its magnitude as little-endian u64 limbs, and whether it is
+// negative.
+fn limbs_from_decimal_string(s: &String, signed: bool) -> Result<([u64; 4], bool), ParseError> {
+    let mut negative = false;
+    let mut limbs = [0u64; 4];
+    let mut digits = 0u32;
+
+    try_for_each_byte(s, |i, c| {
+        match c {
+            b'+' if i == 0 => {}
+            b'-' if i == 0 && signed => negative = true,
+            b'0'..=b'9' => {
+                // Multiply by ten and add the digit.
+                let mut carry = (c - b'0') as u128;
+                for limb in limbs.iter_mut() {
+                    let t = *limb as u128 * 10 + carry;
+                    *limb = t as u64;
+                    carry = t >> 64;
+                }
+                if carry != 0 {
+                    return Err(ParseError::Overflow);
+                }
+                digits += 1;
+            }
+            _ => return Err(ParseError::InvalidChar),
+        }
+        Ok(())
+    })?;
+    if digits == 0 {
+        return Err(ParseError::Empty);
+    }
+    Ok((limbs, negative))
+}
+
+// The rounding applied to the quotient by `mul_div_u128`.
+#[derive(Clone, Copy)]
+pub(crate) enum Rounding {
+    Floor,
+    HalfUp,
+}
+
+// Returns the 256-bit product of `a` and `b`, as (high, low) halves.
+fn wide_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+    let mid = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let lo = (lo_lo & MASK) | (mid << 64);
+    let hi = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+// Returns `a * b / c` rounded as given, or None if the result does not fit
+// in a u128. `c` must be non-zero.
+pub(crate) fn mul_div_u128(a: u128, b: u128, c: u128, rounding: Rounding) -> Option<u128> {
+    let (hi, lo) = wide_mul_u128(a, b);
+    if hi >= c {
+        return None;
+    }
+    // Long division of the 256-bit product, one bit at a time. The remainder
+    // is always less than `c` before shifting, so it fits in 129 bits.
+    let mut rem = hi;
+    let mut quot = 0u128;
+    for i in (0..128).rev() {
+        let overflow = rem >> 127 == 1;
+        rem = rem << 1 | (lo >> i) & 1;
+        quot <<= 1;
+        if overflow || rem >= c {
+            rem = rem.wrapping_sub(c);
+            quot |= 1;
+        }
+    }
+    let round_up = match rounding {
+        Rounding::Floor => false,
+        Rounding::HalfUp => rem >= c - rem,
+    };
+    if round_up {
+        quot.checked_add(1)
+    } else {
+        Some(quot)
+    }
 }
 
 /// I256 holds a 256-bit signed integer.
@@ -462,6 +950,165 @@ impl I256 {
             val,
         }
     }
+
+    /// Returns `self + other`, or `None` if the result would overflow.
+    pub fn checked_add(&self, other: &I256) -> Option<I256> {
+        let env = &self.env;
+        let overflows = if *other > I256::from_i32(env, 0) {
+            *self > I256::max(env).sub(other)
+        } else {
+            *self < I256::min(env).sub(other)
+        };
+        if overflows {
+            None
+        } else {
+            Some(self.add(other))
+        }
+    }
+
+    /// Returns `self - other`, or `None` if the result would overflow.
+    pub fn checked_sub(&self, other: &I256) -> Option<I256> {
+        let env = &self.env;
+        let overflows = if *other > I256::from_i32(env, 0) {
+            *self < I256::min(env).add(other)
+        } else {
+            *self > I256::max(env).add(other)
+        };
+        if overflows {
+            None
+        } else {
+            Some(self.sub(other))
+        }
+    }
+
+    /// Returns `self * other`, or `None` if the result would overflow.
+    pub fn checked_mul(&self, other: &I256) -> Option<I256> {
+        let env = &self.env;
+        let zero = I256::from_i32(env, 0);
+        if *self == zero || *other == zero {
+            return Some(zero);
+        }
+        // Compare against the bounds divided by one operand, choosing the
+        // divisions so that none of them can overflow.
+        let overflows = match (*self > zero, *other > zero) {
+            (true, true) => *self > I256::max(env).div(other),
+            (true, false) => *other < I256::min(env).div(self),
+            (false, true) => *self < I256::min(env).div(other),
+            (false, false) => *other < I256::max(env).div(self),
+        };
+        if overflows {
+            None
+        } else {
+            Some(self.mul(other))
+        }
+    }
+
+    /// Returns the bit at `index` of the two's complement representation,
+    /// where index 0 is the least significant bit.
+    ///
+    /// ### Panics
+    ///
+    /// If `index` is 256 or greater.
+    pub fn bit(&self, index: u32) -> bool {
+        bit_limbs(&self.to_limbs(), index)
+    }
+
+    /// Sets the bit at `index` of the two's complement representation to
+    /// `value`, where index 0 is the least significant bit.
+    ///
+    /// ### Panics
+    ///
+    /// If `index` is 256 or greater.
+    pub fn set_bit(&mut self, index: u32, value: bool) {
+        let mut limbs = self.to_limbs();
+        set_bit_limbs(&mut limbs, index, value);
+        *self = I256::from_parts(&self.env, limbs[3] as i64, limbs[2], limbs[1], limbs[0]);
+    }
+
+    /// Returns the number of leading zeros in the two's complement
+    /// representation.
+    pub fn leading_zeros(&self) -> u32 {
+        leading_zeros_limbs(&self.to_limbs())
+    }
+
+    /// Returns the number of ones in the two's complement representation.
+    pub fn count_ones(&self) -> u32 {
+        self.to_limbs().iter().map(|l| l.count_ones()).sum()
+    }
+
+    /// Returns the value as a string of its decimal digits, without leading
+    /// zeros, preceded by a `-` sign if negative.
+    ///
+    /// The digits are computed in the guest.
+    pub fn to_decimal_string(&self) -> String {
+        let mut limbs = self.to_limbs();
+        let negative = limbs[3] >> 63 == 1;
+        if negative {
+            neg_limbs(&mut limbs);
+        }
+        decimal_string_from_limbs(&self.env, limbs, negative)
+    }
+
+    /// Parses a string of decimal digits, optionally preceded by a `-` or `+`
+    /// sign.
+    ///
+    /// Leading zeros are accepted, so `"-007"` parses as -7, and `"-0"`
+    /// parses as 0. Leading or trailing whitespace, a second sign, digit
+    /// separators, a decimal point, and any other characters are rejected
+    /// rather than ignored.
+    ///
+    /// ### Errors
+    ///
+    /// - [ParseError::Empty] if the string has no digits, such as `""` or
+    ///   `"-"`.
+    /// - [ParseError::InvalidChar] if the string contains any other
+    ///   character.
+    /// - [ParseError::Overflow] if the value is less than `-2^255` or
+    ///   greater than `2^255 - 1`.
+    pub fn from_decimal_string(env: &Env, s: &String) -> Result<I256, ParseError> {
+        let (mut limbs, negative) = limbs_from_decimal_string(s, true)?;
+        // The magnitude of the minimum value, 2^255.
+        let min_magnitude = [0, 0, 0, 1 << 63];
+        if negative {
+            if lt_limbs(&min_magnitude, &limbs) {
+                return Err(ParseError::Overflow);
+            }
+            neg_limbs(&mut limbs);
+        } else if !lt_limbs(&limbs, &min_magnitude) {
+            return Err(ParseError::Overflow);
+        }
+        Ok(I256::from_parts(
+            env,
+            limbs[3] as i64,
+            limbs[2],
+            limbs[1],
+            limbs[0],
+        ))
+    }
+
+    // Returns the two's complement representation as little-endian u64 limbs.
+    fn to_limbs(&self) -> [u64; 4] {
+        limbs_from_be_bytes(&self.to_be_bytes())
+    }
+
+    /// Returns `-self`, or `None` if `self` is the minimum value, whose
+    /// negation can't be represented.
+    pub fn checked_neg(&self) -> Option<I256> {
+        let env = &self.env;
+        if *self == I256::min(env) {
+            None
+        } else {
+            Some(I256::from_i32(env, 0).sub(self))
+        }
+    }
+
+    fn max(env: &Env) -> I256 {
+        I256::from_parts(env, i64::MAX, u64::MAX, u64::MAX, u64::MAX)
+    }
+
+    fn min(env: &Env) -> I256 {
+        I256::from_parts(env, i64::MIN, 0, 0, 0)
+    }
 }
 
 #[doc = "Timepoint holds a 64-bit unsigned integer."]
@@ -629,6 +1276,282 @@ mod test {
         assert_eq!(u3.rem_euclid(&u4), U256::from_u32(&env, 3));
     }
 
+    #[test]
+    fn test_u256_pow_mod() {
+        let env = Env::default();
+
+        let u = |v: u32| U256::from_u32(&env, v);
+        assert_eq!(u(4).pow_mod(&u(13), &u(497)), u(445));
+        assert_eq!(u(2).pow_mod(&u(10), &u(1000)), u(24));
+        // base larger than the modulus
+        assert_eq!(u(1003).pow_mod(&u(3), &u(10)), u(7));
+        // exponent zero
+        assert_eq!(u(5).pow_mod(&u(0), &u(7)), u(1));
+        assert_eq!(u(0).pow_mod(&u(0), &u(7)), u(1));
+        // modulus one
+        assert_eq!(u(5).pow_mod(&u(3), &u(1)), u(0));
+        assert_eq!(u(5).pow_mod(&u(0), &u(1)), u(0));
+
+        // wide values, where intermediate products exceed 256 bits
+        let max = U256::from_parts(&env, u64::MAX, u64::MAX, u64::MAX, u64::MAX);
+        let max_minus_one = U256::from_parts(&env, u64::MAX, u64::MAX, u64::MAX, u64::MAX - 1);
+        // (m - 1)^e mod m is 1 for even e and m - 1 for odd e
+        assert_eq!(max_minus_one.pow_mod(&u(2), &max), u(1));
+        assert_eq!(max_minus_one.pow_mod(&u(3), &max), max_minus_one);
+        // Fermat's little theorem for the BN254 scalar field modulus
+        let r = U256::from_parts(
+            &env,
+            0x30644e72e131a029,
+            0xb85045b68181585d,
+            0x2833e84879b97091,
+            0x43e1f593f0000001,
+        );
+        let r_minus_one = r.sub(&u(1));
+        assert_eq!(u(12345).pow_mod(&r_minus_one, &r), u(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_u256_pow_mod_zero_modulus() {
+        let env = Env::default();
+        U256::from_u32(&env, 2).pow_mod(&U256::from_u32(&env, 3), &U256::from_u32(&env, 0));
+    }
+
+    #[test]
+    fn test_u256_mul_div() {
+        let env = Env::default();
+
+        let u = |v: u32| U256::from_u32(&env, v);
+        assert_eq!(u(6).mul_div(&u(4), &u(3)), u(8));
+        assert_eq!(u(0).mul_div(&u(4), &u(3)), u(0));
+        // rounding of inexact quotients
+        assert_eq!(u(7).mul_div(&u(3), &u(2)), u(10));
+        assert_eq!(u(7).mul_div_floor(&u(3), &u(2)), u(10));
+        assert_eq!(u(7).mul_div_ceil(&u(3), &u(2)), u(11));
+        // exact quotients are not rounded up
+        assert_eq!(u(6).mul_div_ceil(&u(4), &u(3)), u(8));
+
+        // the product of two u128 values overflows a u128, but the quotient fits
+        let a = U256::from_u128(&env, u128::MAX);
+        let b = U256::from_u128(&env, u128::MAX - 1);
+        assert_eq!(a.mul_div(&b, &a).to_u128(), Some(u128::MAX - 1));
+        let half = U256::from_u128(&env, 1 << 127);
+        assert_eq!(
+            half.mul_div(&u(6), &u(4)).to_u128(),
+            Some((1 << 127) + (1 << 126))
+        );
+
+        // the product of two u256 values overflows a u256, but the quotient fits
+        let max = U256::from_parts(&env, u64::MAX, u64::MAX, u64::MAX, u64::MAX);
+        assert_eq!(max.mul_div(&max, &max), max);
+        assert_eq!(max.mul_div_ceil(&max, &max), max);
+        assert_eq!(max.mul_div(&u(2), &u(4)), max.shr(1));
+        assert_eq!(max.mul_div_ceil(&u(2), &u(4)), max.shr(1).add(&u(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "mul_div division by zero")]
+    fn test_u256_mul_div_zero_divisor() {
+        let env = Env::default();
+        let u = |v: u32| U256::from_u32(&env, v);
+        u(2).mul_div(&u(3), &u(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "mul_div overflow")]
+    fn test_u256_mul_div_overflow() {
+        let env = Env::default();
+        let max = U256::from_parts(&env, u64::MAX, u64::MAX, u64::MAX, u64::MAX);
+        max.mul_div(&U256::from_u32(&env, 3), &U256::from_u32(&env, 2));
+    }
+
+    #[test]
+    fn test_u256_checked_arith() {
+        let env = Env::default();
+
+        let u = |v: u32| U256::from_u32(&env, v);
+        let max = U256::from_parts(&env, u64::MAX, u64::MAX, u64::MAX, u64::MAX);
+        let half = max.shr(1);
+
+        assert_eq!(u(6).checked_add(&u(3)), Some(u(9)));
+        assert_eq!(max.sub(&u(1)).checked_add(&u(1)), Some(max.clone()));
+        assert_eq!(max.checked_add(&u(0)), Some(max.clone()));
+        assert_eq!(max.checked_add(&u(1)), None);
+        assert_eq!(max.checked_add(&max), None);
+
+        assert_eq!(u(6).checked_sub(&u(6)), Some(u(0)));
+        assert_eq!(max.checked_sub(&max), Some(u(0)));
+        assert_eq!(u(0).checked_sub(&u(1)), None);
+        assert_eq!(u(5).checked_sub(&u(6)), None);
+
+        assert_eq!(u(6).checked_mul(&u(3)), Some(u(18)));
+        assert_eq!(max.checked_mul(&u(0)), Some(u(0)));
+        assert_eq!(u(0).checked_mul(&max), Some(u(0)));
+        assert_eq!(max.checked_mul(&u(1)), Some(max.clone()));
+        // (2^255 - 1) * 2 = 2^256 - 2
+        assert_eq!(half.checked_mul(&u(2)), Some(max.sub(&u(1))));
+        assert_eq!(half.add(&u(1)).checked_mul(&u(2)), None);
+        assert_eq!(max.checked_mul(&max), None);
+    }
+
+    #[test]
+    fn test_u256_isqrt() {
+        let env = Env::default();
+
+        let u = |v: u128| U256::from_u128(&env, v);
+        assert_eq!(u(0).isqrt(), u(0));
+        assert_eq!(u(1).isqrt(), u(1));
+        assert_eq!(u(2).isqrt(), u(1));
+        assert_eq!(u(3).isqrt(), u(1));
+        assert_eq!(u(4).isqrt(), u(2));
+        assert_eq!(u(15).isqrt(), u(3));
+        assert_eq!(u(16).isqrt(), u(4));
+        assert_eq!(u(17).isqrt(), u(4));
+
+        // perfect squares and their neighbours
+        for r in [7u128, 1_000_000, u64::MAX as u128, u128::MAX] {
+            let r = u(r);
+            let square = r.mul(&r);
+            assert_eq!(square.isqrt(), r);
+            assert_eq!(square.sub(&u(1)).isqrt(), r.sub(&u(1)));
+            if let Some(next) = square.checked_add(&u(1)) {
+                assert_eq!(next.isqrt(), r);
+            }
+        }
+
+        // 2^256 - 1 has root 2^128 - 1
+        let max = U256::from_parts(&env, u64::MAX, u64::MAX, u64::MAX, u64::MAX);
+        assert_eq!(max.isqrt(), u(u128::MAX));
+
+        // isqrt(n)^2 <= n < (isqrt(n) + 1)^2
+        for n in [
+            u(123_456_789),
+            U256::from_parts(&env, 1, 0, 0, 0),
+            U256::from_parts(&env, 0x1234, 0x5678, 0x9abc, 0xdef0),
+            max.shr(1),
+        ] {
+            let r = n.isqrt();
+            assert!(r.mul(&r) <= n);
+            let next = r.add(&u(1));
+            assert!(next.checked_mul(&next).is_none_or(|s| s > n));
+        }
+    }
+
+    #[test]
+    fn test_u256_bits() {
+        let env = Env::default();
+
+        let mut u = U256::from_u32(&env, 0);
+        assert_eq!(u.leading_zeros(), 256);
+        assert_eq!(u.count_ones(), 0);
+
+        for index in [0, 127, 255] {
+            assert!(!u.bit(index));
+            u.set_bit(index, true);
+            assert!(u.bit(index));
+        }
+        assert_eq!(u, U256::from_parts(&env, 1 << 63, 0, 1 << 63, 1));
+        assert_eq!(u.leading_zeros(), 0);
+        assert_eq!(u.count_ones(), 3);
+        assert!(!u.bit(1));
+        assert!(!u.bit(128));
+
+        // setting an already set bit is a no-op
+        u.set_bit(127, true);
+        assert_eq!(u.count_ones(), 3);
+
+        u.set_bit(255, false);
+        assert!(!u.bit(255));
+        assert_eq!(u.leading_zeros(), 128);
+        u.set_bit(127, false);
+        assert_eq!(u.leading_zeros(), 255);
+        u.set_bit(0, false);
+        assert_eq!(u, U256::from_u32(&env, 0));
+
+        let max = U256::from_parts(&env, u64::MAX, u64::MAX, u64::MAX, u64::MAX);
+        assert_eq!(max.count_ones(), 256);
+        assert_eq!(U256::from_u32(&env, 1).leading_zeros(), 255);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit index out of range")]
+    fn test_u256_bit_out_of_range() {
+        let env = Env::default();
+        U256::from_u32(&env, 0).bit(256);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit index out of range")]
+    fn test_u256_set_bit_out_of_range() {
+        let env = Env::default();
+        U256::from_u32(&env, 0).set_bit(256, true);
+    }
+
+    #[test]
+    fn test_i256_bits() {
+        let env = Env::default();
+
+        let minus_one = I256::from_i32(&env, -1);
+        assert_eq!(minus_one.count_ones(), 256);
+        assert_eq!(minus_one.leading_zeros(), 0);
+        assert!(minus_one.bit(255));
+
+        let mut i = I256::from_i32(&env, 0);
+        i.set_bit(0, true);
+        i.set_bit(127, true);
+        assert_eq!(i, I256::from_parts(&env, 0, 0, 1 << 63, 1));
+        assert_eq!(i.leading_zeros(), 128);
+        // setting the sign bit makes the value negative
+        i.set_bit(255, true);
+        assert_eq!(i, I256::from_parts(&env, i64::MIN, 0, 1 << 63, 1));
+        assert!(i < I256::from_i32(&env, 0));
+        assert_eq!(i.count_ones(), 3);
+
+        let mut i = minus_one.clone();
+        i.set_bit(0, false);
+        assert_eq!(i, I256::from_i32(&env, -2));
+    }
+
+    #[test]
+    fn test_i256_checked_arith() {
+        let env = Env::default();
+
+        let i = |v: i32| I256::from_i32(&env, v);
+        let max = I256::from_parts(&env, i64::MAX, u64::MAX, u64::MAX, u64::MAX);
+        let min = I256::from_parts(&env, i64::MIN, 0, 0, 0);
+
+        assert_eq!(i(-6).checked_add(&i(3)), Some(i(-3)));
+        assert_eq!(max.checked_add(&i(0)), Some(max.clone()));
+        assert_eq!(max.checked_add(&i(1)), None);
+        assert_eq!(max.checked_add(&i(-1)), Some(max.sub(&i(1))));
+        assert_eq!(min.checked_add(&i(-1)), None);
+        assert_eq!(min.checked_add(&max), Some(i(-1)));
+
+        assert_eq!(i(-6).checked_sub(&i(3)), Some(i(-9)));
+        assert_eq!(min.checked_sub(&i(1)), None);
+        assert_eq!(min.checked_sub(&i(-1)), Some(min.add(&i(1))));
+        assert_eq!(max.checked_sub(&i(-1)), None);
+        assert_eq!(i(0).checked_sub(&min), None);
+        assert_eq!(i(-1).checked_sub(&min), Some(max.clone()));
+
+        assert_eq!(i(-6).checked_mul(&i(3)), Some(i(-18)));
+        assert_eq!(i(-6).checked_mul(&i(-3)), Some(i(18)));
+        assert_eq!(min.checked_mul(&i(0)), Some(i(0)));
+        assert_eq!(max.checked_mul(&i(1)), Some(max.clone()));
+        assert_eq!(max.checked_mul(&i(-1)), Some(min.add(&i(1))));
+        assert_eq!(min.checked_mul(&i(1)), Some(min.clone()));
+        assert_eq!(min.checked_mul(&i(-1)), None);
+        assert_eq!(i(-1).checked_mul(&min), None);
+        assert_eq!(max.checked_mul(&i(2)), None);
+        assert_eq!(min.checked_mul(&i(2)), None);
+        assert_eq!(min.checked_mul(&min), None);
+
+        assert_eq!(i(5).checked_neg(), Some(i(-5)));
+        assert_eq!(i(0).checked_neg(), Some(i(0)));
+        assert_eq!(max.checked_neg(), Some(min.add(&i(1))));
+        assert_eq!(min.checked_neg(), None);
+    }
+
     #[test]
     fn test_i256_arith() {
         let env = Env::default();
@@ -647,4 +1570,120 @@ mod test {
         let u4 = I256::from_i32(&env, 4);
         assert_eq!(u3.rem_euclid(&u4), I256::from_i32(&env, 1));
     }
+
+    #[test]
+    fn test_u256_decimal_string() {
+        let env = Env::default();
+        let s = |s: &str| String::from_str(&env, s);
+        let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+
+        for (u, text) in [
+            (U256::from_u32(&env, 0), "0"),
+            (U256::from_u32(&env, 7), "7"),
+            (
+                U256::from_u128(&env, 10_000_000_000_000_000_000),
+                "10000000000000000000",
+            ),
+            (U256::from_parts(&env, 0, 0, 1, 0), "18446744073709551616"),
+            (U256::max(&env), max),
+        ] {
+            assert_eq!(u.to_decimal_string(), s(text));
+            assert_eq!(U256::from_decimal_string(&env, &s(text)), Ok(u));
+        }
+
+        assert_eq!(
+            U256::from_decimal_string(&env, &s("+007")),
+            Ok(U256::from_u32(&env, 7))
+        );
+        // Leading zeros beyond a chunk are fine.
+        let padded = std::format!("{}{max}", "0".repeat(100));
+        assert_eq!(
+            U256::from_decimal_string(&env, &s(&padded)),
+            Ok(U256::max(&env))
+        );
+
+        // One more than the maximum.
+        let over = "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        assert_eq!(
+            U256::from_decimal_string(&env, &s(over)),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(
+            U256::from_decimal_string(&env, &s(&"9".repeat(100))),
+            Err(ParseError::Overflow)
+        );
+
+        assert_eq!(
+            U256::from_decimal_string(&env, &s("")),
+            Err(ParseError::Empty)
+        );
+        assert_eq!(
+            U256::from_decimal_string(&env, &s("+")),
+            Err(ParseError::Empty)
+        );
+        for text in ["-1", "-0", " 1", "1 ", "1.0", "1_000", "0x10", "++1"] {
+            assert_eq!(
+                U256::from_decimal_string(&env, &s(text)),
+                Err(ParseError::InvalidChar),
+                "{text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_i256_decimal_string() {
+        let env = Env::default();
+        let s = |s: &str| String::from_str(&env, s);
+        let max = "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+        let min = "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+
+        for (i, text) in [
+            (I256::from_i32(&env, 0), "0"),
+            (I256::from_i32(&env, -1), "-1"),
+            (I256::from_i32(&env, 42), "42"),
+            (
+                I256::from_i128(&env, i128::MIN),
+                "-170141183460469231731687303715884105728",
+            ),
+            (I256::max(&env), max),
+            (I256::min(&env), min),
+        ] {
+            assert_eq!(i.to_decimal_string(), s(text));
+            assert_eq!(I256::from_decimal_string(&env, &s(text)), Ok(i));
+        }
+
+        assert_eq!(
+            I256::from_decimal_string(&env, &s("-0")),
+            Ok(I256::from_i32(&env, 0))
+        );
+        assert_eq!(
+            I256::from_decimal_string(&env, &s("+42")),
+            Ok(I256::from_i32(&env, 42))
+        );
+
+        // One more than the maximum, and one less than the minimum.
+        let over = "57896044618658097711785492504343953926634992332820282019728792003956564819968";
+        let under =
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819969";
+        assert_eq!(
+            I256::from_decimal_string(&env, &s(over)),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(
+            I256::from_decimal_string(&env, &s(under)),
+            Err(ParseError::Overflow)
+        );
+
+        assert_eq!(
+            I256::from_decimal_string(&env, &s("-")),
+            Err(ParseError::Empty)
+        );
+        for text in ["--1", "+-1", "1-", " -1", "-1.5"] {
+            assert_eq!(
+                I256::from_decimal_string(&env, &s(text)),
+                Err(ParseError::InvalidChar),
+                "{text:?}"
+            );
+        }
+    }
 }
@@ -0,0 +1,141 @@
+//! Ownable contains a helper for a contract owner with two-step ownership
+//! transfer.
+use crate::{
+    storage::{ReservedKey, SdkKey},
+    Address, Env,
+};
+
+/// Ownable stores the owner of the current contract, and transfers ownership
+/// in two steps.
+///
+/// The owner starts a transfer with
+/// [`transfer_ownership`][Ownable::transfer_ownership], which records a
+/// pending owner. The transfer only completes when the pending owner calls
+/// [`accept_ownership`][Ownable::accept_ownership]. Until then the current
+/// owner remains the owner, and can start a transfer to a different address
+/// instead. Transferring to an address that cannot authorize, such as a
+/// mistyped address, therefore never locks out control of the contract.
+///
+/// The owner and pending owner are stored in the current contract's instance
+/// storage.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, ownable::Ownable, Address, Env};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn __constructor(env: Env, owner: Address) {
+///         Ownable::set_owner(&env, &owner);
+///     }
+///
+///     pub fn transfer_ownership(env: Env, new_owner: Address) {
+///         Ownable::transfer_ownership(&env, &new_owner);
+///     }
+///
+///     pub fn accept_ownership(env: Env) {
+///         Ownable::accept_ownership(&env);
+///     }
+///
+///     pub fn set_fee(env: Env, fee: u32) {
+///         Ownable::require_owner(&env);
+///         // ...
+///     }
+/// }
+/// # fn main() { }
+/// ```
+pub struct Ownable;
+
+impl Ownable {
+    /// Sets the initial owner of the current contract.
+    ///
+    /// Intended to be called once, such as from the contract's constructor.
+    ///
+    /// ### Panics
+    ///
+    /// If the contract already has an owner.
+    pub fn set_owner(env: &Env, owner: &Address) {
+        let storage = env.storage().instance();
+        if storage.has(&Self::owner_key()) {
+            sdk_panic!("owner already set");
+        }
+        storage.set(&Self::owner_key(), owner);
+    }
+
+    /// Returns the owner of the current contract, or `None` if no owner has
+    /// been set.
+    pub fn owner(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&Self::owner_key())
+    }
+
+    /// Returns the pending owner that a transfer of ownership is waiting on,
+    /// or `None` if there is no transfer in progress.
+    pub fn pending_owner(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&Self::pending_owner_key())
+    }
+
+    /// Requires the owner of the current contract to have authorized the
+    /// invocation, and returns the owner.
+    ///
+    /// ### Panics
+    ///
+    /// If no owner has been set.
+    ///
+    /// If the owner has not authorized the invocation.
+    pub fn require_owner(env: &Env) -> Address {
+        let Some(owner) = Self::owner(env) else {
+            sdk_panic!("owner not set");
+        };
+        owner.require_auth();
+        owner
+    }
+
+    /// Starts transferring ownership of the current contract to `new_owner`.
+    ///
+    /// The owner must authorize the invocation. Ownership is not transferred
+    /// until `new_owner` calls [`accept_ownership`][Ownable::accept_ownership].
+    /// Starting a new transfer replaces any pending transfer.
+    ///
+    /// ### Panics
+    ///
+    /// If no owner has been set.
+    ///
+    /// If the owner has not authorized the invocation.
+    pub fn transfer_ownership(env: &Env, new_owner: &Address) {
+        Self::require_owner(env);
+        env.storage()
+            .instance()
+            .set(&Self::pending_owner_key(), new_owner);
+    }
+
+    /// Completes the transfer of ownership to the pending owner.
+    ///
+    /// The pending owner must authorize the invocation.
+    ///
+    /// ### Panics
+    ///
+    /// If there is no transfer in progress.
+    ///
+    /// If the pending owner has not authorized the invocation.
+    pub fn accept_ownership(env: &Env) {
+        let Some(pending_owner) = Self::pending_owner(env) else {
+            sdk_panic!("no pending owner");
+        };
+        pending_owner.require_auth();
+        let storage = env.storage().instance();
+        storage.set(&Self::owner_key(), &pending_owner);
+        storage.remove(&Self::pending_owner_key());
+    }
+
+    fn owner_key() -> ReservedKey {
+        SdkKey::Owner.into()
+    }
+
+    fn pending_owner_key() -> ReservedKey {
+        SdkKey::PendingOwner.into()
+    }
+}
@@ -0,0 +1,107 @@
+//! Pausable contains a helper for pausing and unpausing a contract.
+use crate::{
+    storage::{ReservedKey, SdkKey},
+    Env,
+};
+
+/// Pausable stores whether the current contract is paused, and provides
+/// guards for functions that should only run while the contract is, or is
+/// not, paused.
+///
+/// The paused flag is stored in the current contract's instance storage.
+///
+/// Pausable does not check who is pausing the contract. Contracts must
+/// authorize the caller, such as an admin, before calling
+/// [`pause`][Pausable::pause] or [`unpause`][Pausable::unpause].
+///
+/// ### Events
+///
+/// Pausing publishes an event with the single topic `paused`, and unpausing
+/// publishes an event with the single topic `unpaused`. Both events have no
+/// data.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, pausable::Pausable, Address, Env};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn pause(env: Env, admin: Address) {
+///         admin.require_auth();
+///         // Check that admin is the contract's admin...
+///         Pausable::pause(&env);
+///     }
+///
+///     pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+///         Pausable::when_not_paused(&env);
+///         // ...
+///     }
+/// }
+/// # fn main() { }
+/// ```
+pub struct Pausable;
+
+impl Pausable {
+    /// Returns true if the current contract is paused.
+    pub fn is_paused(env: &Env) -> bool {
+        env.storage().instance().get(&Self::key()).unwrap_or(false)
+    }
+
+    /// Pauses the current contract.
+    ///
+    /// ### Panics
+    ///
+    /// If the contract is already paused.
+    pub fn pause(env: &Env) {
+        Self::when_not_paused(env);
+        env.storage().instance().set(&Self::key(), &true);
+        env.events()
+            .builder()
+            .topic(soroban_sdk_macros::internal_symbol_short!("paused"))
+            .publish_data(());
+    }
+
+    /// Unpauses the current contract.
+    ///
+    /// ### Panics
+    ///
+    /// If the contract is not paused.
+    pub fn unpause(env: &Env) {
+        Self::when_paused(env);
+        env.storage().instance().remove(&Self::key());
+        env.events()
+            .builder()
+            .topic(soroban_sdk_macros::internal_symbol_short!("unpaused"))
+            .publish_data(());
+    }
+
+    /// Guards a function that must not run while the contract is paused.
+    ///
+    /// ### Panics
+    ///
+    /// If the contract is paused.
+    pub fn when_not_paused(env: &Env) {
+        if Self::is_paused(env) {
+            sdk_panic!("contract is paused");
+        }
+    }
+
+    /// Guards a function that must only run while the contract is paused.
+    ///
+    /// ### Panics
+    ///
+    /// If the contract is not paused.
+    pub fn when_paused(env: &Env) {
+        if !Self::is_paused(env) {
+            sdk_panic!("contract is not paused");
+        }
+    }
+
+    fn key() -> ReservedKey {
+        SdkKey::Paused.into()
+    }
+}
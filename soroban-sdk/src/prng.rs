@@ -375,6 +375,54 @@ impl Prng {
     {
         v.shuffle(self);
     }
+
+    /// Returns `n` items sampled from the vec without replacement.
+    ///
+    /// Every subset of `n` items is equally likely to be returned, and the
+    /// items returned are in random order. The vec is not modified.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is greater than the length of the vec.
+    ///
+    /// # Warning
+    ///
+    /// **The PRNG is unsuitable for generating secrets or use in applications with
+    /// low risk tolerance, see the module-level comment.**
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use soroban_sdk::{Env, contract, contractimpl, symbol_short, vec, Bytes};
+    /// #
+    /// # #[contract]
+    /// # pub struct Contract;
+    /// #
+    /// # #[cfg(feature = "testutils")]
+    /// # fn main() {
+    /// #     let env = Env::default();
+    /// #     let contract_id = env.register(Contract, ());
+    /// #     env.as_contract(&contract_id, || {
+    /// #         env.prng().seed(Bytes::from_array(&env, &[1; 32]));
+    /// let candidates = vec![&env, 1u32, 2, 3, 4, 5];
+    /// let committee = env.prng().sample(&candidates, 3);
+    /// assert_eq!(committee.len(), 3);
+    /// #     })
+    /// # }
+    /// # #[cfg(not(feature = "testutils"))]
+    /// # fn main() { }
+    /// ```
+    pub fn sample<T>(&self, v: &Vec<T>, n: u32) -> Vec<T> {
+        let env = self.env();
+        assert_in_contract!(env);
+        if n > v.len() {
+            sdk_panic!("sample size is greater than the vec length");
+        }
+        // The first n items of a uniformly shuffled copy are a uniform sample.
+        let obj = internal::Env::prng_vec_shuffle(env, v.to_object()).unwrap_infallible();
+        let obj = internal::Env::vec_slice(env, obj, 0u32.into(), n.into()).unwrap_infallible();
+        unsafe { Vec::unchecked_new(env.clone(), obj) }
+    }
 }
 
 impl<T> Shuffle for Vec<T> {
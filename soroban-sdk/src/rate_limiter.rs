@@ -0,0 +1,78 @@
+//! Rate limiter contains a helper for limiting how often an address can take
+//! an action.
+use crate::{
+    storage::{ReservedKey, SdkKey},
+    Address, Env,
+};
+
+/// RateLimiter limits how often each address can take an action, such as
+/// claiming from a faucet, to once per interval.
+///
+/// The ledger timestamp of each address's last action is stored in the
+/// current contract's temporary storage.
+///
+/// RateLimiter does not check who the address is. Contracts must authorize
+/// the address before calling
+/// [`check_and_record`][RateLimiter::check_and_record].
+///
+/// ### Storage
+///
+/// The timestamp is stored for at least as many ledgers as there are seconds
+/// in the interval, limited to the maximum TTL, and ledgers close no more
+/// often than once a second, so the timestamp is kept for the interval. An
+/// interval longer than the maximum TTL is not enforced once the timestamp
+/// expires.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, rate_limiter::RateLimiter, Address, Env};
+///
+/// #[contract]
+/// pub struct Faucet;
+///
+/// #[contractimpl]
+/// impl Faucet {
+///     pub fn claim(env: Env, to: Address) {
+///         to.require_auth();
+///         // Allow one claim a day for each address.
+///         RateLimiter::check_and_record(&env, &to, 86_400);
+///         // ...
+///     }
+/// }
+/// # fn main() { }
+/// ```
+pub struct RateLimiter;
+
+impl RateLimiter {
+    /// Records an action by the address at the current ledger timestamp.
+    ///
+    /// The action is allowed if the address has not taken an action before,
+    /// or if at least `min_interval_secs` seconds have passed since its last
+    /// action, so that an action exactly `min_interval_secs` after the last
+    /// one is allowed.
+    ///
+    /// ### Panics
+    ///
+    /// If the address took an action less than `min_interval_secs` seconds
+    /// ago.
+    pub fn check_and_record(env: &Env, address: &Address, min_interval_secs: u64) {
+        let now = env.ledger().timestamp();
+        let storage = env.storage().temporary();
+        let key = Self::key(address);
+        if let Some(last) = storage.get::<_, u64>(&key) {
+            if now < last.saturating_add(min_interval_secs) {
+                sdk_panic!("rate limited");
+            }
+        }
+        storage.set(&key, &now);
+        let ttl = u32::try_from(min_interval_secs)
+            .unwrap_or(u32::MAX)
+            .min(env.storage().max_ttl());
+        storage.extend_ttl(&key, ttl, ttl);
+    }
+
+    fn key(address: &Address) -> ReservedKey {
+        SdkKey::RateLimit(address.clone()).into()
+    }
+}
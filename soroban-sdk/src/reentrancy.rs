@@ -0,0 +1,81 @@
+//! Reentrancy contains a guard for protecting contract functions from
+//! re-entry.
+//!
+//! The host already prohibits a contract from being re-entered through a
+//! cross-contract call while it is on the call stack, and rejects such a call
+//! before the guard is reached. The guard in this module protects against the
+//! re-entry the host cannot see, a guarded function of a contract calling,
+//! directly or through helper functions, another guarded function of the same
+//! contract within one invocation.
+use crate::{
+    storage::{ReservedKey, SdkKey},
+    Env,
+};
+
+/// ReentrancyGuard marks the current contract as entered for as long as the
+/// guard is held.
+///
+/// The guard stores a flag in the current contract's instance storage when it
+/// is created, and removes the flag when it is dropped. Creating a guard while
+/// another guard is held by the same contract panics. Each guarded call pays
+/// for the write of the flag to instance storage, so functions that only need
+/// the protection the host gives against cross-contract re-entry do not need
+/// a guard.
+///
+/// If the invocation fails the flag is discarded along with every other write
+/// the invocation made, so a failed call never leaves the contract locked.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, reentrancy::ReentrancyGuard, Address, Env, IntoVal, Symbol};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn withdraw(env: Env, callee: Address) {
+///         let _guard = ReentrancyGuard::new(&env);
+///         // Any guarded function of this contract called before the guard is
+///         // dropped panics.
+///         env.invoke_contract::<()>(&callee, &Symbol::new(&env, "on_withdraw"), ().into_val(&env));
+///     }
+/// }
+/// # fn main() { }
+/// ```
+pub struct ReentrancyGuard {
+    env: Env,
+}
+
+impl ReentrancyGuard {
+    /// Acquires the guard for the current contract.
+    ///
+    /// ### Panics
+    ///
+    /// If a guard is already held by the current contract.
+    pub fn new(env: &Env) -> ReentrancyGuard {
+        let storage = env.storage().instance();
+        let key = Self::key();
+        if storage.has(&key) {
+            sdk_panic!("reentrant call");
+        }
+        storage.set(&key, &true);
+        ReentrancyGuard { env: env.clone() }
+    }
+
+    /// Returns true if a guard is currently held by the current contract.
+    pub fn is_entered(env: &Env) -> bool {
+        env.storage().instance().has(&Self::key())
+    }
+
+    fn key() -> ReservedKey {
+        SdkKey::Reentrant.into()
+    }
+}
+
+impl Drop for ReentrancyGuard {
+    fn drop(&mut self) {
+        self.env.storage().instance().remove(&Self::key());
+    }
+}
@@ -0,0 +1,292 @@
+use crate::{
+    iter::UnwrappedIter, vec::VecTryIter, ConversionError, Env, IntoVal, TryFromVal, Val, Vec,
+};
+
+/// RingBuffer is a [Vec] with a fixed capacity, that evicts its oldest item
+/// when an item is pushed while it is full.
+///
+/// It is suited to keeping a bounded history, such as the last N price
+/// observations of an oracle.
+///
+/// The items are stored in a [Vec] in the order they are written to, which
+/// wraps around to the start once the buffer is full. Pushing an item is a
+/// constant number of host calls, and reading the items in chronological
+/// order rotates the [Vec] with a constant number of host calls.
+///
+/// ### Storage
+///
+/// RingBuffer can be stored as a contract value, which is encoded as the tuple
+/// `(capacity, start, items)`, where `start` is the position in `items` of the
+/// oldest item.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{vec, Env, RingBuffer};
+///
+/// let env = Env::default();
+/// let mut prices = RingBuffer::<i128>::new(&env, 3);
+/// for price in [100, 101, 102, 103] {
+///     prices.push(price);
+/// }
+/// assert_eq!(prices.to_vec(), vec![&env, 101, 102, 103]);
+/// ```
+pub struct RingBuffer<T> {
+    capacity: u32,
+    start: u32,
+    items: Vec<T>,
+}
+
+impl<T> Clone for RingBuffer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            capacity: self.capacity,
+            start: self.start,
+            items: self.items.clone(),
+        }
+    }
+}
+
+impl<T> RingBuffer<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    /// Create an empty RingBuffer that holds up to `capacity` items.
+    ///
+    /// ### Panics
+    ///
+    /// If the capacity is zero.
+    pub fn new(env: &Env, capacity: u32) -> Self {
+        if capacity == 0 {
+            sdk_panic!("ring buffer capacity must be non-zero");
+        }
+        RingBuffer {
+            capacity,
+            start: 0,
+            items: Vec::new(env),
+        }
+    }
+
+    /// Returns the maximum number of items in the RingBuffer.
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Returns the number of items in the RingBuffer.
+    pub fn len(&self) -> u32 {
+        self.items.len()
+    }
+
+    /// Returns true if the RingBuffer has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns true if the RingBuffer holds as many items as its capacity, so
+    /// that the next push evicts the oldest item.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity
+    }
+
+    /// Appends an item as the newest item.
+    ///
+    /// Returns the oldest item if it was evicted to make room, otherwise
+    /// `None`.
+    pub fn push(&mut self, item: T) -> Option<T> {
+        if !self.is_full() {
+            self.items.push_back(item);
+            return None;
+        }
+        let evicted = self.items.get_unchecked(self.start);
+        self.items.set(self.start, item);
+        self.start = (self.start + 1) % self.capacity;
+        Some(evicted)
+    }
+
+    /// Returns the oldest item, or None if empty.
+    pub fn oldest(&self) -> Option<T> {
+        self.items.get(self.start)
+    }
+
+    /// Returns the newest item, or None if empty.
+    pub fn newest(&self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.items.get((self.start + self.len() - 1) % self.len())
+    }
+
+    /// Returns the items in chronological order, from the oldest to the
+    /// newest.
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<T> {
+        let mut items = self.items.slice(self.start..);
+        items.append(&self.items.slice(..self.start));
+        items
+    }
+
+    /// Returns an iterator over the items in chronological order, from the
+    /// oldest to the newest.
+    pub fn iter_chronological(&self) -> UnwrappedIter<VecTryIter<T>, T, T::Error>
+    where
+        T: Clone,
+        T::Error: core::fmt::Debug,
+    {
+        self.to_vec().into_iter()
+    }
+}
+
+impl<T> TryFromVal<Env, Val> for RingBuffer<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    type Error = ConversionError;
+
+    fn try_from_val(env: &Env, val: &Val) -> Result<Self, Self::Error> {
+        let fields = Vec::<Val>::try_from_val(env, val)?;
+        if fields.len() != 3 {
+            return Err(ConversionError);
+        }
+        let capacity = u32::try_from_val(env, &fields.get_unchecked(0))?;
+        let start = u32::try_from_val(env, &fields.get_unchecked(1))?;
+        let items = Vec::<T>::try_from_val(env, &fields.get_unchecked(2))?;
+        let len = items.len();
+        // The items only wrap around once the buffer is full.
+        let valid =
+            capacity != 0 && len <= capacity && (start == 0 || (start < len && len == capacity));
+        if !valid {
+            return Err(ConversionError);
+        }
+        Ok(RingBuffer {
+            capacity,
+            start,
+            items,
+        })
+    }
+}
+
+impl<T> TryFromVal<Env, RingBuffer<T>> for Val {
+    type Error = ConversionError;
+
+    fn try_from_val(env: &Env, v: &RingBuffer<T>) -> Result<Self, Self::Error> {
+        Ok(Vec::<Val>::try_from_val(env, &(v.capacity, v.start, v.items.clone()))?.to_val())
+    }
+}
+
+impl<T> TryFromVal<Env, &RingBuffer<T>> for Val {
+    type Error = ConversionError;
+
+    fn try_from_val(env: &Env, v: &&RingBuffer<T>) -> Result<Self, Self::Error> {
+        TryFromVal::<_, RingBuffer<T>>::try_from_val(env, *v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{vec, TryIntoVal};
+
+    #[test]
+    fn test_push_within_capacity() {
+        let env = Env::default();
+        let mut rb = RingBuffer::<u32>::new(&env, 3);
+        assert!(rb.is_empty());
+        assert_eq!(rb.oldest(), None);
+        assert_eq!(rb.newest(), None);
+
+        assert_eq!(rb.push(1), None);
+        assert_eq!(rb.push(2), None);
+        assert_eq!(rb.len(), 2);
+        assert!(!rb.is_full());
+        assert_eq!(rb.oldest(), Some(1));
+        assert_eq!(rb.newest(), Some(2));
+        assert_eq!(rb.to_vec(), vec![&env, 1, 2]);
+    }
+
+    #[test]
+    fn test_push_past_capacity_evicts_oldest() {
+        let env = Env::default();
+        let mut rb = RingBuffer::<u32>::new(&env, 3);
+        let evicted: std::vec::Vec<_> = (1..=8).map(|i| rb.push(i)).collect();
+        assert_eq!(
+            evicted,
+            [
+                None,
+                None,
+                None,
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(4),
+                Some(5)
+            ]
+        );
+        assert!(rb.is_full());
+        assert_eq!(rb.len(), 3);
+        assert_eq!(rb.capacity(), 3);
+        assert_eq!(rb.oldest(), Some(6));
+        assert_eq!(rb.newest(), Some(8));
+        assert_eq!(rb.to_vec(), vec![&env, 6, 7, 8]);
+        assert_eq!(
+            rb.iter_chronological().collect::<std::vec::Vec<_>>(),
+            [6, 7, 8]
+        );
+
+        // Wrapping all the way around returns to the start.
+        rb.push(9);
+        assert_eq!(rb.to_vec(), vec![&env, 7, 8, 9]);
+        assert_eq!(rb.newest(), Some(9));
+    }
+
+    #[test]
+    fn test_capacity_one() {
+        let env = Env::default();
+        let mut rb = RingBuffer::<u32>::new(&env, 1);
+        assert_eq!(rb.push(1), None);
+        assert_eq!(rb.push(2), Some(1));
+        assert_eq!(rb.oldest(), Some(2));
+        assert_eq!(rb.newest(), Some(2));
+        assert_eq!(rb.to_vec(), vec![&env, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ring buffer capacity must be non-zero")]
+    fn test_zero_capacity_panics() {
+        let env = Env::default();
+        let _ = RingBuffer::<u32>::new(&env, 0);
+    }
+
+    #[test]
+    fn test_val_round_trip() {
+        let env = Env::default();
+        let mut rb = RingBuffer::<u32>::new(&env, 3);
+        for i in 1..=5 {
+            rb.push(i);
+        }
+        let val: Val = rb.try_into_val(&env).unwrap();
+        let mut rb = RingBuffer::<u32>::try_from_val(&env, &val).unwrap();
+        assert_eq!(rb.to_vec(), vec![&env, 3, 4, 5]);
+        rb.push(6);
+        assert_eq!(rb.to_vec(), vec![&env, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_val_invalid() {
+        let env = Env::default();
+        for (capacity, start, items) in [
+            (0u32, 0u32, Vec::<u32>::new(&env)),
+            (2, 0, vec![&env, 1, 2, 3]),
+            // Wrapped before the buffer is full.
+            (3, 1, vec![&env, 1, 2]),
+            (2, 2, vec![&env, 1, 2]),
+        ] {
+            let val = Vec::<Val>::try_from_val(&env, &(capacity, start, items))
+                .unwrap()
+                .to_val();
+            assert_eq!(
+                RingBuffer::<u32>::try_from_val(&env, &val).err(),
+                Some(ConversionError)
+            );
+        }
+    }
+}
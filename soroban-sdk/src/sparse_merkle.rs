@@ -0,0 +1,167 @@
+//! Sparse Merkle contains an accumulator that commits to a set of key-value
+//! pairs, and can prove both that a key is in the set and that a key is not.
+//!
+//! ### Tree
+//!
+//! The tree is a binary tree of SHA-256 hashes of depth [`DEPTH`], with one
+//! leaf for every possible 32 byte key. The key, read as a 256-bit big-endian
+//! integer, is the index of its leaf, so the bits of the key from the most
+//! significant bit down are the path from the root to the leaf, where `0` is
+//! left and `1` is right.
+//!
+//! Leaves and interior nodes are hashed with the same domain separation as
+//! [`crypto::merkle`][crate::crypto::merkle]:
+//!
+//! - Leaf with a value: `sha256(0x00 || key || value)`
+//! - Leaf without a value: [`EMPTY_LEAF`], 32 zero bytes
+//! - Node: `sha256(0x01 || left || right)`
+//!
+//! A subtree with no values has the same hash everywhere at the same height,
+//! so the root of the empty tree is a constant, [`EMPTY_ROOT`].
+//!
+//! ### Proof
+//!
+//! A proof for a key is the list of the [`DEPTH`] sibling hashes on the path
+//! from the key's leaf to the root, starting with the leaf's sibling. The same
+//! proof proves inclusion when the leaf has a value, and exclusion when the
+//! leaf is empty. Proofs are constructed off-chain from the full set of
+//! key-value pairs.
+use crate::{
+    crypto::merkle,
+    storage::{ReservedKey, SdkKey},
+    Bytes, BytesN, Env, Vec,
+};
+
+/// The depth of the tree, which is the number of bits in a key.
+pub const DEPTH: u32 = 256;
+
+/// The hash of a leaf that has no value.
+pub const EMPTY_LEAF: [u8; 32] = [0; 32];
+
+/// The root of the tree with no values.
+pub const EMPTY_ROOT: [u8; 32] = [
+    0x61, 0x55, 0x28, 0x91, 0x30, 0x89, 0x38, 0x72, 0x35, 0x5e, 0xac, 0x98, 0x04, 0x2d, 0x22, 0xae,
+    0xfa, 0x2c, 0x2e, 0x70, 0x8b, 0xea, 0x16, 0x94, 0x02, 0x76, 0x0e, 0x3b, 0x55, 0xf9, 0xa2, 0xdc,
+];
+
+const LEAF_PREFIX: u8 = 0x00;
+
+/// Returns the hash of the leaf for `key` with `value`,
+/// `sha256(0x00 || key || value)`.
+pub fn leaf_hash(env: &Env, key: &BytesN<32>, value: &BytesN<32>) -> BytesN<32> {
+    let mut buf = [0u8; 65];
+    buf[0] = LEAF_PREFIX;
+    buf[1..33].copy_from_slice(&key.to_array());
+    buf[33..].copy_from_slice(&value.to_array());
+    env.crypto()
+        .sha256(&Bytes::from_slice(env, &buf))
+        .to_bytes()
+}
+
+/// SparseMerkle stores the root of a sparse Merkle tree in the current
+/// contract's instance storage.
+///
+/// Only the root is stored. Values are inserted by providing an exclusion
+/// proof for the key against the current root, which is also enough to
+/// compute the new root. See the [module documentation][self] for how the tree
+/// and the proofs are constructed.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, sparse_merkle::SparseMerkle, BytesN, Env, Vec};
+///
+/// #[contract]
+/// pub struct Registry;
+///
+/// #[contractimpl]
+/// impl Registry {
+///     pub fn revoke(env: Env, id: BytesN<32>, reason: BytesN<32>, proof: Vec<BytesN<32>>) {
+///         // ... check authorization ...
+///         SparseMerkle::insert(&env, &id, &reason, &proof);
+///     }
+///
+///     pub fn is_not_revoked(env: Env, id: BytesN<32>, proof: Vec<BytesN<32>>) -> bool {
+///         SparseMerkle::verify_exclusion(&env, &id, &proof)
+///     }
+/// }
+/// # fn main() { }
+/// ```
+pub struct SparseMerkle;
+
+impl SparseMerkle {
+    /// Returns the root of the tree, which is [`EMPTY_ROOT`] until a value
+    /// has been inserted.
+    pub fn root(env: &Env) -> BytesN<32> {
+        env.storage()
+            .instance()
+            .get(&Self::root_key())
+            .unwrap_or_else(|| BytesN::from_array(env, &EMPTY_ROOT))
+    }
+
+    /// Inserts `value` for `key`, and returns the new root.
+    ///
+    /// The `proof` is the exclusion proof for `key` against the current root.
+    ///
+    /// ### Panics
+    ///
+    /// If the proof does not prove that `key` has no value, which is the case
+    /// if `key` already has a value, or if the proof is for a different root.
+    pub fn insert(
+        env: &Env,
+        key: &BytesN<32>,
+        value: &BytesN<32>,
+        proof: &Vec<BytesN<32>>,
+    ) -> BytesN<32> {
+        if !Self::verify_exclusion(env, key, proof) {
+            sdk_panic!("invalid exclusion proof");
+        }
+        let root = compute_root(env, key, leaf_hash(env, key, value), proof);
+        env.storage().instance().set(&Self::root_key(), &root);
+        root
+    }
+
+    /// Returns true if `proof` proves that `key` has `value` in the tree.
+    pub fn verify_inclusion(
+        env: &Env,
+        key: &BytesN<32>,
+        value: &BytesN<32>,
+        proof: &Vec<BytesN<32>>,
+    ) -> bool {
+        proof.len() == DEPTH
+            && compute_root(env, key, leaf_hash(env, key, value), proof) == Self::root(env)
+    }
+
+    /// Returns true if `proof` proves that `key` has no value in the tree.
+    pub fn verify_exclusion(env: &Env, key: &BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+        proof.len() == DEPTH
+            && compute_root(env, key, BytesN::from_array(env, &EMPTY_LEAF), proof)
+                == Self::root(env)
+    }
+
+    fn root_key() -> ReservedKey {
+        SdkKey::SparseMerkleRoot.into()
+    }
+}
+
+// Returns the root of the tree that has `leaf` at the leaf for `key`, and the
+// `proof` siblings on its path. The bits of the key, from the least
+// significant, decide the order at each level from the leaf up.
+fn compute_root(
+    env: &Env,
+    key: &BytesN<32>,
+    leaf: BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> BytesN<32> {
+    let key = key.to_array();
+    let mut hash = leaf;
+    for (i, sibling) in proof.iter().enumerate() {
+        let bit = (key[31 - i / 8] >> (i % 8)) & 1;
+        hash = if bit == 0 {
+            merkle::node_hash(env, &hash, &sibling)
+        } else {
+            merkle::node_hash(env, &sibling, &hash)
+        };
+    }
+    hash
+}
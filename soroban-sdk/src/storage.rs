@@ -1,10 +1,11 @@
 //! Storage contains types for storing data for the currently executing contract.
-use core::fmt::Debug;
+use core::{fmt::Debug, marker::PhantomData};
 
 use crate::{
+    contracttype,
     env::internal::{self, StorageType, Val},
     unwrap::{UnwrapInfallible, UnwrapOptimized},
-    Env, IntoVal, TryFromVal,
+    Address, Env, IntoVal, Symbol, TryFromVal, Vec,
 };
 
 /// Storage stores and retrieves data for the currently executing contract.
@@ -29,6 +30,14 @@ use crate::{
 /// of the instance. Instance storage is good for global contract data like
 /// metadata, admin accounts, or pool reserves.
 ///
+/// ### Reserved Keys
+///
+/// The contract helpers in this crate, such as
+/// [`Ownable`](crate::ownable::Ownable) and
+/// [`Pausable`](crate::pausable::Pausable), store their state under keys that
+/// encode as a vec starting with the symbol `SorobanSdk`. Contracts must not
+/// store their own data under keys of that shape.
+///
 /// ### Examples
 ///
 /// ```
@@ -203,6 +212,35 @@ impl Storage {
             .unwrap_infallible();
     }
 
+    /// Returns the values stored for each of the given keys, in the same order
+    /// as the keys.
+    pub(crate) fn get_many<K, V>(&self, keys: &Vec<K>, storage_type: StorageType) -> Vec<Option<V>>
+    where
+        K::Error: Debug,
+        V::Error: Debug,
+        K: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        let mut vals = Vec::new(&self.env);
+        for key in keys.iter() {
+            vals.push_back(self.get(&key, storage_type));
+        }
+        vals
+    }
+
+    /// Stores each of the given key-value pairs, in order.
+    pub(crate) fn set_many<K, V>(&self, pairs: &Vec<(K, V)>, storage_type: StorageType)
+    where
+        K::Error: Debug,
+        V::Error: Debug,
+        K: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        for (key, val) in pairs.iter() {
+            self.set(&key, &val, storage_type);
+        }
+    }
+
     /// Update a value stored against a key.
     ///
     /// Loads the value, calls the function with it, then sets the value to the
@@ -327,6 +365,33 @@ impl Persistent {
         self.storage.set(key, val, StorageType::Persistent)
     }
 
+    /// Returns the values stored for each of the given keys, in the same order
+    /// as the keys.
+    ///
+    /// Each value is `None` when there is no value stored for the key.
+    pub fn get_many<K, V>(&self, keys: &Vec<K>) -> Vec<Option<V>>
+    where
+        K::Error: Debug,
+        V::Error: Debug,
+        K: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        self.storage.get_many(keys, StorageType::Persistent)
+    }
+
+    /// Stores each of the given key-value pairs, in order.
+    ///
+    /// If a key appears more than once the last value for it is stored.
+    pub fn set_many<K, V>(&self, pairs: &Vec<(K, V)>)
+    where
+        K::Error: Debug,
+        V::Error: Debug,
+        K: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        self.storage.set_many(pairs, StorageType::Persistent)
+    }
+
     /// Update a value stored against a key.
     ///
     /// Loads the value, calls the function with it, then sets the value to the
@@ -385,6 +450,21 @@ impl Persistent {
     {
         self.storage.remove(key, StorageType::Persistent)
     }
+
+    /// Returns a [`StorageEntry`] for accessing the value stored under the
+    /// key, with the key and value types fixed.
+    pub fn entry<K, V>(&self, key: K) -> StorageEntry<K, V>
+    where
+        K: IntoVal<Env, Val>,
+    {
+        StorageEntry::new(self.storage.clone(), StorageType::Persistent, key)
+    }
+
+    /// Returns a [`Namespace`] that stores values under keys prefixed with
+    /// the given symbol.
+    pub fn namespace(&self, prefix: Symbol) -> Namespace {
+        Namespace::new(self.storage.clone(), StorageType::Persistent, prefix)
+    }
 }
 
 pub struct Temporary {
@@ -416,6 +496,33 @@ impl Temporary {
         self.storage.set(key, val, StorageType::Temporary)
     }
 
+    /// Returns the values stored for each of the given keys, in the same order
+    /// as the keys.
+    ///
+    /// Each value is `None` when there is no value stored for the key.
+    pub fn get_many<K, V>(&self, keys: &Vec<K>) -> Vec<Option<V>>
+    where
+        K::Error: Debug,
+        V::Error: Debug,
+        K: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        self.storage.get_many(keys, StorageType::Temporary)
+    }
+
+    /// Stores each of the given key-value pairs, in order.
+    ///
+    /// If a key appears more than once the last value for it is stored.
+    pub fn set_many<K, V>(&self, pairs: &Vec<(K, V)>)
+    where
+        K::Error: Debug,
+        V::Error: Debug,
+        K: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        self.storage.set_many(pairs, StorageType::Temporary)
+    }
+
     /// Update a value stored against a key.
     ///
     /// Loads the value, calls the function with it, then sets the value to the
@@ -474,6 +581,21 @@ impl Temporary {
     {
         self.storage.remove(key, StorageType::Temporary)
     }
+
+    /// Returns a [`StorageEntry`] for accessing the value stored under the
+    /// key, with the key and value types fixed.
+    pub fn entry<K, V>(&self, key: K) -> StorageEntry<K, V>
+    where
+        K: IntoVal<Env, Val>,
+    {
+        StorageEntry::new(self.storage.clone(), StorageType::Temporary, key)
+    }
+
+    /// Returns a [`Namespace`] that stores values under keys prefixed with
+    /// the given symbol.
+    pub fn namespace(&self, prefix: Symbol) -> Namespace {
+        Namespace::new(self.storage.clone(), StorageType::Temporary, prefix)
+    }
 }
 
 pub struct Instance {
@@ -505,6 +627,33 @@ impl Instance {
         self.storage.set(key, val, StorageType::Instance)
     }
 
+    /// Returns the values stored for each of the given keys, in the same order
+    /// as the keys.
+    ///
+    /// Each value is `None` when there is no value stored for the key.
+    pub fn get_many<K, V>(&self, keys: &Vec<K>) -> Vec<Option<V>>
+    where
+        K::Error: Debug,
+        V::Error: Debug,
+        K: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        self.storage.get_many(keys, StorageType::Instance)
+    }
+
+    /// Stores each of the given key-value pairs, in order.
+    ///
+    /// If a key appears more than once the last value for it is stored.
+    pub fn set_many<K, V>(&self, pairs: &Vec<(K, V)>)
+    where
+        K::Error: Debug,
+        V::Error: Debug,
+        K: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        self.storage.set_many(pairs, StorageType::Instance)
+    }
+
     /// Update a value stored against a key.
     ///
     /// Loads the value, calls the function with it, then sets the value to the
@@ -568,6 +717,288 @@ impl Instance {
     }
 }
 
+/// StorageEntry provides access to the value stored under a single key in
+/// [Persistent] or [Temporary] storage.
+///
+/// The key and value types are fixed when the entry is created, so the
+/// methods don't need the key or type annotations to be repeated.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn increment(env: Env) -> u32 {
+///         let counter = env.storage().persistent().entry::<_, u32>(symbol_short!("COUNTER"));
+///         let count = counter.get().unwrap_or(0) + 1;
+///         counter.set(&count);
+///         counter.extend_ttl(100, 1000);
+///         count
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+///     let env = Env::default();
+///     let contract_id = env.register(Contract, ());
+///     let client = ContractClient::new(&env, &contract_id);
+///     assert_eq!(client.increment(), 1);
+///     assert_eq!(client.increment(), 2);
+/// }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub struct StorageEntry<K, V> {
+    storage: Storage,
+    storage_type: StorageType,
+    key: K,
+    _v: PhantomData<V>,
+}
+
+impl<K, V> StorageEntry<K, V>
+where
+    K: IntoVal<Env, Val>,
+{
+    fn new(storage: Storage, storage_type: StorageType, key: K) -> Self {
+        StorageEntry {
+            storage,
+            storage_type,
+            key,
+            _v: PhantomData,
+        }
+    }
+
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns if there is a value stored for the entry.
+    pub fn has(&self) -> bool {
+        self.storage.has(&self.key, self.storage_type)
+    }
+
+    /// Returns the value stored for the entry, or `None` if there is no value.
+    ///
+    /// ### Panics
+    ///
+    /// If the stored value cannot be converted to `V`.
+    pub fn get(&self) -> Option<V>
+    where
+        V::Error: Debug,
+        V: TryFromVal<Env, Val>,
+    {
+        self.storage.get(&self.key, self.storage_type)
+    }
+
+    /// Stores the value for the entry.
+    pub fn set(&self, val: &V)
+    where
+        V: IntoVal<Env, Val>,
+    {
+        self.storage.set(&self.key, val, self.storage_type)
+    }
+
+    /// Removes the value stored for the entry.
+    ///
+    /// No-op if there is no value.
+    pub fn remove(&self) {
+        self.storage.remove(&self.key, self.storage_type)
+    }
+
+    /// Extend the TTL of the entry.
+    ///
+    /// Extends the TTL only if the TTL for the entry is below `threshold` ledgers.
+    /// The TTL will then become `extend_to`.
+    ///
+    /// The TTL is the number of ledgers between the current ledger and the final ledger the data can still be accessed.
+    pub fn extend_ttl(&self, threshold: u32, extend_to: u32) {
+        self.storage
+            .extend_ttl(&self.key, self.storage_type, threshold, extend_to)
+    }
+}
+
+/// Namespace stores values in [Persistent] or [Temporary] storage under keys
+/// prefixed with a symbol.
+///
+/// Each key `k` is stored as the tuple `(prefix, k)`, so values stored through
+/// namespaces with different prefixes never collide, even when they use the
+/// same keys. The stored key is identical to using the tuple directly, i.e.
+/// `ns.get(&k)` is the same as `storage.get(&(prefix, k))`.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn deposit(env: Env, user: Address, amount: i128) {
+///         let balances = env.storage().persistent().namespace(symbol_short!("bal"));
+///         let deposits = env.storage().persistent().namespace(symbol_short!("dep"));
+///         let balance: i128 = balances.get(&user).unwrap_or(0);
+///         balances.set(&user, &(balance + amount));
+///         let count: u32 = deposits.get(&user).unwrap_or(0);
+///         deposits.set(&user, &(count + 1));
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # #[cfg(feature = "testutils")]
+/// # fn main() {
+/// #     use soroban_sdk::testutils::Address as _;
+///     let env = Env::default();
+///     let contract_id = env.register(Contract, ());
+///     let client = ContractClient::new(&env, &contract_id);
+///     client.deposit(&Address::generate(&env), &100);
+/// }
+/// # #[cfg(not(feature = "testutils"))]
+/// # fn main() { }
+/// ```
+pub struct Namespace {
+    storage: Storage,
+    storage_type: StorageType,
+    prefix: Symbol,
+}
+
+impl Namespace {
+    fn new(storage: Storage, storage_type: StorageType, prefix: Symbol) -> Self {
+        Namespace {
+            storage,
+            storage_type,
+            prefix,
+        }
+    }
+
+    /// Returns the prefix of the namespace.
+    pub fn prefix(&self) -> &Symbol {
+        &self.prefix
+    }
+
+    /// Returns if there is a value stored for the given key in the namespace.
+    pub fn has<K>(&self, key: &K) -> bool
+    where
+        K: IntoVal<Env, Val>,
+    {
+        self.storage.has(&self.key(key), self.storage_type)
+    }
+
+    /// Returns the value stored for the given key in the namespace, or `None`
+    /// if there is no value.
+    pub fn get<K, V>(&self, key: &K) -> Option<V>
+    where
+        V::Error: Debug,
+        K: IntoVal<Env, Val>,
+        V: TryFromVal<Env, Val>,
+    {
+        self.storage.get(&self.key(key), self.storage_type)
+    }
+
+    /// Stores the value for the given key in the namespace.
+    pub fn set<K, V>(&self, key: &K, val: &V)
+    where
+        K: IntoVal<Env, Val>,
+        V: IntoVal<Env, Val>,
+    {
+        self.storage.set(&self.key(key), val, self.storage_type)
+    }
+
+    /// Removes the value stored for the given key in the namespace.
+    ///
+    /// No-op if there is no value.
+    pub fn remove<K>(&self, key: &K)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        self.storage.remove(&self.key(key), self.storage_type)
+    }
+
+    /// Extend the TTL of the data under the key in the namespace.
+    ///
+    /// Extends the TTL only if the TTL for the provided data is below `threshold` ledgers.
+    /// The TTL will then become `extend_to`.
+    ///
+    /// The TTL is the number of ledgers between the current ledger and the final ledger the data can still be accessed.
+    pub fn extend_ttl<K>(&self, key: &K, threshold: u32, extend_to: u32)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        self.storage
+            .extend_ttl(&self.key(key), self.storage_type, threshold, extend_to)
+    }
+
+    // Returns the storage key used for `key`, which is encoded the same as the
+    // tuple `(prefix, key)`.
+    fn key<K>(&self, key: &K) -> Val
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let env = &self.storage.env;
+        let key: Val = key.into_val(env);
+        let parts: Vec<Val> = Vec::from_array(env, [self.prefix.to_val(), key]);
+        parts.into_val(env)
+    }
+}
+
+/// Key that the contract helpers in this crate store their state under.
+///
+/// Every key encodes as a vec starting with the symbol `SorobanSdk`, which is
+/// reserved for the SDK, so that the keys do not collide with the keys of the
+/// contracts using the helpers.
+#[contracttype(crate_path = "crate", export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ReservedKey {
+    SorobanSdk(SdkKey),
+}
+
+/// The state stored by each of the contract helpers in this crate.
+#[contracttype(crate_path = "crate", export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum SdkKey {
+    /// [`ReentrancyGuard`](crate::reentrancy::ReentrancyGuard) flag.
+    Reentrant,
+    /// [`Pausable`](crate::pausable::Pausable) flag.
+    Paused,
+    /// [`Ownable`](crate::ownable::Ownable) owner.
+    Owner,
+    /// [`Ownable`](crate::ownable::Ownable) pending owner.
+    PendingOwner,
+    /// [`AccessControl`](crate::access_control::AccessControl) role members.
+    Roles,
+    /// [`AccessControl`](crate::access_control::AccessControl) role admins.
+    RoleAdmins,
+    /// [`Upgradeable`](crate::upgradeable::Upgradeable) version.
+    Version,
+    /// [`Upgradeable`](crate::upgradeable::Upgradeable) Wasm hash.
+    WasmHash,
+    /// [`SparseMerkle`](crate::sparse_merkle::SparseMerkle) root.
+    SparseMerkleRoot,
+    /// [`RateLimiter`](crate::rate_limiter::RateLimiter) last call of an
+    /// address.
+    RateLimit(Address),
+    /// [`Migration`](crate::migration::Migration) schema version.
+    SchemaVersion,
+}
+
+impl From<SdkKey> for ReservedKey {
+    fn from(key: SdkKey) -> Self {
+        ReservedKey::SorobanSdk(key)
+    }
+}
+
 #[cfg(any(test, feature = "testutils"))]
 #[cfg_attr(feature = "docs", doc(cfg(feature = "testutils")))]
 mod testutils {
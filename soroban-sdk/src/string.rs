@@ -256,6 +256,49 @@ impl String {
         }
     }
 
+    /// Create a String from the bytes, checking that they are valid UTF-8.
+    ///
+    /// Unlike [String::from_bytes], which accepts any bytes, this returns an
+    /// error if the bytes are not valid UTF-8.
+    ///
+    /// ### Errors
+    ///
+    /// Returns [ConversionError] if the bytes contain a sequence that is not
+    /// valid UTF-8, including overlong encodings and surrogates, or if they
+    /// end part way through a character.
+    pub fn try_from_bytes(env: &Env, b: &Bytes) -> Result<String, ConversionError> {
+        const CHUNK_SIZE: u32 = 256;
+        // Room for the chunk plus an incomplete character, at most 3 bytes,
+        // carried over from the previous chunk.
+        let mut buf = [0u8; CHUNK_SIZE as usize + 3];
+        let mut carry = 0;
+        let len = b.len();
+        let mut i = 0;
+        while i < len {
+            let n = core::cmp::min(CHUNK_SIZE, len - i);
+            let filled = carry + n as usize;
+            b.slice(i..i + n).copy_into_slice(&mut buf[carry..filled]);
+            carry = match core::str::from_utf8(&buf[..filled]) {
+                Ok(_) => 0,
+                // The chunk ends part way through a character.
+                Err(e) if e.error_len().is_none() => {
+                    let valid = e.valid_up_to();
+                    buf.copy_within(valid..filled, 0);
+                    filled - valid
+                }
+                Err(_) => return Err(ConversionError),
+            };
+            i += n;
+        }
+        if carry != 0 {
+            return Err(ConversionError);
+        }
+        Ok(env
+            .bytes_to_string(b.to_object())
+            .unwrap_infallible()
+            .into_val(env))
+    }
+
     #[inline(always)]
     pub fn from_str(env: &Env, s: &str) -> String {
         String {
@@ -293,6 +336,18 @@ impl String {
     pub fn to_bytes(&self) -> Bytes {
         self.into()
     }
+
+    /// Create a String by joining the parts in order.
+    ///
+    /// The parts are joined as bytes in the host, the contents are never
+    /// copied into guest memory.
+    pub fn concat(env: &Env, parts: &[&String]) -> String {
+        let mut bytes = Bytes::new(env);
+        for part in parts {
+            bytes.append(&part.to_bytes());
+        }
+        bytes.into()
+    }
 }
 
 #[cfg(test)]
@@ -388,6 +443,65 @@ mod test {
         assert_eq!(b, b2);
     }
 
+    #[test]
+    fn test_string_concat() {
+        let env = Env::default();
+        let a = String::from_str(&env, "abc");
+        let b = String::from_str(&env, "def");
+        let empty = String::from_str(&env, "");
+
+        let s = String::concat(&env, &[&a, &b]);
+        assert_eq!(s, String::from_str(&env, "abcdef"));
+        assert_eq!(s.len(), 6);
+        let mut slice = [0u8; 6];
+        s.copy_into_slice(&mut slice);
+        assert_eq!(&slice, b"abcdef");
+
+        assert_eq!(String::concat(&env, &[&empty, &a, &empty]), a);
+        let s = String::concat(&env, &[]);
+        assert!(s.is_empty());
+        assert_eq!(s, empty);
+    }
+
+    #[test]
+    fn test_string_try_from_bytes() {
+        let env = Env::default();
+
+        let input = "héllo ✓ 🚀";
+        let b = Bytes::from_slice(&env, input.as_bytes());
+        let s = String::try_from_bytes(&env, &b).unwrap();
+        assert_eq!(s, String::from_str(&env, input));
+        assert_eq!(s.to_bytes(), b);
+
+        let s = String::try_from_bytes(&env, &Bytes::new(&env)).unwrap();
+        assert!(s.is_empty());
+
+        // a multibyte character crossing the boundary of the chunks validated
+        let mut input = [b'a'; 258];
+        input[255..].copy_from_slice("✓".as_bytes());
+        let b = Bytes::from_slice(&env, &input);
+        let s = String::try_from_bytes(&env, &b).unwrap();
+        assert_eq!(s.to_bytes(), b);
+    }
+
+    #[test]
+    fn test_string_try_from_bytes_invalid_utf8() {
+        let env = Env::default();
+
+        let b = Bytes::from_slice(&env, b"a\xc3\x28d"); // \xc3 is invalid utf8
+        assert_eq!(String::try_from_bytes(&env, &b), Err(ConversionError));
+
+        // truncated multibyte character at the end
+        let b = Bytes::from_slice(&env, b"abc\xe2\x9c");
+        assert_eq!(String::try_from_bytes(&env, &b), Err(ConversionError));
+
+        // invalid byte after the first chunk
+        let mut input = [b'a'; 300];
+        input[280] = 0xff;
+        let b = Bytes::from_slice(&env, &input);
+        assert_eq!(String::try_from_bytes(&env, &b), Err(ConversionError));
+    }
+
     #[test]
     fn test_string_accepts_any_bytes_even_invalid_utf8() {
         let env = Env::default();
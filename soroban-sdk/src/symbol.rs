@@ -2,9 +2,11 @@ use core::{cmp::Ordering, convert::Infallible, fmt::Debug};
 
 use super::{
     env::internal::{Env as _, Symbol as SymbolVal, SymbolSmall},
-    ConversionError, Env, TryFromVal, TryIntoVal, Val,
+    Bytes, ConversionError, Env, TryFromVal, TryIntoVal, Val,
 };
 
+pub use super::env::internal::SymbolError;
+
 #[cfg(not(target_family = "wasm"))]
 use super::env::SymbolStr;
 
@@ -15,6 +17,9 @@ use crate::{
     unwrap::{UnwrapInfallible, UnwrapOptimized},
 };
 
+// Maximum number of characters in a Symbol.
+const MAX_LEN: usize = 32;
+
 /// Symbol is a short string with a limited character set.
 ///
 /// Valid characters are `a-zA-Z0-9_` and maximum length is 32 characters.
@@ -216,6 +221,50 @@ impl Symbol {
         }
     }
 
+    /// Creates a new Symbol from bytes containing valid characters.
+    ///
+    /// Valid characters are `a-zA-Z0-9_` and maximum length is 32 characters.
+    ///
+    /// Useful when the symbol is derived from data only known at runtime, such
+    /// as an event topic name provided by a caller.
+    ///
+    /// ### Errors
+    ///
+    /// Returns [`SymbolError::TooLong`] if the input is longer than 32 bytes,
+    /// and [`SymbolError::BadChar`] with the first invalid character found.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{symbol_short, Bytes, Env, Symbol, SymbolError};
+    ///
+    /// let env = Env::default();
+    ///
+    /// let b = Bytes::from_slice(&env, b"hello_1");
+    /// assert_eq!(Symbol::try_from_bytes(&env, &b), Ok(symbol_short!("hello_1")));
+    ///
+    /// let b = Bytes::from_slice(&env, b"hello-1");
+    /// assert_eq!(Symbol::try_from_bytes(&env, &b), Err(SymbolError::BadChar('-')));
+    /// ```
+    pub fn try_from_bytes(env: &Env, b: &Bytes) -> Result<Self, SymbolError> {
+        let len = b.len() as usize;
+        if len > MAX_LEN {
+            return Err(SymbolError::TooLong(len));
+        }
+        let mut buf = [0u8; MAX_LEN];
+        let buf = &mut buf[..len];
+        b.copy_into_slice(buf);
+        if let Some(c) = buf
+            .iter()
+            .find(|c| !matches!(c, b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_'))
+        {
+            return Err(SymbolError::BadChar(*c as char));
+        }
+        // All characters are ASCII, so the buffer is valid UTF-8.
+        let s = core::str::from_utf8(buf).unwrap_optimized();
+        Ok(Symbol::new(env, s))
+    }
+
     /// Creates a new Symbol given a short string with valid characters.
     ///
     /// Valid characters are `a-zA-Z0-9_` and maximum length is 9 characters.
@@ -1,45 +1,82 @@
 #![cfg(test)]
 
+mod access_control;
 mod address;
 mod auth;
 mod bytes_alloc_vec;
 mod bytes_buffer;
 mod cmp_across_env_in_tests;
+mod codec_cbor;
+mod codec_json;
 mod contract_add_i32;
 mod contract_assert;
 mod contract_custom_account_impl;
+mod contract_custom_account_multisig;
+mod contract_deploy_many;
 mod contract_docs;
 mod contract_duration;
+mod contract_error_result;
 mod contract_event;
+mod contract_extend_instance;
 mod contract_fn;
 mod contract_invoke;
 mod contract_invoke_arg_count;
+mod contract_invoke_checked;
 mod contract_meta;
 mod contract_overlapping_type_fn_names;
+mod contract_predict_address;
 mod contract_snapshot;
 mod contract_store;
 mod contract_timepoint;
+mod contract_try_invoke;
 mod contract_udt_enum;
 mod contract_udt_enum_error;
 mod contract_udt_enum_int;
 mod contract_udt_enum_option;
 mod contract_udt_option;
 mod contract_udt_struct;
+mod contract_udt_struct_default;
 mod contract_udt_struct_tuple;
 mod contractimport;
 mod contractimport_with_error;
 mod cost_estimate;
 mod crypto_bls12_381;
+mod crypto_bn254;
+mod crypto_constant_time_eq;
 mod crypto_ed25519;
+mod crypto_hkdf_sha256;
+mod crypto_hmac_sha256;
 mod crypto_keccak256;
+mod crypto_merkle;
 mod crypto_secp256k1;
 mod crypto_secp256r1;
 mod crypto_sha256;
+mod crypto_sha3_256;
 mod env;
+mod events_builder;
+mod interop_abi;
+mod interop_rlp;
+mod into_from_map;
+mod ledger_clock;
+mod ledger_timestamp;
+mod log_fields;
 mod max_ttl;
+mod migration;
 mod muxed_address;
+mod ownable;
+mod pausable;
 mod prng;
 mod proptest_scval_cmp;
 mod proptest_val_cmp;
+mod rate_limiter;
+mod reentrancy;
+mod sparse_merkle;
+mod storage_entry;
+mod storage_many;
+mod storage_namespace;
+mod storage_snapshot;
 mod storage_testutils;
+mod symbol;
 mod token_client;
+mod tuple;
+mod upgradeable;
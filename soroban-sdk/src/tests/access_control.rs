@@ -0,0 +1,138 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    access_control::{AccessControl, DEFAULT_ADMIN_ROLE},
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn __constructor(env: Env, admin: Address) {
+        AccessControl::set_initial_admin(&env, &admin);
+        // Minters are managed by members of the minter admin role.
+        AccessControl::set_role_admin(&env, &symbol_short!("minter"), &symbol_short!("mintadmin"));
+    }
+
+    pub fn grant_role(env: Env, caller: Address, role: Symbol, account: Address) {
+        AccessControl::grant_role(&env, &caller, &role, &account);
+    }
+
+    pub fn revoke_role(env: Env, caller: Address, role: Symbol, account: Address) {
+        AccessControl::revoke_role(&env, &caller, &role, &account);
+    }
+
+    pub fn has_role(env: Env, role: Symbol, account: Address) -> bool {
+        AccessControl::has_role(&env, &role, &account)
+    }
+
+    pub fn members(env: Env, role: Symbol) -> soroban_sdk::Vec<Address> {
+        AccessControl::role_members(&env, &role)
+    }
+
+    pub fn mint(env: Env, minter: Address) {
+        AccessControl::require_role(&env, &symbol_short!("minter"), &minter);
+    }
+}
+
+#[test]
+fn test_grant_and_revoke() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let mint_admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let client = ContractClient::new(&e, &e.register(Contract, (&admin,)));
+
+    let minter_role = symbol_short!("minter");
+    let mint_admin_role = symbol_short!("mintadmin");
+
+    assert!(client.has_role(&DEFAULT_ADMIN_ROLE, &admin));
+    assert!(!client.has_role(&minter_role, &minter));
+    assert!(client.try_mint(&minter).is_err());
+
+    // The default admin manages the mint admin role, which manages minters.
+    client.grant_role(&admin, &mint_admin_role, &mint_admin);
+    client.grant_role(&mint_admin, &minter_role, &minter);
+    assert!(client.has_role(&minter_role, &minter));
+    assert_eq!(client.members(&minter_role), vec![&e, minter.clone()]);
+    client.mint(&minter);
+
+    // Granting again has no effect.
+    client.grant_role(&mint_admin, &minter_role, &minter);
+    assert_eq!(client.members(&minter_role), vec![&e, minter.clone()]);
+
+    client.revoke_role(&mint_admin, &minter_role, &minter);
+    assert!(!client.has_role(&minter_role, &minter));
+    assert_eq!(client.members(&minter_role), vec![&e]);
+    assert!(client.try_mint(&minter).is_err());
+}
+
+#[test]
+#[should_panic(expected = "account is missing role")]
+fn test_unauthorized_grant() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let client = ContractClient::new(&e, &e.register(Contract, (&admin,)));
+
+    // The default admin is not a member of the minter role's admin role.
+    client.grant_role(&admin, &symbol_short!("minter"), &minter);
+}
+
+#[test]
+#[should_panic(expected = "account is missing role")]
+fn test_member_cannot_grant_own_role() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let pauser = Address::generate(&e);
+    let other = Address::generate(&e);
+    let client = ContractClient::new(&e, &e.register(Contract, (&admin,)));
+
+    let pauser_role = symbol_short!("pauser");
+    client.grant_role(&admin, &pauser_role, &pauser);
+    client.grant_role(&pauser, &pauser_role, &other);
+}
+
+#[test]
+fn test_grant_requires_caller_auth() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let pauser = Address::generate(&e);
+    let contract_id = e.register(Contract, (&admin,));
+    let client = ContractClient::new(&e, &contract_id);
+
+    let pauser_role = symbol_short!("pauser");
+
+    // The pauser authorizes, but names the admin as the caller.
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &pauser,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "grant_role",
+                args: (&admin, &pauser_role, &pauser).into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .try_grant_role(&admin, &pauser_role, &pauser);
+    assert!(res.is_err());
+    assert!(!client.has_role(&pauser_role, &pauser));
+}
+
+#[test]
+#[should_panic(expected = "admin already set")]
+fn test_set_initial_admin_twice_panics() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let contract_id = e.register(Contract, (&admin,));
+
+    e.as_contract(&contract_id, || {
+        AccessControl::set_initial_admin(&e, &admin)
+    });
+}
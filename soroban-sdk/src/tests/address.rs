@@ -2,7 +2,7 @@ use crate::{self as soroban_sdk};
 use sha2::{Digest, Sha256};
 use soroban_sdk::{
     address::Executable, contract, env::EnvTestConfig, testutils::Address as _, Address, Bytes,
-    BytesN, Env, String, TryIntoVal,
+    BytesN, ConversionError, Env, String, TryFromVal, TryIntoVal,
 };
 
 #[contract]
@@ -130,3 +130,118 @@ fn test_get_existing_contract_address_executable_asset() {
     assert_eq!(sac_issuer.executable(), Some(Executable::Account));
     assert!(sac_issuer.exists());
 }
+
+#[test]
+fn test_address_is_contract_or_account() {
+    let env = Env::default();
+
+    let account = Address::from_str(
+        &env,
+        "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ",
+    );
+    assert!(account.is_account());
+    assert!(!account.is_contract());
+
+    let contract = Address::from_str(
+        &env,
+        "CA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJUWDA",
+    );
+    assert!(contract.is_contract());
+    assert!(!contract.is_account());
+
+    // Generated addresses are contract addresses.
+    let generated = Address::generate(&env);
+    assert!(generated.is_contract());
+    assert!(!generated.is_account());
+
+    let deployed = env.register(TestContract, ());
+    assert!(deployed.is_contract());
+    assert!(!deployed.is_account());
+
+    let sac = env.register_stellar_asset_contract_v2(generated);
+    assert!(sac.address().is_contract());
+    assert!(sac.issuer().address().is_account());
+    assert!(!sac.issuer().address().is_contract());
+}
+
+#[test]
+fn test_address_other_types_are_neither_contract_nor_account() {
+    use crate::xdr::{ClaimableBalanceId, Hash, PoolId, ScAddress};
+    let env = Env::default();
+
+    for sc_address in [
+        ScAddress::ClaimableBalance(ClaimableBalanceId::ClaimableBalanceIdTypeV0(Hash([1; 32]))),
+        ScAddress::LiquidityPool(PoolId(Hash([1; 32]))),
+    ] {
+        let address = Address::try_from_val(&env, &sc_address).unwrap();
+        assert!(!address.is_contract());
+        assert!(!address.is_account());
+    }
+}
+
+#[test]
+fn test_try_from_string_bytes_round_trip() {
+    let env = Env::default();
+
+    for strkey in [
+        "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ",
+        "CA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJUWDA",
+    ] {
+        let bytes = Bytes::from_slice(&env, strkey.as_bytes());
+        let address = Address::try_from_string_bytes(&bytes).unwrap();
+        assert_eq!(address, Address::from_str(&env, strkey));
+        assert_eq!(address.to_string().to_string(), strkey);
+        assert_eq!(address.to_string().to_bytes(), bytes);
+
+        let string = String::from_str(&env, strkey);
+        assert_eq!(Address::try_from_string(&string), Ok(address));
+    }
+
+    // Generated addresses round trip through their strkey.
+    let generated = Address::generate(&env);
+    assert_eq!(
+        Address::try_from_string(&generated.to_string()),
+        Ok(generated.clone())
+    );
+    assert_eq!(
+        Address::try_from_string_bytes(&generated.to_string().to_bytes()),
+        Ok(generated)
+    );
+}
+
+#[test]
+fn test_try_from_string_bytes_rejects_malformed() {
+    let env = Env::default();
+
+    for strkey in [
+        // Bad checksums.
+        "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGA",
+        "CA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJUWDB",
+        // A changed key character.
+        "GB7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ",
+        // Lowercase is not a valid encoding.
+        "ga7qynf7sowq3glr2bgmzehxavirza4kvwltjjfc7mgxua74p7ujvsgz",
+        // Characters outside the base32 alphabet.
+        "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSG0",
+        // Unsupported strkey types, a secret seed and a muxed account.
+        "SBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHOKR",
+        "MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVAAAAAAAAAAAAAJLK",
+        // Wrong lengths.
+        "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSG",
+        "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZA",
+        "",
+    ] {
+        let bytes = Bytes::from_slice(&env, strkey.as_bytes());
+        assert_eq!(
+            Address::try_from_string_bytes(&bytes),
+            Err(ConversionError),
+            "{strkey}"
+        );
+        let string = String::from_str(&env, strkey);
+        assert_eq!(
+            Address::try_from_string(&string),
+            Err(ConversionError),
+            "{strkey}"
+        );
+    }
+}
@@ -5,3 +5,5 @@ mod auth_20_deep_one_address;
 mod auth_30_deep_one_address_repeat;
 mod auth_35_deep_one_address_repeat_grouped;
 mod auth_40_multi_one_address;
+mod auth_50_invoker_contract_tree;
+mod auth_60_invoker_contract_direct;
@@ -0,0 +1,74 @@
+use crate as soroban_sdk;
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractimpl, symbol_short, vec, Address, Env, IntoVal,
+};
+
+#[contract]
+pub struct ContractA;
+
+#[contractimpl]
+impl ContractA {
+    // Calls B, authorizing only the nested call from B to C with
+    // `authorized_amount`.
+    pub fn fna(e: Env, b: Address, c: Address, authorized_amount: i128, amount: i128) -> i128 {
+        let a = e.current_contract_address();
+        e.authorize_as_current_contract(vec![
+            &e,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: c.clone(),
+                    fn_name: symbol_short!("fnc"),
+                    args: (a.clone(), authorized_amount).into_val(&e),
+                },
+                sub_invocations: vec![&e],
+            }),
+        ]);
+        ContractBClient::new(&e, &b).fnb(&a, &c, &amount)
+    }
+}
+
+#[contract]
+pub struct ContractB;
+
+#[contractimpl]
+impl ContractB {
+    pub fn fnb(e: Env, a: Address, c: Address, amount: i128) -> i128 {
+        ContractCClient::new(&e, &c).fnc(&a, &amount)
+    }
+}
+
+#[contract]
+pub struct ContractC;
+
+#[contractimpl]
+impl ContractC {
+    pub fn fnc(a: Address, amount: i128) -> i128 {
+        a.require_auth();
+        amount
+    }
+}
+
+#[test]
+fn test_authorized_sub_invocation() {
+    let e = Env::default();
+    let contract_a_id = e.register(ContractA, ());
+    let contract_b_id = e.register(ContractB, ());
+    let contract_c_id = e.register(ContractC, ());
+    let client = ContractAClient::new(&e, &contract_a_id);
+
+    assert_eq!(client.fna(&contract_b_id, &contract_c_id, &10, &10), 10);
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_sub_invocation_with_other_args_not_authorized() {
+    let e = Env::default();
+    let contract_a_id = e.register(ContractA, ());
+    let contract_b_id = e.register(ContractB, ());
+    let contract_c_id = e.register(ContractC, ());
+    let client = ContractAClient::new(&e, &contract_a_id);
+
+    client.fna(&contract_b_id, &contract_c_id, &10, &11);
+}
@@ -0,0 +1,53 @@
+//! Demonstrates how a contract learns which contract invoked it: the invoker
+//! passes its own address, and the callee requires its authorization, which
+//! the host grants only to the contract that directly made the call.
+
+use crate as soroban_sdk;
+
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contract]
+pub struct Router;
+
+#[contractimpl]
+impl Router {
+    // Calls the callee, claiming to be `invoker`.
+    pub fn route(e: Env, callee: Address, invoker: Option<Address>) -> Address {
+        let invoker = invoker.unwrap_or_else(|| e.current_contract_address());
+        CalleeClient::new(&e, &callee).callback(&invoker)
+    }
+}
+
+#[contract]
+pub struct Callee;
+
+#[contractimpl]
+impl Callee {
+    pub fn callback(invoker: Address) -> Address {
+        invoker.require_auth();
+        invoker
+    }
+}
+
+#[test]
+fn test_direct_invoker_is_authorized() {
+    let e = Env::default();
+    let router_id = e.register(Router, ());
+    let callee_id = e.register(Callee, ());
+    let client = RouterClient::new(&e, &router_id);
+
+    // No authorizations are mocked, the router is the direct invoker.
+    assert_eq!(client.route(&callee_id, &None), router_id);
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_other_contract_is_not_authorized() {
+    let e = Env::default();
+    let router_id = e.register(Router, ());
+    let other_id = e.register(Router, ());
+    let callee_id = e.register(Callee, ());
+    let client = RouterClient::new(&e, &router_id);
+
+    client.route(&callee_id, &Some(other_id));
+}
@@ -0,0 +1,256 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    bytes, codec::cbor, map, symbol_short, vec, Bytes, ConversionError, Env, IntoVal, Map, String,
+    TryFromVal, Val, Vec,
+};
+
+fn bytes(env: &Env, hex: &str) -> Bytes {
+    Bytes::from_slice(env, &hex::decode(hex).unwrap())
+}
+
+fn encode(env: &Env, value: impl IntoVal<Env, Val>) -> std::string::String {
+    let encoded = cbor::encode(env, value).unwrap();
+    let mut buf = std::vec![0u8; encoded.len() as usize];
+    encoded.copy_into_slice(&mut buf);
+    hex::encode(buf)
+}
+
+#[test]
+fn test_encode_examples() {
+    let env = Env::default();
+    let s = |s: &str| String::from_str(&env, s);
+
+    // Examples from RFC 8949, Appendix A.
+    assert_eq!(encode(&env, 0u32), "00");
+    assert_eq!(encode(&env, 23u32), "17");
+    assert_eq!(encode(&env, 24u32), "1818");
+    assert_eq!(encode(&env, 100i32), "1864");
+    assert_eq!(encode(&env, 1000u64), "1903e8");
+    assert_eq!(encode(&env, 1_000_000i64), "1a000f4240");
+    assert_eq!(encode(&env, 1_000_000_000_000u128), "1b000000e8d4a51000");
+    assert_eq!(encode(&env, u64::MAX), "1bffffffffffffffff");
+    assert_eq!(encode(&env, -(1i128 << 64)), "3bffffffffffffffff");
+    assert_eq!(encode(&env, -1i32), "20");
+    assert_eq!(encode(&env, -10i64), "29");
+    assert_eq!(encode(&env, -100i128), "3863");
+    assert_eq!(encode(&env, -1000i32), "3903e7");
+    assert_eq!(encode(&env, false), "f4");
+    assert_eq!(encode(&env, true), "f5");
+    assert_eq!(encode(&env, ()), "f6");
+    assert_eq!(encode(&env, Bytes::new(&env)), "40");
+    assert_eq!(encode(&env, bytes!(&env, 0x01020304)), "4401020304");
+    assert_eq!(encode(&env, s("")), "60");
+    assert_eq!(encode(&env, s("IETF")), "6449455446");
+    assert_eq!(encode(&env, s("\u{00fc}")), "62c3bc");
+    assert_eq!(encode(&env, Vec::<u32>::new(&env)), "80");
+    assert_eq!(encode(&env, vec![&env, 1u32, 2, 3]), "83010203");
+    assert_eq!(
+        encode(
+            &env,
+            vec![
+                &env,
+                1u32.into_val(&env),
+                vec![&env, 2u32, 3].to_val(),
+                vec![&env, 4u32, 5].to_val()
+            ]
+        ),
+        "8301820203820405"
+    );
+    assert_eq!(encode(&env, Map::<u32, u32>::new(&env)), "a0");
+    assert_eq!(
+        encode(
+            &env,
+            map![
+                &env,
+                (s("a"), 1u32.into_val(&env)),
+                (s("b"), vec![&env, 2u32, 3].to_val())
+            ]
+        ),
+        "a26161016162820203"
+    );
+}
+
+#[test]
+fn test_encode_sorts_map_keys_by_encoding() {
+    let env = Env::default();
+    // The map orders its keys by type then value, but the encoding orders
+    // them by their encoded bytes: 0a, 1864, 6161.
+    let map: Map<Val, u32> = map![
+        &env,
+        (String::from_str(&env, "a").to_val(), 1),
+        (100u32.into_val(&env), 2),
+        (10u64.into_val(&env), 3)
+    ];
+    assert_eq!(encode(&env, &map), "a30a03186402616101");
+}
+
+#[test]
+fn test_encode_unsupported() {
+    let env = Env::default();
+    assert_eq!(cbor::encode(&env, symbol_short!("a")), Err(ConversionError));
+    assert_eq!(cbor::encode(&env, u128::MAX), Err(ConversionError));
+    assert_eq!(cbor::encode(&env, i128::MIN), Err(ConversionError));
+    // Not valid UTF-8.
+    assert_eq!(
+        cbor::encode(&env, String::from_bytes(&env, &[0xff])),
+        Err(ConversionError)
+    );
+    // Keys with the same encoding.
+    let map: Map<Val, u32> = map![&env, (1u32.into_val(&env), 1), (1u64.into_val(&env), 2)];
+    assert_eq!(cbor::encode(&env, map), Err(ConversionError));
+    // Unsupported types nested in supported types.
+    assert_eq!(
+        cbor::encode(&env, vec![&env, symbol_short!("a")]),
+        Err(ConversionError)
+    );
+}
+
+#[test]
+fn test_round_trip_nested() {
+    let env = Env::default();
+    let s = |s: &str| String::from_str(&env, s);
+
+    let inner: Map<String, Val> = map![
+        &env,
+        (s("price"), 1_234_500u64.into_val(&env)),
+        (s("delta"), (-42i64).into_val(&env)),
+        (s("raw"), bytes!(&env, 0xdeadbeef).to_val())
+    ];
+    let value: Map<String, Val> = map![
+        &env,
+        (s("asset"), s("XLM").to_val()),
+        (s("quote"), inner.to_val()),
+        (
+            s("history"),
+            vec![&env, inner.to_val(), ().into_val(&env), true.into_val(&env)].to_val()
+        ),
+        (s("empty"), Map::<u32, u32>::new(&env).to_val())
+    ];
+
+    let encoded = cbor::encode(&env, &value).unwrap();
+    let decoded = cbor::decode(&env, &encoded).unwrap();
+    let decoded = Map::<String, Val>::try_from_val(&env, &decoded).unwrap();
+    assert_eq!(decoded.len(), 4);
+    assert_eq!(
+        String::try_from_val(&env, &decoded.get(s("asset")).unwrap()),
+        Ok(s("XLM"))
+    );
+    let quote = Map::<String, Val>::try_from_val(&env, &decoded.get(s("quote")).unwrap()).unwrap();
+    assert_eq!(
+        u64::try_from_val(&env, &quote.get(s("price")).unwrap()),
+        Ok(1_234_500)
+    );
+    assert_eq!(
+        i64::try_from_val(&env, &quote.get(s("delta")).unwrap()),
+        Ok(-42)
+    );
+    assert_eq!(
+        Bytes::try_from_val(&env, &quote.get(s("raw")).unwrap()),
+        Ok(bytes!(&env, 0xdeadbeef))
+    );
+    let history = Vec::<Val>::try_from_val(&env, &decoded.get(s("history")).unwrap()).unwrap();
+    assert_eq!(history.len(), 3);
+    assert!(history.get(1).unwrap().is_void());
+    assert_eq!(bool::try_from_val(&env, &history.get(2).unwrap()), Ok(true));
+
+    // Encoding the decoded value gives the same bytes.
+    assert_eq!(cbor::encode(&env, &decoded), Ok(encoded.clone()));
+    let first = Map::<String, Val>::try_from_val(&env, &history.get(0).unwrap()).unwrap();
+    assert_eq!(cbor::encode(&env, &first), cbor::encode(&env, &quote));
+}
+
+#[test]
+fn test_decode_integers() {
+    let env = Env::default();
+    let decode = |hex: &str| cbor::decode(&env, &bytes(&env, hex)).unwrap();
+
+    assert_eq!(u64::try_from_val(&env, &decode("00")), Ok(0));
+    assert_eq!(
+        u64::try_from_val(&env, &decode("1bffffffffffffffff")),
+        Ok(u64::MAX)
+    );
+    assert_eq!(i64::try_from_val(&env, &decode("20")), Ok(-1));
+    assert_eq!(
+        i64::try_from_val(&env, &decode("3b7fffffffffffffff")),
+        Ok(i64::MIN)
+    );
+    // Below the range of an i64.
+    assert_eq!(
+        cbor::decode(&env, &bytes(&env, "3b8000000000000000")),
+        Err(ConversionError)
+    );
+}
+
+#[test]
+fn test_negative_below_i64_min_encodes_but_does_not_decode() {
+    let env = Env::default();
+    let encoded = cbor::encode(&env, i128::from(i64::MIN) - 1).unwrap();
+    assert_eq!(encoded, bytes(&env, "3b8000000000000000"));
+    assert_eq!(cbor::decode(&env, &encoded), Err(ConversionError));
+}
+
+#[test]
+fn test_decode_max_depth() {
+    let env = Env::default();
+    let max = cbor::MAX_DEPTH as usize;
+
+    // Arrays nested to the maximum depth decode.
+    let nested = std::format!("{}80", "81".repeat(max - 1));
+    let mut value =
+        Vec::<Val>::try_from_val(&env, &cbor::decode(&env, &bytes(&env, &nested)).unwrap())
+            .unwrap();
+    for _ in 1..max {
+        value = Vec::<Val>::try_from_val(&env, &value.get(0).unwrap()).unwrap();
+    }
+    assert!(value.is_empty());
+
+    // Arrays and maps nested deeper do not.
+    for nested in [
+        std::format!("{}80", "81".repeat(max)),
+        std::format!("{}a0", "a100".repeat(max)),
+    ] {
+        assert_eq!(
+            cbor::decode(&env, &bytes(&env, &nested)),
+            Err(ConversionError)
+        );
+    }
+}
+
+#[test]
+fn test_decode_rejects_non_deterministic() {
+    let env = Env::default();
+    for hex in [
+        // Not the fewest bytes.
+        "1817",
+        "190017",
+        "1a0000ffff",
+        "1b00000000ffffffff",
+        "5801ff",
+        // Indefinite lengths.
+        "5f4101ff",
+        "9f01ff",
+        "bf616101ff",
+        // Map keys out of order or repeated.
+        "a2616201616102",
+        "a2616101616102",
+        "a30a01616102186403",
+        // Trailing and missing bytes.
+        "0000",
+        "4401",
+        "82",
+        "",
+        // Unsupported types: floats, tags, and other simple values.
+        "f93c00",
+        "c11a514b67b0",
+        "f0",
+        "f818",
+        // Text that is not valid UTF-8.
+        "61ff",
+    ] {
+        assert_eq!(
+            cbor::decode(&env, &bytes(&env, hex)),
+            Err(ConversionError),
+            "{hex}"
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    bytes, codec::json, map, symbol_short, testutils::Address as _, vec, Address, Bytes, Duration,
+    Env, IntoVal, Map, String, Symbol, Timepoint, Val, Vec, I256, U256,
+};
+
+fn to_json(env: &Env, value: impl IntoVal<Env, Val>) -> std::string::String {
+    json::to_json(env, &value.into_val(env)).to_string()
+}
+
+#[test]
+fn test_scalars() {
+    let env = Env::default();
+    assert_eq!(to_json(&env, false), "false");
+    assert_eq!(to_json(&env, true), "true");
+    assert_eq!(to_json(&env, ()), "null");
+    assert_eq!(to_json(&env, 0u32), "0");
+    assert_eq!(to_json(&env, u32::MAX), "4294967295");
+    assert_eq!(to_json(&env, i32::MIN), "-2147483648");
+    assert_eq!(to_json(&env, u64::MAX), "18446744073709551615");
+    assert_eq!(to_json(&env, i64::MIN), "-9223372036854775808");
+    assert_eq!(
+        to_json(&env, u128::MAX),
+        "340282366920938463463374607431768211455"
+    );
+    assert_eq!(
+        to_json(&env, i128::MIN),
+        "-170141183460469231731687303715884105728"
+    );
+    assert_eq!(
+        to_json(&env, Timepoint::from_unix(&env, 1_700_000_000)),
+        "1700000000"
+    );
+    assert_eq!(to_json(&env, Duration::from_seconds(&env, 60)), "60");
+    assert_eq!(to_json(&env, Bytes::new(&env)), r#""""#);
+    assert_eq!(to_json(&env, bytes!(&env, 0x666f6f6261)), r#""Zm9vYmE=""#);
+    assert_eq!(to_json(&env, symbol_short!("transfer")), r#""transfer""#);
+    assert_eq!(
+        to_json(&env, Symbol::new(&env, "a_symbol_longer_than_nine")),
+        r#""a_symbol_longer_than_nine""#
+    );
+
+    let address = Address::generate(&env);
+    assert_eq!(
+        to_json(&env, address.clone()),
+        std::format!(r#""{}""#, address.to_string())
+    );
+}
+
+#[test]
+fn test_large_integers_are_strings() {
+    let env = Env::default();
+    assert_eq!(to_json(&env, U256::from_u32(&env, 0)), r#""0""#);
+    assert_eq!(
+        to_json(&env, U256::from_parts(&env, 0, 0, 1, 0)),
+        r#""18446744073709551616""#
+    );
+    assert_eq!(
+        to_json(
+            &env,
+            U256::from_parts(&env, u64::MAX, u64::MAX, u64::MAX, u64::MAX)
+        ),
+        r#""115792089237316195423570985008687907853269984665640564039457584007913129639935""#
+    );
+    assert_eq!(to_json(&env, I256::from_i32(&env, -1)), r#""-1""#);
+    assert_eq!(
+        to_json(&env, I256::from_i128(&env, i128::MAX)),
+        std::format!(r#""{}""#, i128::MAX)
+    );
+    assert_eq!(
+        to_json(&env, I256::from_parts(&env, i64::MIN, 0, 0, 0)),
+        r#""-57896044618658097711785492504343953926634992332820282019728792003956564819968""#
+    );
+    assert_eq!(
+        to_json(
+            &env,
+            I256::from_parts(&env, i64::MAX, u64::MAX, u64::MAX, u64::MAX)
+        ),
+        r#""57896044618658097711785492504343953926634992332820282019728792003956564819967""#
+    );
+}
+
+#[test]
+fn test_strings_are_escaped() {
+    let env = Env::default();
+    let s = |s: &str| String::from_str(&env, s);
+    assert_eq!(to_json(&env, s("")), r#""""#);
+    assert_eq!(to_json(&env, s("plain")), r#""plain""#);
+    assert_eq!(
+        to_json(&env, s("\"quoted\" \\ back")),
+        r#""\"quoted\" \\ back""#
+    );
+    assert_eq!(
+        to_json(&env, s("\n\r\t\u{8}\u{c}\u{0}\u{1f}")),
+        r#""\n\r\t\b\f\u0000\u001f""#
+    );
+    // Characters outside of ASCII are not escaped.
+    assert_eq!(
+        to_json(&env, s("caf\u{e9} \u{1f980}")),
+        "\"caf\u{e9} \u{1f980}\""
+    );
+
+    // Strings longer than a chunk are escaped in full.
+    let long = "\"ab\\".repeat(50);
+    assert_eq!(
+        to_json(&env, s(&long)),
+        std::format!(r#""{}""#, r#"\"ab\\"#.repeat(50))
+    );
+}
+
+#[test]
+#[should_panic(expected = "string is not valid utf-8")]
+fn test_invalid_utf8_string_panics() {
+    let env = Env::default();
+    to_json(&env, String::from_bytes(&env, &[0x61, 0xff]));
+}
+
+#[test]
+fn test_nested() {
+    let env = Env::default();
+    assert_eq!(to_json(&env, Vec::<u32>::new(&env)), "[]");
+    assert_eq!(to_json(&env, Map::<u32, u32>::new(&env)), "{}");
+    assert_eq!(
+        to_json(
+            &env,
+            vec![
+                &env,
+                1u32.into_val(&env),
+                vec![&env, -2i64, 3].to_val(),
+                Vec::<u32>::new(&env).to_val(),
+                map![&env, (symbol_short!("k"), vec![&env, true])].to_val(),
+                ().into_val(&env),
+            ]
+        ),
+        r#"[1,[-2,3],[],{"k":[true]},null]"#
+    );
+
+    // A value as a contract type struct would be stored, with vectors and
+    // maps nested in each other.
+    let order: Map<Symbol, Val> = map![
+        &env,
+        (symbol_short!("id"), 7u64.into_val(&env)),
+        (
+            symbol_short!("legs"),
+            vec![
+                &env,
+                map![
+                    &env,
+                    (symbol_short!("amount"), 1_000i128.into_val(&env)),
+                    (symbol_short!("memo"), bytes!(&env, 0xff).to_val()),
+                ],
+                map![
+                    &env,
+                    (symbol_short!("amount"), (-5i128).into_val(&env)),
+                    (symbol_short!("memo"), Bytes::new(&env).to_val()),
+                ],
+            ]
+            .to_val()
+        ),
+        (
+            symbol_short!("total"),
+            U256::from_u128(&env, 995).into_val(&env)
+        ),
+    ];
+    assert_eq!(
+        to_json(&env, order),
+        r#"{"id":7,"legs":[{"amount":1000,"memo":"/w=="},{"amount":-5,"memo":""}],"total":"995"}"#
+    );
+}
+
+#[test]
+fn test_map_keys_are_sorted_strings() {
+    let env = Env::default();
+    let s = |s: &str| String::from_str(&env, s);
+
+    // Keys are sorted by their bytes, rather than in the order of the map.
+    let map = map![
+        &env,
+        (s("b"), 1u32),
+        (s("a"), 2u32),
+        (s("B"), 3u32),
+        (s("ab"), 4u32)
+    ];
+    assert_eq!(to_json(&env, map), r#"{"B":3,"a":2,"ab":4,"b":1}"#);
+
+    // The order of the members does not depend on the order of insertion.
+    let mut first = Map::<Val, Val>::new(&env);
+    first.set(symbol_short!("z").to_val(), 1u32.into_val(&env));
+    first.set(s("y").to_val(), 2u32.into_val(&env));
+    let mut second = Map::<Val, Val>::new(&env);
+    second.set(s("y").to_val(), 2u32.into_val(&env));
+    second.set(symbol_short!("z").to_val(), 1u32.into_val(&env));
+    assert_eq!(to_json(&env, first.clone()), r#"{"y":2,"z":1}"#);
+    assert_eq!(to_json(&env, first), to_json(&env, second));
+
+    // Keys of other types are strings of their JSON text. Integers are sorted
+    // as strings, not as numbers.
+    let map = map![&env, (2u32, true), (10u32, false), (1u32, true)];
+    assert_eq!(to_json(&env, map), r#"{"1":true,"10":false,"2":true}"#);
+    let map = map![&env, (vec![&env, s("a")], 1u32)];
+    assert_eq!(to_json(&env, map), r#"{"[\"a\"]":1}"#);
+}
+
+#[test]
+#[should_panic(expected = "map has two keys written as the same string")]
+fn test_map_duplicate_keys_panics() {
+    let env = Env::default();
+    let mut map = Map::<Val, Val>::new(&env);
+    map.set(1u32.into_val(&env), 1u32.into_val(&env));
+    map.set(1u64.into_val(&env), 2u32.into_val(&env));
+    to_json(&env, map);
+}
+
+#[test]
+#[should_panic(expected = "value of an unsupported type")]
+fn test_unsupported_type_panics() {
+    let env = Env::default();
+    to_json(&env, soroban_sdk::Error::from_contract_error(1));
+}
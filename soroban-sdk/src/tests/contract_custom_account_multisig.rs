@@ -0,0 +1,233 @@
+use crate::{self as soroban_sdk};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_env_host::xdr::{ScErrorCode, ScErrorType};
+use soroban_sdk::{
+    auth::{Context, CustomAccountInterface},
+    contract, contracterror, contractimpl, contracttype,
+    crypto::Hash,
+    symbol_short,
+    testutils::{CustomAccount, MockAuthInvoke},
+    vec, Address, BytesN, ConversionError, Env, Error, IntoVal, InvokeError, Symbol, TryFromVal,
+    Val, Vec,
+};
+
+const SIGNERS: Symbol = symbol_short!("signers");
+const THRESHOLD: Symbol = symbol_short!("threshold");
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Signature {
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
+
+#[contracterror]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AccountError {
+    NotEnoughSignatures = 1,
+    UnknownSigner = 2,
+    DuplicateSigner = 3,
+}
+
+#[contract]
+pub struct MultisigAccount;
+
+#[contractimpl]
+impl MultisigAccount {
+    pub fn __constructor(env: Env, signers: Vec<BytesN<32>>, threshold: u32) {
+        env.storage().instance().set(&SIGNERS, &signers);
+        env.storage().instance().set(&THRESHOLD, &threshold);
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for MultisigAccount {
+    type Signature = Vec<Signature>;
+    type Error = AccountError;
+
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signatures: Vec<Signature>,
+        _auth_contexts: Vec<Context>,
+    ) -> Result<(), AccountError> {
+        let signers: Vec<BytesN<32>> = env.storage().instance().get(&SIGNERS).unwrap();
+        let threshold: u32 = env.storage().instance().get(&THRESHOLD).unwrap();
+        if signatures.len() < threshold {
+            return Err(AccountError::NotEnoughSignatures);
+        }
+        let mut seen = Vec::<BytesN<32>>::new(&env);
+        for s in signatures.iter() {
+            if !signers.contains(&s.public_key) {
+                return Err(AccountError::UnknownSigner);
+            }
+            if seen.contains(&s.public_key) {
+                return Err(AccountError::DuplicateSigner);
+            }
+            env.crypto().ed25519_verify(
+                &s.public_key,
+                &signature_payload.to_bytes().into(),
+                &s.signature,
+            );
+            seen.push_back(s.public_key);
+        }
+        Ok(())
+    }
+}
+
+#[contract]
+pub struct Wallet;
+
+#[contractimpl]
+impl Wallet {
+    pub fn withdraw(_env: Env, from: Address, amount: i128) -> i128 {
+        from.require_auth();
+        amount
+    }
+}
+
+struct Setup {
+    env: Env,
+    wallet: Address,
+    account: Address,
+    keys: [SigningKey; 3],
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    let keys = [1u8, 2, 3].map(|i| SigningKey::from_bytes(&[i; 32]));
+    let signers = Vec::from_iter(
+        &env,
+        keys.iter()
+            .map(|k| BytesN::from_array(&env, &k.verifying_key().to_bytes())),
+    );
+    let account = env.register(MultisigAccount, (signers, 2u32));
+    let wallet = env.register(Wallet, ());
+    Setup {
+        env,
+        wallet,
+        account,
+        keys,
+    }
+}
+
+// Returns an account for `setup.account` signing with the given keys.
+fn multisig<'a>(
+    setup: &'a Setup,
+    keys: &'a [&'a SigningKey],
+) -> CustomAccount<impl Fn(&BytesN<32>) -> Val + 'a> {
+    let env = &setup.env;
+    CustomAccount::new(&setup.account, move |payload: &BytesN<32>| {
+        let mut signatures = Vec::<Signature>::new(env);
+        for key in keys {
+            signatures.push_back(Signature {
+                public_key: BytesN::from_array(env, &key.verifying_key().to_bytes()),
+                signature: BytesN::from_array(env, &key.sign(&payload.to_array()).to_bytes()),
+            });
+        }
+        signatures.into_val(env)
+    })
+}
+
+fn try_withdraw(
+    setup: &Setup,
+    account: &CustomAccount<impl Fn(&BytesN<32>) -> Val>,
+) -> Result<Result<i128, ConversionError>, Result<Error, InvokeError>> {
+    let env = &setup.env;
+    let client = WalletClient::new(env, &setup.wallet);
+    let args = vec![env, setup.account.to_val(), 100i128.into_val(env)];
+    client
+        .set_auths(&[account.authorize(&MockAuthInvoke {
+            contract: &setup.wallet,
+            fn_name: "withdraw",
+            args,
+            sub_invokes: &[],
+        })])
+        .try_withdraw(&setup.account, &100)
+}
+
+fn withdraw(setup: &Setup, account: &CustomAccount<impl Fn(&BytesN<32>) -> Val>) -> i128 {
+    try_withdraw(setup, account).unwrap().unwrap()
+}
+
+// Asserts that withdrawing with the account fails auth, and that the account
+// contract rejects the account's signatures with the given error.
+fn assert_withdraw_fails(
+    setup: &Setup,
+    account: &CustomAccount<impl Fn(&BytesN<32>) -> Val>,
+    error: AccountError,
+) {
+    let env = &setup.env;
+    assert_eq!(
+        try_withdraw(setup, account),
+        Err(Ok(Error::from_type_and_code(
+            ScErrorType::Context,
+            ScErrorCode::InvalidAction
+        )))
+    );
+    let payload = BytesN::from_array(env, &[7; 32]);
+    assert_eq!(
+        env.try_invoke_contract_check_auth::<AccountError>(
+            &setup.account,
+            &payload,
+            account.sign(&payload),
+            &vec![env],
+        ),
+        Err(Ok(error))
+    );
+}
+
+#[test]
+fn test_multisig_two_of_three() {
+    let setup = setup();
+    let [a, b, c] = &setup.keys;
+    assert_eq!(withdraw(&setup, &multisig(&setup, &[a, b])), 100);
+    assert_eq!(withdraw(&setup, &multisig(&setup, &[b, c])), 100);
+    assert_eq!(withdraw(&setup, &multisig(&setup, &[a, b, c])), 100);
+}
+
+#[test]
+fn test_multisig_one_of_three_fails() {
+    let setup = setup();
+    let [a, _, _] = &setup.keys;
+    assert_withdraw_fails(
+        &setup,
+        &multisig(&setup, &[a]),
+        AccountError::NotEnoughSignatures,
+    );
+}
+
+#[test]
+fn test_multisig_unknown_signer_fails() {
+    let setup = setup();
+    let [a, _, _] = &setup.keys;
+    let other = SigningKey::from_bytes(&[4; 32]);
+    assert_withdraw_fails(
+        &setup,
+        &multisig(&setup, &[a, &other]),
+        AccountError::UnknownSigner,
+    );
+}
+
+#[test]
+fn test_multisig_duplicate_signer_fails() {
+    let setup = setup();
+    let [a, _, _] = &setup.keys;
+    assert_withdraw_fails(
+        &setup,
+        &multisig(&setup, &[a, a]),
+        AccountError::DuplicateSigner,
+    );
+}
+
+#[test]
+fn test_sign_uses_signer() {
+    let setup = setup();
+    let account = CustomAccount::new(&setup.account, |payload: &BytesN<32>| payload.to_val());
+    let payload = BytesN::from_array(&setup.env, &[7; 32]);
+    assert_eq!(account.address(), &setup.account);
+    assert_eq!(
+        BytesN::<32>::try_from_val(&setup.env, &account.sign(&payload)).unwrap(),
+        payload
+    );
+}
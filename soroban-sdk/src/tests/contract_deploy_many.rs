@@ -0,0 +1,62 @@
+use crate::{self as soroban_sdk};
+use soroban_sdk::{contract, contractimpl, vec, Address, BytesN, Env, Val, Vec};
+
+const WASM: &[u8] = include_bytes!("../../doctest_fixtures/contract.wasm");
+
+#[contract]
+pub struct Factory;
+
+#[contractimpl]
+impl Factory {
+    pub fn deploy_many(env: Env, wasm_hash: BytesN<32>, salts: Vec<BytesN<32>>) -> Vec<Address> {
+        let mut items = Vec::new(&env);
+        for salt in salts.iter() {
+            items.push_back((salt, Vec::<Val>::new(&env)));
+        }
+        env.deployer().deploy_many(wasm_hash, items)
+    }
+
+    pub fn deployed_address(env: Env, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_current_contract(salt)
+            .deployed_address()
+    }
+}
+
+#[test]
+fn test_deploy_many() {
+    let env = Env::default();
+    let contract_id = env.register(Factory, ());
+    let client = FactoryClient::new(&env, &contract_id);
+    let wasm_hash = env.deployer().upload_contract_wasm(WASM);
+
+    let salts = vec![
+        &env,
+        BytesN::from_array(&env, &[1; 32]),
+        BytesN::from_array(&env, &[2; 32]),
+        BytesN::from_array(&env, &[3; 32]),
+    ];
+    let addresses = client.deploy_many(&wasm_hash, &salts);
+
+    assert_eq!(addresses.len(), 3);
+    for (salt, address) in salts.iter().zip(addresses.iter()) {
+        assert_eq!(client.deployed_address(&salt), address);
+        assert!(address.exists());
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_deploy_many_duplicate_salt() {
+    let env = Env::default();
+    let contract_id = env.register(Factory, ());
+    let client = FactoryClient::new(&env, &contract_id);
+    let wasm_hash = env.deployer().upload_contract_wasm(WASM);
+
+    let salts = vec![
+        &env,
+        BytesN::from_array(&env, &[1; 32]),
+        BytesN::from_array(&env, &[1; 32]),
+    ];
+    client.deploy_many(&wasm_hash, &salts);
+}
@@ -0,0 +1,113 @@
+use crate as soroban_sdk;
+use soroban_sdk::{contract, contracterror, contractimpl, symbol_short, Env};
+
+#[contract]
+pub struct Contract;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    Zero = 1,
+    TooLarge = 2,
+}
+
+fn check(value: u32) -> Result<u32, Error> {
+    if value == 0 {
+        return Err(Error::Zero);
+    }
+    if value > 100 {
+        return Err(Error::TooLarge);
+    }
+    Ok(value)
+}
+
+// Several calls deep, so the error is propagated with `?`.
+fn double_checked(value: u32) -> Result<u32, Error> {
+    let value = check(value)?;
+    check(value * 2)
+}
+
+#[contractimpl]
+impl Contract {
+    pub fn returns(env: Env, value: u32) -> Result<u32, Error> {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("value"), &value);
+        double_checked(value)
+    }
+
+    pub fn panics(env: Env, value: u32) -> u32 {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("value"), &value);
+        match double_checked(value) {
+            Ok(value) => value,
+            Err(e) => panic_with_error!(&env, e),
+        }
+    }
+}
+
+#[test]
+fn test_ok() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    assert_eq!(client.returns(&10), 20);
+    assert_eq!(client.panics(&10), 20);
+}
+
+#[test]
+fn test_returned_error_matches_panic_with_error() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    assert_eq!(client.try_returns(&0), Err(Ok(Error::Zero)));
+    assert_eq!(client.try_panics(&0), Err(Ok(Error::Zero)));
+
+    assert_eq!(client.try_returns(&60), Err(Ok(Error::TooLarge)));
+    assert_eq!(client.try_panics(&60), Err(Ok(Error::TooLarge)));
+}
+
+#[test]
+fn test_returned_error_rolls_back_like_panic_with_error() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    let stored = || {
+        e.as_contract(&contract_id, || {
+            e.storage()
+                .instance()
+                .get::<_, u32>(&symbol_short!("value"))
+        })
+    };
+
+    assert!(client.try_returns(&0).is_err());
+    assert_eq!(stored(), None);
+    assert!(client.try_panics(&0).is_err());
+    assert_eq!(stored(), None);
+
+    // A successful call is not rolled back.
+    client.returns(&10);
+    assert_eq!(stored(), Some(10));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_returned_error_diagnostic() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    ContractClient::new(&e, &contract_id).returns(&60);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_panic_with_error_diagnostic() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    ContractClient::new(&e, &contract_id).panics(&60);
+}
@@ -542,3 +542,95 @@ fn test_ref_fields() {
         ],
     );
 }
+
+#[test]
+fn test_max_topic_fields() {
+    let env = Env::default();
+
+    #[contract]
+    pub struct Contract;
+    let id = env.register(Contract, ());
+
+    #[contractevent(topics = [], data_format = "single-value")]
+    pub struct MyEvent {
+        #[topic]
+        a: u32,
+        #[topic]
+        b: Symbol,
+        #[topic]
+        c: u64,
+        #[topic]
+        d: bool,
+        value: i128,
+    }
+
+    env.as_contract(&id, || {
+        MyEvent {
+            a: 1,
+            b: symbol_short!("b"),
+            c: 3,
+            d: true,
+            value: 5,
+        }
+        .publish(&env);
+    });
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                id,
+                // Expect these event topics, in field order.
+                (1u32, symbol_short!("b"), 3u64, true).into_val(&env),
+                // Expect this event body.
+                5i128.into_val(&env),
+            ),
+        ],
+    );
+}
+
+#[test]
+fn test_max_topic_fields_with_default_prefix() {
+    let env = Env::default();
+
+    #[contract]
+    pub struct Contract;
+    let id = env.register(Contract, ());
+
+    // The default prefix topic counts towards the limit of four topics.
+    #[contractevent(data_format = "single-value")]
+    pub struct MyEvent {
+        #[topic]
+        a: u32,
+        #[topic]
+        b: Symbol,
+        #[topic]
+        c: u64,
+        value: i128,
+    }
+
+    env.as_contract(&id, || {
+        MyEvent {
+            a: 1,
+            b: symbol_short!("b"),
+            c: 3,
+            value: 5,
+        }
+        .publish(&env);
+    });
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                id,
+                // Expect these event topics, in field order.
+                (symbol_short!("my_event"), 1u32, symbol_short!("b"), 3u64).into_val(&env),
+                // Expect this event body.
+                5i128.into_val(&env),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,77 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Deployer as _, Ledger as _},
+    Env,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl(extend_instance(threshold = 200, extend_to = 1000))]
+impl Contract {
+    pub fn get(env: Env) -> u32 {
+        env.storage().instance().get(&0).unwrap_or(0)
+    }
+}
+
+#[contract]
+pub struct DefaultContract;
+
+#[contractimpl(extend_instance)]
+impl DefaultContract {
+    pub fn get(env: Env) -> u32 {
+        env.storage().instance().get(&0).unwrap_or(0)
+    }
+}
+
+fn setup() -> Env {
+    let e = Env::default();
+    e.ledger().set_sequence_number(1000);
+    e.ledger().set_min_persistent_entry_ttl(100);
+    e.ledger().set_max_entry_ttl(10_000);
+    e
+}
+
+#[test]
+fn test_call_extends_instance_ttl() {
+    let e = setup();
+    let id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &id);
+    assert_eq!(e.deployer().get_contract_instance_ttl(&id), 99);
+
+    assert_eq!(client.get(), 0);
+    assert_eq!(e.deployer().get_contract_instance_ttl(&id), 1000);
+    assert_eq!(e.deployer().get_contract_code_ttl(&id), 1000);
+
+    // Not extended while the TTL is at or above the threshold.
+    e.ledger().set_sequence_number(1800);
+    client.get();
+    assert_eq!(e.deployer().get_contract_instance_ttl(&id), 200);
+
+    // Extended again once below the threshold.
+    e.ledger().set_sequence_number(1801);
+    client.get();
+    assert_eq!(e.deployer().get_contract_instance_ttl(&id), 1000);
+}
+
+#[test]
+fn test_call_extends_instance_ttl_to_max_by_default() {
+    let e = setup();
+    let id = e.register(DefaultContract, ());
+    let client = DefaultContractClient::new(&e, &id);
+    let max_ttl = e.as_contract(&id, || e.storage().max_ttl());
+
+    client.get();
+    assert_eq!(e.deployer().get_contract_instance_ttl(&id), max_ttl);
+}
+
+#[test]
+fn test_extend_instance_ttl_auto_limits_to_max_ttl() {
+    let e = setup();
+    let id = e.register(Contract, ());
+    e.as_contract(&id, || {
+        e.extend_instance_ttl_auto(u32::MAX, u32::MAX);
+        assert_eq!(e.storage().instance().get_ttl(), e.storage().max_ttl());
+    });
+}
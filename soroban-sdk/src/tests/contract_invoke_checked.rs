@@ -0,0 +1,80 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, symbol_short, vec, ConversionError, Env, IntoVal,
+    InvokeError, Symbol,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    Negative = 1,
+}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn double(value: i64) -> Result<i64, Error> {
+        if value < 0 {
+            return Err(Error::Negative);
+        }
+        Ok(value * 2)
+    }
+
+    pub fn panic() -> i64 {
+        panic!("I panicked")
+    }
+}
+
+#[test]
+fn test_invoke_checked_success() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    let res = e.invoke_contract_checked::<i64>(
+        &contract_id,
+        &symbol_short!("double"),
+        vec![&e, 21i64.into_val(&e)],
+    );
+    assert_eq!(res, Ok(Ok(42)));
+}
+
+#[test]
+fn test_invoke_checked_wrong_return_type() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    let res = e.invoke_contract_checked::<Symbol>(
+        &contract_id,
+        &symbol_short!("double"),
+        vec![&e, 21i64.into_val(&e)],
+    );
+    assert_eq!(res, Ok(Err(ConversionError)));
+}
+
+#[test]
+fn test_invoke_checked_callee_panics() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    let res = e.invoke_contract_checked::<i64>(&contract_id, &symbol_short!("panic"), vec![&e]);
+    assert_eq!(res, Err(InvokeError::Abort));
+}
+
+#[test]
+fn test_invoke_checked_callee_error() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    let res = e.invoke_contract_checked::<i64>(
+        &contract_id,
+        &symbol_short!("double"),
+        vec![&e, (-1i64).into_val(&e)],
+    );
+    assert_eq!(res, Err(InvokeError::Contract(1)));
+    assert_eq!(
+        Error::try_from_invoke_error(res.unwrap_err()),
+        Some(Error::Negative)
+    );
+}
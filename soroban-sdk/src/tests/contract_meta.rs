@@ -28,3 +28,33 @@ fn test_meta_env_macro_support() {
 
     assert_eq!(entry, expect);
 }
+
+#[soroban_sdk::contract]
+pub struct Contract;
+
+const WASM: &[u8] = include_bytes!("../../doctest_fixtures/contract.wasm");
+
+#[test]
+fn test_contract_metadata() {
+    let env = soroban_sdk::Env::default();
+    let s = |s: &str| soroban_sdk::String::from_str(&env, s);
+    let id = env.register(WASM, ());
+
+    // The SDK declares the rustc version with contractmeta! in every contract
+    // built with it.
+    let meta = env.contract_metadata(&id);
+    assert_eq!(meta.len(), 2);
+    assert_eq!(meta.get(s("rsver")), Some(s("1.74.0")));
+    assert!(meta.contains_key(s("rssdkver")));
+
+    let current = env.as_contract(&id, || env.current_contract_metadata());
+    assert_eq!(current, meta);
+}
+
+#[test]
+#[should_panic(expected = "is not a wasm contract")]
+fn test_contract_metadata_of_rust_contract_panics() {
+    let env = soroban_sdk::Env::default();
+    let id = env.register(Contract, ());
+    env.contract_metadata(&id);
+}
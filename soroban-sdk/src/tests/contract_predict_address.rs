@@ -0,0 +1,53 @@
+use crate::{self as soroban_sdk};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+
+const WASM: &[u8] = include_bytes!("../../doctest_fixtures/contract.wasm");
+
+#[contract]
+pub struct Factory;
+
+#[contractimpl]
+impl Factory {
+    pub fn predict(env: Env, salt: BytesN<32>) -> Address {
+        env.deployer().predict_address(salt)
+    }
+
+    pub fn deploy(env: Env, wasm_hash: BytesN<32>, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_current_contract(salt)
+            .deploy_v2(wasm_hash, ())
+    }
+}
+
+#[test]
+fn test_predict_address_matches_deploy() {
+    let env = Env::default();
+    let contract_id = env.register(Factory, ());
+    let client = FactoryClient::new(&env, &contract_id);
+    let wasm_hash = env.deployer().upload_contract_wasm(WASM);
+
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    let predicted = client.predict(&salt);
+    assert!(!predicted.exists());
+
+    let deployed = client.deploy(&wasm_hash, &salt);
+    assert_eq!(deployed, predicted);
+    assert!(predicted.exists());
+
+    // The prediction doesn't change after deployment.
+    assert_eq!(client.predict(&salt), predicted);
+}
+
+#[test]
+fn test_predict_address_depends_on_salt_and_deployer() {
+    let env = Env::default();
+    let client = FactoryClient::new(&env, &env.register(Factory, ()));
+    let other = FactoryClient::new(&env, &env.register(Factory, ()));
+
+    let salt = BytesN::from_array(&env, &[1; 32]);
+    assert_ne!(
+        client.predict(&salt),
+        client.predict(&BytesN::from_array(&env, &[2; 32]))
+    );
+    assert_ne!(client.predict(&salt), other.predict(&salt));
+}
@@ -33,3 +33,66 @@ fn test() {
 
     assert_eq!(client.get(&2), 4);
 }
+
+#[test]
+fn test_ledger_snapshot_round_trip_preserves_state() {
+    use crate::testutils::{
+        storage::{Instance as _, Persistent as _, Temporary as _},
+        Ledger as _,
+    };
+
+    let e = Env::default();
+    e.ledger().set_sequence_number(1000);
+    e.ledger().set_timestamp(1_700_000_000);
+    let contract_id = e.register(Contract, ());
+    e.as_contract(&contract_id, || {
+        e.storage().instance().set(&1, &10);
+        e.storage().persistent().set(&2, &20);
+        e.storage().temporary().set(&3, &30);
+        e.storage().persistent().extend_ttl(&2, 5000, 6000);
+        e.storage().temporary().extend_ttl(&3, 100, 200);
+    });
+    let ttls = |e: &Env| {
+        e.as_contract(&contract_id, || {
+            (
+                e.storage().instance().get_ttl(),
+                e.storage().persistent().get_ttl(&2),
+                e.storage().temporary().get_ttl(&3),
+            )
+        })
+    };
+    let expected_ttls = ttls(&e);
+    let expected_storage = e.storage_snapshot();
+
+    let snapshot = e.to_ledger_snapshot();
+    let e2 = Env::from_ledger_snapshot(snapshot.clone());
+
+    assert_eq!(e2.ledger().sequence(), 1000);
+    assert_eq!(e2.ledger().timestamp(), 1_700_000_000);
+    assert_eq!(e2.storage_snapshot(), expected_storage);
+    assert_eq!(ttls(&e2), expected_ttls);
+    assert_eq!(e2.to_ledger_snapshot(), snapshot);
+}
+
+#[test]
+fn test_ledger_snapshot_file_round_trip() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+    client.store(&2, &4);
+
+    let path = std::env::temp_dir().join(format!(
+        "soroban-sdk-ledger-snapshot-{}.json",
+        std::process::id()
+    ));
+    e.to_ledger_snapshot_file(&path);
+    let e2 = Env::from_ledger_snapshot_file(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(e2.to_ledger_snapshot(), e.to_ledger_snapshot());
+
+    let contract_id_xdr = xdr::ScAddress::try_from(&contract_id).unwrap();
+    let contract_id = Address::try_from_val(&e2, &contract_id_xdr).unwrap();
+    e2.register_at(&contract_id, Contract, ());
+    assert_eq!(ContractClient::new(&e2, &contract_id).get(&2), 4);
+}
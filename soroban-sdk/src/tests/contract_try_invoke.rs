@@ -0,0 +1,140 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, symbol_short, vec, Address, Env, InvokeError, Vec,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PositionError {
+    Healthy = 1,
+}
+
+#[contract]
+pub struct Position;
+
+#[contractimpl]
+impl Position {
+    pub fn __constructor(env: Env, healthy: bool) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("healthy"), &healthy);
+    }
+
+    // Liquidates the position, returning the amount recovered, or an error if
+    // the position is healthy. The position is marked liquidated before the
+    // check, so the change is rolled back if the call fails.
+    pub fn liquidate(env: Env) -> Result<i128, PositionError> {
+        env.storage().instance().set(&symbol_short!("liq"), &true);
+        let healthy: bool = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("healthy"))
+            .unwrap();
+        if healthy {
+            return Err(PositionError::Healthy);
+        }
+        Ok(100)
+    }
+
+    pub fn liquidated(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("liq"))
+            .unwrap_or(false)
+    }
+
+    pub fn trap(_env: Env) -> i128 {
+        panic!("trapped")
+    }
+}
+
+#[contract]
+pub struct Liquidator;
+
+#[contractimpl]
+impl Liquidator {
+    // Liquidates every position it can, skipping the ones that fail, and
+    // returns the total recovered and the number of healthy positions.
+    pub fn liquidate_all(env: Env, positions: Vec<Address>) -> (i128, u32) {
+        let mut recovered = 0;
+        let mut healthy = 0;
+        for position in positions.iter() {
+            let res = env.try_invoke_contract::<i128, PositionError>(
+                &position,
+                &symbol_short!("liquidate"),
+                vec![&env],
+            );
+            match res {
+                Ok(Ok(amount)) => recovered += amount,
+                Err(Ok(PositionError::Healthy)) => healthy += 1,
+                _ => panic!("unexpected result"),
+            }
+        }
+        (recovered, healthy)
+    }
+
+    pub fn try_trap(env: Env, position: Address) -> bool {
+        let res = env.try_invoke_contract::<i128, PositionError>(
+            &position,
+            &symbol_short!("trap"),
+            vec![&env],
+        );
+        matches!(res, Err(Err(InvokeError::Abort)))
+    }
+}
+
+#[test]
+fn test_caller_proceeds_after_callee_error() {
+    let e = Env::default();
+    let positions = [
+        e.register(Position, (false,)),
+        e.register(Position, (true,)),
+        e.register(Position, (false,)),
+    ];
+    let liquidator = LiquidatorClient::new(&e, &e.register(Liquidator, ()));
+
+    let (recovered, healthy) = liquidator.liquidate_all(&Vec::from_array(&e, positions.clone()));
+    assert_eq!(recovered, 200);
+    assert_eq!(healthy, 1);
+
+    // The failed liquidation was rolled back, the others were not.
+    let liquidated = positions
+        .iter()
+        .map(|p| PositionClient::new(&e, p).liquidated())
+        .collect::<std::vec::Vec<_>>();
+    assert_eq!(liquidated, [true, false, true]);
+}
+
+#[test]
+fn test_caller_proceeds_after_callee_trap() {
+    let e = Env::default();
+    let position = e.register(Position, (false,));
+    let liquidator = LiquidatorClient::new(&e, &e.register(Liquidator, ()));
+
+    assert!(liquidator.try_trap(&position));
+}
+
+#[test]
+fn test_try_invoke_result_layers() {
+    let e = Env::default();
+    let unhealthy = e.register(Position, (false,));
+    let healthy = e.register(Position, (true,));
+    let liquidate = symbol_short!("liquidate");
+
+    assert_eq!(
+        e.try_invoke_contract::<i128, PositionError>(&unhealthy, &liquidate, vec![&e]),
+        Ok(Ok(100))
+    );
+    assert_eq!(
+        e.try_invoke_contract::<i128, PositionError>(&healthy, &liquidate, vec![&e]),
+        Err(Ok(PositionError::Healthy))
+    );
+    assert!(matches!(
+        e.try_invoke_contract::<bool, PositionError>(&unhealthy, &liquidate, vec![&e]),
+        Ok(Err(_))
+    ));
+    assert_eq!(
+        e.try_invoke_contract::<i128, PositionError>(&unhealthy, &symbol_short!("trap"), vec![&e]),
+        Err(Err(InvokeError::Abort))
+    );
+}
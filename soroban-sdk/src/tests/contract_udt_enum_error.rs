@@ -1,5 +1,8 @@
 use crate::{self as soroban_sdk};
-use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Env, IntoVal, Val};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, vec, Address, Env, IntoVal,
+    InvokeError, Val,
+};
 
 #[contract]
 pub struct Contract;
@@ -13,6 +16,7 @@ pub enum Flag {
 #[contracterror]
 pub enum Error {
     AnError = 1,
+    AnotherError = 2,
 }
 
 #[contractimpl]
@@ -23,6 +27,35 @@ impl Contract {
             Flag::B => Err(Error::AnError),
         }
     }
+
+    pub fn g() -> Result<(), Error> {
+        Err(Error::AnotherError)
+    }
+}
+
+#[contract]
+pub struct Caller;
+
+#[contractimpl]
+impl Caller {
+    // Calls the contract without knowing its error type at the call site, and
+    // recovers the typed error from the invoke error.
+    pub fn call(env: Env, contract_id: Address, flag: Flag) -> u32 {
+        let res = env.try_invoke_contract::<(), InvokeError>(
+            &contract_id,
+            &symbol_short!("f"),
+            vec![&env, flag.into_val(&env)],
+        );
+        match res {
+            Ok(_) => 0,
+            Err(Ok(e)) => match Error::try_from_invoke_error(e) {
+                Some(Error::AnError) => 1,
+                Some(Error::AnotherError) => 2,
+                None => u32::MAX,
+            },
+            Err(Err(_)) => u32::MAX,
+        }
+    }
 }
 
 // The assertions in the following tests intentionally don't use assert_eq, and
@@ -82,3 +115,70 @@ fn test_double_ref_to_val() {
     let val: Val = (&&e).into_val(&env);
     let _: Error = val.into_val(&env);
 }
+
+#[test]
+fn test_try_from_error() {
+    use soroban_env_host::xdr::{ScErrorCode, ScErrorType};
+
+    // See comment above about why these assertions are matches.
+    let Some(Error::AnError) = Error::try_from_error(soroban_sdk::Error::from_contract_error(1))
+    else {
+        panic!("unexpected value returned");
+    };
+    let Some(Error::AnotherError) =
+        Error::try_from_error(soroban_sdk::Error::from_contract_error(2))
+    else {
+        panic!("unexpected value returned");
+    };
+    let None = Error::try_from_error(soroban_sdk::Error::from_contract_error(3)) else {
+        panic!("unexpected value returned");
+    };
+    let None = Error::try_from_error(soroban_sdk::Error::from_type_and_code(
+        ScErrorType::Context,
+        ScErrorCode::InvalidAction,
+    )) else {
+        panic!("unexpected value returned");
+    };
+}
+
+#[test]
+fn test_try_from_invoke_error() {
+    // See comment above about why these assertions are matches.
+    let Some(Error::AnotherError) = Error::try_from_invoke_error(InvokeError::Contract(2)) else {
+        panic!("unexpected value returned");
+    };
+    let None = Error::try_from_invoke_error(InvokeError::Contract(3)) else {
+        panic!("unexpected value returned");
+    };
+    let None = Error::try_from_invoke_error(InvokeError::Abort) else {
+        panic!("unexpected value returned");
+    };
+}
+
+#[test]
+fn test_caller_recovers_error() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let caller_id = env.register(Caller, ());
+    let caller = CallerClient::new(&env, &caller_id);
+
+    assert_eq!(caller.call(&contract_id, &Flag::A), 0);
+    assert_eq!(caller.call(&contract_id, &Flag::B), 1);
+}
+
+#[test]
+fn test_client_error_recovered_from_invoke_error() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    // The client returns the typed error directly, and converting it through
+    // an invoke error recovers the same variant.
+    let Err(Ok(e)) = client.try_g() else {
+        panic!("unexpected value returned");
+    };
+    let invoke_error: InvokeError = e.into();
+    let Some(Error::AnotherError) = Error::try_from_invoke_error(invoke_error) else {
+        panic!("unexpected value returned");
+    };
+}
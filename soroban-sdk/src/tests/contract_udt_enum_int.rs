@@ -1,5 +1,6 @@
 use crate::{self as soroban_sdk};
-use soroban_sdk::{contract, contracttype, Env, IntoVal, Val};
+use soroban_sdk::{contract, contracttype, ConversionError, Env, IntoVal, TryFromVal, Val};
+use stellar_xdr::curr::ScVal;
 
 #[contract]
 pub struct Contract;
@@ -10,6 +11,14 @@ pub enum Flag {
     B = 1,
 }
 
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Reordered {
+    C = 7,
+    A = 1,
+    B = 2,
+}
+
 #[test]
 fn test_owned_to_val() {
     let env = Env::default();
@@ -48,3 +57,28 @@ fn test_double_ref_to_val() {
         panic!("failed roundtrip");
     };
 }
+
+#[test]
+fn test_encoding_uses_discriminant() {
+    let env = Env::default();
+
+    // The variants are encoded as their discriminants, rather than their
+    // position in the enum, so variants can be reordered.
+    for (variant, discriminant) in [
+        (Reordered::C, 7u32),
+        (Reordered::A, 1u32),
+        (Reordered::B, 2u32),
+    ] {
+        let val: Val = variant.into_val(&env);
+        assert_eq!(u32::try_from_val(&env, &val), Ok(discriminant));
+        assert_eq!(Reordered::try_from_val(&env, &val), Ok(variant));
+
+        let scval: ScVal = variant.try_into().unwrap();
+        assert_eq!(scval, ScVal::U32(discriminant));
+        assert_eq!(Reordered::try_from_val(&env, &scval), Ok(variant));
+    }
+
+    // Positions are not discriminants.
+    let val: Val = 0u32.into_val(&env);
+    assert_eq!(Reordered::try_from_val(&env, &val), Err(ConversionError));
+}
@@ -0,0 +1,177 @@
+use crate::{self as soroban_sdk};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, map, symbol_short, ConversionError, Env, IntoVal, String,
+    TryFromVal, Val,
+};
+use stellar_xdr::curr as stellar_xdr;
+use stellar_xdr::{ScMap, ScMapEntry, ScSymbol, ScVal};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UdtV1 {
+    pub a: i32,
+    pub b: i32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Udt {
+    pub a: i32,
+    pub b: i32,
+    #[default]
+    pub c: Option<String>,
+    #[default]
+    pub d: u64,
+}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn echo(udt: Udt) -> Udt {
+        udt
+    }
+}
+
+#[test]
+fn test_decode_earlier_encoding() {
+    let env = Env::default();
+
+    let v1: Val = UdtV1 { a: 5, b: 7 }.into_val(&env);
+    let udt = Udt::try_from_val(&env, &v1);
+    assert_eq!(
+        udt,
+        Ok(Udt {
+            a: 5,
+            b: 7,
+            c: None,
+            d: 0
+        })
+    );
+
+    // Some of the default fields can be present.
+    let map = map![
+        &env,
+        (symbol_short!("a"), 5.into_val(&env)),
+        (symbol_short!("b"), 7.into_val(&env)),
+        (symbol_short!("d"), 9u64.into_val(&env)),
+    ]
+    .to_val();
+    let udt = Udt::try_from_val(&env, &map);
+    assert_eq!(
+        udt,
+        Ok(Udt {
+            a: 5,
+            b: 7,
+            c: None,
+            d: 9
+        })
+    );
+}
+
+#[test]
+fn test_roundtrip() {
+    let env = Env::default();
+
+    let udt = Udt {
+        a: 5,
+        b: 7,
+        c: Some(String::from_str(&env, "c")),
+        d: 9,
+    };
+    let val: Val = udt.clone().into_val(&env);
+    assert_eq!(Udt::try_from_val(&env, &val), Ok(udt.clone()));
+
+    let client = ContractClient::new(&env, &env.register(Contract, ()));
+    assert_eq!(client.echo(&udt), udt);
+}
+
+#[test]
+fn test_decode_errors() {
+    let env = Env::default();
+
+    // Fields without a default must be present.
+    let map = map![&env, (symbol_short!("a"), 5)].to_val();
+    assert_eq!(Udt::try_from_val(&env, &map), Err(ConversionError));
+
+    // Keys that aren't fields are not allowed.
+    let map = map![
+        &env,
+        (symbol_short!("a"), 5),
+        (symbol_short!("b"), 7),
+        (symbol_short!("e"), 9)
+    ]
+    .to_val();
+    assert_eq!(Udt::try_from_val(&env, &map), Err(ConversionError));
+
+    // Default fields that are present must have the right type.
+    let map = map![
+        &env,
+        (symbol_short!("a"), 5.into_val(&env)),
+        (symbol_short!("b"), 7.into_val(&env)),
+        (symbol_short!("d"), symbol_short!("d").into_val(&env)),
+    ]
+    .to_val();
+    assert_eq!(Udt::try_from_val(&env, &map), Err(ConversionError));
+
+    // Values that aren't maps are not structs.
+    let val: Val = 5u32.into_val(&env);
+    assert_eq!(Udt::try_from_val(&env, &val), Err(ConversionError));
+}
+
+#[test]
+fn test_decode_earlier_encoding_from_xdr() {
+    let env = Env::default();
+    let entry = |k: &str, v: i32| ScMapEntry {
+        key: ScVal::Symbol(ScSymbol(k.try_into().unwrap())),
+        val: ScVal::I32(v),
+    };
+
+    let scval = ScVal::Map(Some(ScMap(
+        vec![entry("a", 5), entry("b", 7)].try_into().unwrap(),
+    )));
+    let udt = Udt::try_from_val(&env, &scval);
+    assert_eq!(
+        udt,
+        Ok(Udt {
+            a: 5,
+            b: 7,
+            c: None,
+            d: 0
+        })
+    );
+
+    let scval = ScVal::Map(Some(ScMap(vec![entry("a", 5)].try_into().unwrap())));
+    assert_eq!(
+        Udt::try_from_val(&env, &scval),
+        Err(stellar_xdr::Error::Invalid)
+    );
+
+    let scval = ScVal::Map(Some(ScMap(
+        vec![entry("a", 5), entry("b", 7), entry("e", 9)]
+            .try_into()
+            .unwrap(),
+    )));
+    assert_eq!(
+        Udt::try_from_val(&env, &scval),
+        Err(stellar_xdr::Error::Invalid)
+    );
+}
+
+// All fields are written, so the encoding can't be read by a struct
+// without the default fields.
+#[test]
+#[should_panic]
+fn test_earlier_struct_errors_on_later_encoding() {
+    let env = Env::default();
+
+    let val: Val = Udt {
+        a: 5,
+        b: 7,
+        c: None,
+        d: 0,
+    }
+    .into_val(&env);
+    let _ = UdtV1::try_from_val(&env, &val);
+}
@@ -265,3 +265,42 @@ fn test_cost_estimate_budget() {
         .as_str(),
     );
 }
+
+#[test]
+fn test_cost_estimate_assert_budget_below() {
+    let e = Env::default();
+
+    let contract_id = e.register(contract_data::WASM, ());
+    let client = contract_data::Client::new(&e, &contract_id);
+
+    client.put(&symbol_short!("k1"), &symbol_short!("v1"));
+
+    e.cost_estimate()
+        .assert_budget_below(10_000_000, 10_000_000);
+}
+
+#[test]
+#[should_panic(expected = "budget exceeded: cpu instructions used")]
+fn test_cost_estimate_assert_budget_below_cpu_exceeded() {
+    let e = Env::default();
+
+    let contract_id = e.register(contract_data::WASM, ());
+    let client = contract_data::Client::new(&e, &contract_id);
+
+    client.put(&symbol_short!("k1"), &symbol_short!("v1"));
+
+    e.cost_estimate().assert_budget_below(1_000, 10_000_000);
+}
+
+#[test]
+#[should_panic(expected = "budget exceeded: memory bytes used")]
+fn test_cost_estimate_assert_budget_below_mem_exceeded() {
+    let e = Env::default();
+
+    let contract_id = e.register(contract_data::WASM, ());
+    let client = contract_data::Client::new(&e, &contract_id);
+
+    client.put(&symbol_short!("k1"), &symbol_short!("v1"));
+
+    e.cost_estimate().assert_budget_below(10_000_000, 1_000);
+}
@@ -0,0 +1,506 @@
+use crate::{self as soroban_sdk};
+use soroban_sdk::{
+    bytes, bytesn,
+    crypto::bn254::{Bn254, Fp, Fr, G1Affine, G2Affine},
+    vec, Bytes, Env, Vec, U256,
+};
+
+#[test]
+fn test_bn254_g1_msm() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    let one = G1Affine::from_bytes(bytesn!(&env, 0x00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002));
+
+    let vp: Vec<G1Affine> = vec![&env, one.clone(), one.clone()];
+    let vs: Vec<Fr> = vec![
+        &env,
+        U256::from_u32(&env, 2).into(),
+        U256::from_u32(&env, 3).into(),
+    ];
+    let res = bn254.g1_msm(vp, vs);
+    assert_eq!(res, bn254.g1_mul(&one, &U256::from_u32(&env, 5).into()));
+}
+
+#[test]
+fn test_bn254_g2_msm() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    let one = G2Affine::from_bytes(bytesn!(&env, 0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa));
+
+    let vp: Vec<G2Affine> = vec![&env, one.clone(), one.clone()];
+    let vs: Vec<Fr> = vec![
+        &env,
+        U256::from_u32(&env, 2).into(),
+        U256::from_u32(&env, 3).into(),
+    ];
+    let res = bn254.g2_msm(vp, vs);
+    assert_eq!(res, bn254.g2_mul(&one, &U256::from_u32(&env, 5).into()));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Crypto, InvalidInput)")]
+fn test_bn254_g1_msm_length_mismatch() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    let one = G1Affine::from_bytes(bytesn!(&env, 0x00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002));
+
+    let vp: Vec<G1Affine> = vec![&env, one.clone(), one];
+    let vs: Vec<Fr> = vec![&env, U256::from_u32(&env, 2).into()];
+    bn254.g1_msm(vp, vs);
+}
+
+#[test]
+fn test_bn254_identity() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+
+    let g1_zero = G1Affine::identity(&env);
+    assert!(g1_zero.is_identity());
+    assert_eq!(G1Affine::from_bytes(g1_zero.to_bytes()), g1_zero);
+    let g1_one = G1Affine::from_bytes(bytesn!(&env, 0x00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002));
+    assert!(!g1_one.is_identity());
+    assert_eq!(bn254.g1_add(&g1_zero, &g1_one), g1_one);
+    assert!(bn254
+        .g1_mul(&g1_one, &U256::from_u32(&env, 0).into())
+        .is_identity());
+
+    let g2_zero = G2Affine::identity(&env);
+    assert!(g2_zero.is_identity());
+    assert_eq!(G2Affine::from_bytes(g2_zero.to_bytes()), g2_zero);
+    let g2_one = G2Affine::from_bytes(bytesn!(&env, 0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa));
+    assert!(!g2_one.is_identity());
+    assert_eq!(bn254.g2_add(&g2_zero, &g2_one), g2_one);
+    assert!(bn254
+        .g2_mul(&g2_one, &U256::from_u32(&env, 0).into())
+        .is_identity());
+}
+
+#[test]
+fn test_bn254_g1_negation() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    let zero = G1Affine::identity(&env);
+    assert_eq!(bn254.g1_neg(&zero), zero);
+
+    let one = G1Affine::from_bytes(bytesn!(&env, 0x00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002));
+    let neg_one = G1Affine::from_bytes(bytesn!(&env, 0x000000000000000000000000000000000000000000000000000000000000000130644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45));
+    assert_eq!(bn254.g1_neg(&one), neg_one);
+    assert_eq!(-one.clone(), neg_one);
+    assert_eq!(bn254.g1_add(&one, &bn254.g1_neg(&one)), zero);
+
+    let p = bn254.g1_mul(&one, &U256::from_u32(&env, 12345).into());
+    assert_eq!(bn254.g1_add(&p, &bn254.g1_neg(&p)), zero);
+    assert_eq!(bn254.g1_neg(&bn254.g1_neg(&p)), p);
+}
+
+#[test]
+fn test_bn254_g2_negation() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    let zero = G2Affine::identity(&env);
+    assert_eq!(bn254.g2_neg(&zero), zero);
+
+    let one = G2Affine::from_bytes(bytesn!(&env, 0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa));
+    let neg_one = G2Affine::from_bytes(bytesn!(&env, 0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed275dc4a288d1afb3cbb1ac09187524c7db36395df7be3b99e673b13a075a65ec1d9befcd05a5323e6da4d435f3b617cdb3af83285c2df711ef39c01571827f9d));
+    assert_eq!(bn254.g2_neg(&one), neg_one);
+    assert_eq!(-one.clone(), neg_one);
+    assert_eq!(bn254.g2_add(&one, &bn254.g2_neg(&one)), zero);
+
+    let p = bn254.g2_mul(&one, &U256::from_u32(&env, 12345).into());
+    assert_eq!(bn254.g2_add(&p, &bn254.g2_neg(&p)), zero);
+}
+
+#[test]
+fn test_bn254_pairing_with_negation() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    let g1 = G1Affine::from_bytes(bytesn!(&env, 0x00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002));
+    let g2 = G2Affine::from_bytes(bytesn!(&env, 0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa));
+
+    // e(a * G1, G2) == e(G1, a * G2)  <=>  e(-(a * G1), G2) * e(G1, a * G2) == 1
+    let a: Fr = U256::from_u32(&env, 7).into();
+    let a_g1 = bn254.g1_mul(&g1, &a);
+    let a_g2 = bn254.g2_mul(&g2, &a);
+    assert!(bn254.pairing_check(
+        vec![&env, bn254.g1_neg(&a_g1), g1.clone()],
+        vec![&env, g2.clone(), a_g2.clone()],
+    ));
+    assert!(!bn254.pairing_check(vec![&env, a_g1, g1], vec![&env, g2, a_g2]));
+}
+
+#[test]
+fn test_bn254_fr_neg() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    let modulus = U256::from_be_bytes(
+        &env,
+        &bytes!(
+            &env,
+            0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+        ),
+    );
+
+    let zero: Fr = U256::from_u32(&env, 0).into();
+    assert_eq!(bn254.fr_neg(&zero), zero);
+
+    let x: Fr = U256::from_u32(&env, 5).into();
+    assert_eq!(
+        bn254.fr_neg(&x),
+        modulus.sub(&U256::from_u32(&env, 5)).into()
+    );
+    assert_eq!(bn254.fr_add(&x, &bn254.fr_neg(&x)), zero);
+}
+
+#[test]
+fn test_bn254_fr_div() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+
+    let six: Fr = U256::from_u32(&env, 6).into();
+    let three: Fr = U256::from_u32(&env, 3).into();
+    assert_eq!(bn254.fr_div(&six, &three), U256::from_u32(&env, 2).into());
+
+    let seven: Fr = U256::from_u32(&env, 7).into();
+    let q = bn254.fr_div(&six, &seven);
+    assert_eq!(bn254.fr_mul(&q, &seven), six);
+}
+
+#[test]
+#[should_panic(expected = "division by zero in Fr")]
+fn test_bn254_fr_div_by_zero() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    bn254.fr_div(
+        &U256::from_u32(&env, 6).into(),
+        &U256::from_u32(&env, 0).into(),
+    );
+}
+
+#[test]
+fn test_bn254_fr_from_u256_reduce() {
+    let env = Env::default();
+    let modulus = U256::from_be_bytes(
+        &env,
+        &bytes!(
+            &env,
+            0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+        ),
+    );
+
+    let five = U256::from_u32(&env, 5);
+    assert_eq!(
+        Fr::from_u256_reduce(five.clone()),
+        Fr::from_u256(five.clone())
+    );
+    assert_eq!(
+        Fr::from_u256_reduce(modulus.add(&five)),
+        Fr::from_u256(five)
+    );
+    assert_eq!(
+        Fr::from_u256_reduce(modulus.clone()),
+        U256::from_u32(&env, 0).into()
+    );
+
+    let max = U256::from_parts(&env, u64::MAX, u64::MAX, u64::MAX, u64::MAX);
+    assert_eq!(
+        Fr::from_u256_reduce(max),
+        Fr::from_bytes(bytesn!(
+            &env,
+            0x0e0a77c19a07df2f666ea36f7879462e36fc76959f60cd29ac96341c4ffffffa
+        ))
+    );
+}
+
+#[test]
+fn test_bn254_g1_compressed() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    let one = G1Affine::from_bytes(bytesn!(&env, 0x00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002));
+
+    let compressed = one.to_compressed();
+    assert_eq!(
+        compressed,
+        bytesn!(
+            &env,
+            0x0000000000000000000000000000000000000000000000000000000000000001
+        )
+    );
+    assert_eq!(G1Affine::from_compressed(&env, compressed), one);
+
+    // -one has an odd Y, which sets the sign bit
+    let neg_one = -one.clone();
+    let compressed = neg_one.to_compressed();
+    assert_eq!(
+        compressed,
+        bytesn!(
+            &env,
+            0x8000000000000000000000000000000000000000000000000000000000000001
+        )
+    );
+    assert_eq!(G1Affine::from_compressed(&env, compressed), neg_one);
+
+    for i in [2u32, 3, 12345, 999999] {
+        let p = bn254.g1_mul(&one, &U256::from_u32(&env, i).into());
+        assert_eq!(G1Affine::from_compressed(&env, p.to_compressed()), p);
+        let neg_p = -p;
+        assert_eq!(
+            G1Affine::from_compressed(&env, neg_p.to_compressed()),
+            neg_p
+        );
+    }
+
+    let zero = G1Affine::identity(&env);
+    assert_eq!(
+        zero.to_compressed(),
+        bytesn!(
+            &env,
+            0x4000000000000000000000000000000000000000000000000000000000000000
+        )
+    );
+    assert_eq!(G1Affine::from_compressed(&env, zero.to_compressed()), zero);
+}
+
+#[test]
+#[should_panic(expected = "invalid input - no point on G1 with the given X coordinate")]
+fn test_bn254_g1_from_compressed_not_on_curve() {
+    let env = Env::default();
+    // 0^3 + 3 is not a square in Fp
+    G1Affine::from_compressed(
+        &env,
+        bytesn!(
+            &env,
+            0x0000000000000000000000000000000000000000000000000000000000000000
+        ),
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid input - Fp is larger than the field modulus")]
+fn test_bn254_g1_from_compressed_out_of_range() {
+    let env = Env::default();
+    G1Affine::from_compressed(
+        &env,
+        bytesn!(
+            &env,
+            0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47
+        ),
+    );
+}
+
+#[test]
+fn test_bn254_g2_compressed() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    let one = G2Affine::from_bytes(bytesn!(&env, 0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa));
+
+    let compressed = one.to_compressed();
+    assert_eq!(
+        compressed,
+        bytesn!(&env, 0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed)
+    );
+    assert_eq!(G2Affine::from_compressed(&env, compressed), one);
+
+    let neg_one = -one.clone();
+    let compressed = neg_one.to_compressed();
+    assert_eq!(
+        compressed,
+        bytesn!(&env, 0x998e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed)
+    );
+    assert_eq!(G2Affine::from_compressed(&env, compressed), neg_one);
+
+    for i in [2u32, 3, 12345] {
+        let p = bn254.g2_mul(&one, &U256::from_u32(&env, i).into());
+        assert_eq!(G2Affine::from_compressed(&env, p.to_compressed()), p);
+        let neg_p = -p;
+        assert_eq!(
+            G2Affine::from_compressed(&env, neg_p.to_compressed()),
+            neg_p
+        );
+    }
+
+    let zero = G2Affine::identity(&env);
+    assert_eq!(G2Affine::from_compressed(&env, zero.to_compressed()), zero);
+}
+
+#[test]
+#[should_panic(expected = "invalid input - no point on G2 with the given X coordinate")]
+fn test_bn254_g2_from_compressed_not_on_curve() {
+    let env = Env::default();
+    G2Affine::from_compressed(
+        &env,
+        bytesn!(&env, 0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    );
+}
+
+#[test]
+fn test_bn254_hash_to_g1() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    let dst = Bytes::from_slice(
+        &env,
+        "QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_".as_bytes(),
+    );
+
+    let vectors = [
+        ("", bytesn!(&env, 0x0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e8602925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5)),
+        ("abc", bytesn!(&env, 0x23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d104142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d)),
+        ("abcdef0123456789", bytesn!(&env, 0x187dbf1c3c89aceceef254d6548d7163fdfa43084145f92c4c91c85c21442d4a0abd99d5b0000910b56058f9cc3b0ab0a22d47cf27615f588924fac1e5c63b4d)),
+        ("q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", bytesn!(&env, 0x00fe2b0743575324fc452d590d217390ad48e5a16cf051bee5c40a2eba233f5c0794211e0cc72d3cbbdf8e4e5cd6e7d7e78d101ff94862caae8acbe63e9fdc78)),
+    ];
+    for (msg, expected) in vectors {
+        let msg = Bytes::from_slice(&env, msg.as_bytes());
+        let res = bn254.hash_to_g1(&dst, &msg);
+        assert_eq!(res, G1Affine::from_bytes(expected));
+        assert!(bn254.g1_is_in_subgroup(&res));
+    }
+}
+
+#[test]
+fn test_bn254_map_fp_to_g1() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    for fp in [
+        bytesn!(
+            &env,
+            0x0000000000000000000000000000000000000000000000000000000000000000
+        ),
+        bytesn!(
+            &env,
+            0x0000000000000000000000000000000000000000000000000000000000000001
+        ),
+        bytesn!(
+            &env,
+            0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46
+        ),
+    ] {
+        let p = bn254.map_fp_to_g1(&Fp::from_bytes(fp));
+        assert!(!p.is_identity());
+        assert!(bn254.g1_is_in_subgroup(&p));
+    }
+}
+
+#[test]
+#[should_panic(expected = "invalid input - dst must be between 1 and 255 bytes")]
+fn test_bn254_hash_to_g1_empty_dst() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    bn254.hash_to_g1(
+        &Bytes::new(&env),
+        &Bytes::from_slice(&env, "abc".as_bytes()),
+    );
+}
+
+#[test]
+fn test_bn254_generators() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+
+    let g1 = G1Affine::generator(&env);
+    assert_eq!(g1, G1Affine::from_bytes(bytesn!(&env, 0x00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002)));
+    assert!(bn254.g1_is_in_subgroup(&g1));
+    assert_eq!(bn254.g1_mul(&g1, &U256::from_u32(&env, 1).into()), g1);
+
+    let g2 = G2Affine::generator(&env);
+    assert_eq!(g2, G2Affine::from_bytes(bytesn!(&env, 0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa)));
+    assert!(bn254.g2_is_in_subgroup(&g2));
+    assert_eq!(bn254.g2_mul(&g2, &U256::from_u32(&env, 1).into()), g2);
+
+    // e(G1, G2) * e(-G1, G2) == 1
+    assert!(bn254.pairing_check(vec![&env, g1.clone(), -g1], vec![&env, g2.clone(), g2]));
+}
+
+#[test]
+fn test_bn254_point_equality() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+
+    let g1 = G1Affine::generator(&env);
+    let g1_double = bn254.g1_mul(&g1, &U256::from_u32(&env, 2).into());
+    // every way of reaching the point at infinity yields the same encoding
+    let g1_zero = G1Affine::identity(&env);
+    assert_eq!(g1_zero, G1Affine::identity(&env));
+    assert_eq!(bn254.g1_add(&g1, &-g1.clone()), g1_zero);
+    assert_eq!(bn254.g1_mul(&g1, &U256::from_u32(&env, 0).into()), g1_zero);
+    assert_ne!(g1, g1_zero);
+    assert_ne!(g1, g1_double);
+    assert_eq!(bn254.g1_add(&g1, &g1), g1_double);
+
+    let g2 = G2Affine::generator(&env);
+    let g2_double = bn254.g2_mul(&g2, &U256::from_u32(&env, 2).into());
+    let g2_zero = G2Affine::identity(&env);
+    assert_eq!(g2_zero, G2Affine::identity(&env));
+    assert_eq!(bn254.g2_add(&g2, &-g2.clone()), g2_zero);
+    assert_eq!(bn254.g2_mul(&g2, &U256::from_u32(&env, 0).into()), g2_zero);
+    assert_ne!(g2, g2_zero);
+    assert_ne!(g2, g2_double);
+    assert_eq!(bn254.g2_add(&g2, &g2), g2_double);
+}
+
+#[test]
+fn test_bn254_batch_in_subgroup() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+
+    let g1 = G1Affine::generator(&env);
+    let g1_points: Vec<G1Affine> = vec![
+        &env,
+        g1.clone(),
+        G1Affine::identity(&env),
+        bn254.g1_mul(&g1, &U256::from_u32(&env, 12345).into()),
+    ];
+    assert!(bn254.g1_batch_in_subgroup(&g1_points));
+    assert!(bn254.g1_batch_in_subgroup(&Vec::new(&env)));
+
+    let g2 = G2Affine::generator(&env);
+    // on the twist (x = 1) but outside the r-torsion subgroup
+    let outside = G2Affine::from_bytes(bytesn!(&env, 0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010d1271953ed9ea0836846e70a1934187998c7f790cb4d7511b7f8da82de048a42869111d5381f072f8e2728fdb825a51aadd70e52c9830e9ab4b871c0531f1bb));
+    assert!(!bn254.g2_is_in_subgroup(&outside));
+
+    let mut g2_points: Vec<G2Affine> = vec![
+        &env,
+        g2.clone(),
+        G2Affine::identity(&env),
+        bn254.g2_mul(&g2, &U256::from_u32(&env, 12345).into()),
+    ];
+    assert!(bn254.g2_batch_in_subgroup(&g2_points));
+    assert!(bn254.g2_batch_in_subgroup(&Vec::new(&env)));
+    g2_points.insert(1, outside);
+    assert!(!bn254.g2_batch_in_subgroup(&g2_points));
+}
+
+#[test]
+fn test_bn254_map_scalars_to_points() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+    let g1 = G1Affine::generator(&env);
+
+    let scalars: Vec<Fr> = vec![
+        &env,
+        U256::from_u32(&env, 1).into(),
+        U256::from_u32(&env, 2).into(),
+        U256::from_u32(&env, 3).into(),
+    ];
+    let points: Vec<G1Affine> =
+        Vec::from_iter_in(&env, scalars.iter().map(|s| bn254.g1_mul(&g1, &s)));
+    assert_eq!(points.len(), 3);
+    for (p, s) in points.iter().zip(scalars.iter()) {
+        assert_eq!(p, bn254.g1_mul(&g1, &s));
+    }
+
+    // 1 * G + 2 * 2G + 3 * 3G == 14 * G
+    let res = bn254.g1_msm(points, scalars);
+    assert_eq!(res, bn254.g1_mul(&g1, &U256::from_u32(&env, 14).into()));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Crypto, InvalidInput)")]
+fn test_bn254_g1_batch_in_subgroup_not_on_curve() {
+    let env = Env::default();
+    let bn254 = Bn254::new(&env);
+
+    // G1 has a cofactor of 1 so every point on the curve is in the subgroup,
+    // and an invalid point is one that is not on the curve, such as (1, 3).
+    let not_on_curve = G1Affine::from_bytes(bytesn!(&env, 0x00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000003));
+    let points: Vec<G1Affine> = vec![&env, G1Affine::generator(&env), not_on_curve];
+    bn254.g1_batch_in_subgroup(&points);
+}
@@ -0,0 +1,42 @@
+use crate::{bytes, Bytes, Env};
+
+#[test]
+fn test_constant_time_eq_equal() {
+    let env = Env::default();
+    let crypto = env.crypto();
+
+    assert!(crypto.constant_time_eq(&Bytes::new(&env), &Bytes::new(&env)));
+    assert!(crypto.constant_time_eq(&bytes!(&env, 0x0102), &bytes!(&env, 0x0102)));
+
+    // Longer than the chunks the bytes are compared in.
+    let a = Bytes::repeat(&env, 0xab, 200);
+    assert!(crypto.constant_time_eq(&a, &a.clone()));
+}
+
+#[test]
+fn test_constant_time_eq_unequal_same_length() {
+    let env = Env::default();
+    let crypto = env.crypto();
+
+    assert!(!crypto.constant_time_eq(&bytes!(&env, 0x0102), &bytes!(&env, 0x0103)));
+    assert!(!crypto.constant_time_eq(&bytes!(&env, 0x0102), &bytes!(&env, 0x0202)));
+
+    // Differences in each position, including in later chunks.
+    let a = Bytes::repeat(&env, 0xab, 200);
+    for i in [0, 63, 64, 199] {
+        let mut b = a.clone();
+        b.set(i, 0xac);
+        assert!(!crypto.constant_time_eq(&a, &b), "{i}");
+        assert!(!crypto.constant_time_eq(&b, &a), "{i}");
+    }
+}
+
+#[test]
+fn test_constant_time_eq_different_length() {
+    let env = Env::default();
+    let crypto = env.crypto();
+
+    assert!(!crypto.constant_time_eq(&Bytes::new(&env), &bytes!(&env, 0x00)));
+    assert!(!crypto.constant_time_eq(&bytes!(&env, 0x0102), &bytes!(&env, 0x010203)));
+    assert!(!crypto.constant_time_eq(&bytes!(&env, 0x010203), &bytes!(&env, 0x0102)));
+}
@@ -1,4 +1,4 @@
-use crate::{bytes, bytesn, Env};
+use crate::{bytes, bytesn, vec, Bytes, BytesN, Env, Vec};
 
 #[test]
 fn test_verify_sig_ed25519() {
@@ -40,3 +40,79 @@ fn test_verify_sig_ed25519_invalid_sig() {
     env.crypto()
         .ed25519_verify(&public_key, &message, &signature);
 }
+
+// From https://datatracker.ietf.org/doc/html/rfc8032#section-7.1 TESTS 1, 2
+// and 3.
+fn rfc8032_batch(env: &Env) -> (Vec<BytesN<32>>, Vec<Bytes>, Vec<BytesN<64>>) {
+    let public_keys = vec![
+        env,
+        bytesn!(
+            env,
+            0xd75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a
+        ),
+        bytesn!(
+            env,
+            0x3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c
+        ),
+        bytesn!(
+            env,
+            0xfc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025
+        ),
+    ];
+    let messages = vec![env, Bytes::new(env), bytes!(env, 0x72), bytes!(env, 0xaf82)];
+    let signatures = vec![
+        env,
+        bytesn!(
+            env,
+            0xe5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b
+        ),
+        bytesn!(
+            env,
+            0x92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00
+        ),
+        bytesn!(
+            env,
+            0x6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a
+        ),
+    ];
+    (public_keys, messages, signatures)
+}
+
+#[test]
+fn test_verify_sig_ed25519_batch() {
+    let env = Env::default();
+    let (public_keys, messages, signatures) = rfc8032_batch(&env);
+
+    env.crypto()
+        .ed25519_verify_batch(&public_keys, &messages, &signatures);
+}
+
+#[test]
+fn test_verify_sig_ed25519_batch_empty() {
+    let env = Env::default();
+
+    env.crypto()
+        .ed25519_verify_batch(&Vec::new(&env), &Vec::new(&env), &Vec::new(&env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Crypto, InvalidInput)")]
+fn test_verify_sig_ed25519_batch_one_invalid_sig() {
+    let env = Env::default();
+    let (public_keys, mut messages, signatures) = rfc8032_batch(&env);
+    messages.set(1, bytes!(&env, 0x73));
+
+    env.crypto()
+        .ed25519_verify_batch(&public_keys, &messages, &signatures);
+}
+
+#[test]
+#[should_panic(expected = "ed25519_verify_batch with mismatched lengths")]
+fn test_verify_sig_ed25519_batch_mismatched_lengths() {
+    let env = Env::default();
+    let (public_keys, mut messages, signatures) = rfc8032_batch(&env);
+    messages.pop_back();
+
+    env.crypto()
+        .ed25519_verify_batch(&public_keys, &messages, &signatures);
+}
@@ -0,0 +1,100 @@
+use crate::{bytes, Bytes, Env};
+
+// Test vectors from RFC 5869.
+
+fn bytes_of(env: &Env, iter: impl Iterator<Item = u8>) -> Bytes {
+    Bytes::from_slice(env, &iter.collect::<std::vec::Vec<u8>>())
+}
+
+#[test]
+fn test_hkdf_sha256_basic() {
+    let env = Env::default();
+
+    // Test case 1.
+    let okm = env.crypto().hkdf_sha256(
+        &bytes!(&env, 0x000102030405060708090a0b0c),
+        &Bytes::repeat(&env, 0x0b, 22),
+        &bytes!(&env, 0xf0f1f2f3f4f5f6f7f8f9),
+        42,
+    );
+    assert_eq!(
+        okm,
+        bytes!(
+            &env,
+            0x3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865
+        )
+    );
+}
+
+#[test]
+fn test_hkdf_sha256_longer_inputs_and_outputs() {
+    let env = Env::default();
+
+    // Test case 2.
+    let okm = env.crypto().hkdf_sha256(
+        &bytes_of(&env, 0x60..=0xaf),
+        &bytes_of(&env, 0x00..=0x4f),
+        &bytes_of(&env, 0xb0..=0xff),
+        82,
+    );
+    assert_eq!(
+        okm,
+        bytes!(
+            &env,
+            0xb11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87
+        )
+    );
+}
+
+#[test]
+fn test_hkdf_sha256_empty_salt_and_info() {
+    let env = Env::default();
+
+    // Test case 3.
+    let ikm = Bytes::repeat(&env, 0x0b, 22);
+    let expect = bytes!(
+        &env,
+        0x8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8
+    );
+    let okm = env
+        .crypto()
+        .hkdf_sha256(&Bytes::new(&env), &ikm, &Bytes::new(&env), 42);
+    assert_eq!(okm, expect);
+
+    // An empty salt is the same as a salt of 32 zero bytes.
+    let okm = env
+        .crypto()
+        .hkdf_sha256(&Bytes::repeat(&env, 0, 32), &ikm, &Bytes::new(&env), 42);
+    assert_eq!(okm, expect);
+}
+
+#[test]
+fn test_hkdf_sha256_lengths() {
+    let env = Env::default();
+    let crypto = env.crypto();
+    let salt = Bytes::from_slice(&env, b"salt");
+    let ikm = Bytes::from_slice(&env, b"ikm");
+    let info = Bytes::from_slice(&env, b"info");
+
+    // Shorter outputs are prefixes of longer outputs.
+    let max = crypto.hkdf_sha256(&salt, &ikm, &info, 8160);
+    assert_eq!(max.len(), 8160);
+    for length in [0, 1, 31, 32, 33, 64, 100] {
+        assert_eq!(
+            crypto.hkdf_sha256(&salt, &ikm, &info, length),
+            max.slice(..length)
+        );
+    }
+
+    // Different info derives different keys.
+    let other = Bytes::from_slice(&env, b"other");
+    assert_ne!(crypto.hkdf_sha256(&salt, &ikm, &other, 32), max.slice(..32));
+}
+
+#[test]
+#[should_panic(expected = "hkdf_sha256 length is greater than 8160")]
+fn test_hkdf_sha256_length_too_long() {
+    let env = Env::default();
+    let b = Bytes::new(&env);
+    env.crypto().hkdf_sha256(&b, &b, &b, 8161);
+}
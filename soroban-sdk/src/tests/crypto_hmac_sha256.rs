@@ -0,0 +1,111 @@
+use crate::{bytes, bytesn, Bytes, BytesN, Env};
+
+// Test vectors from RFC 4231.
+
+#[test]
+fn test_hmac_sha256_rfc4231() {
+    let env = Env::default();
+    let b = |s: &[u8]| Bytes::from_slice(&env, s);
+
+    let cases: [(Bytes, Bytes, BytesN<32>); 5] = [
+        // Test case 1.
+        (
+            Bytes::repeat(&env, 0x0b, 20),
+            b(b"Hi There"),
+            bytesn!(
+                &env,
+                0xb0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7
+            ),
+        ),
+        // Test case 2, a key shorter than the output.
+        (
+            b(b"Jefe"),
+            b(b"what do ya want for nothing?"),
+            bytesn!(
+                &env,
+                0x5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843
+            ),
+        ),
+        // Test case 3, combined length of key and data larger than 64 bytes.
+        (
+            Bytes::repeat(&env, 0xaa, 20),
+            Bytes::repeat(&env, 0xdd, 50),
+            bytesn!(
+                &env,
+                0x773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe
+            ),
+        ),
+        // Test case 4.
+        (
+            bytes!(&env, 0x0102030405060708090a0b0c0d0e0f10111213141516171819),
+            Bytes::repeat(&env, 0xcd, 50),
+            bytesn!(
+                &env,
+                0x82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b
+            ),
+        ),
+        // Test case 6, a key larger than the block size, which is hashed.
+        (
+            Bytes::repeat(&env, 0xaa, 131),
+            b(b"Test Using Larger Than Block-Size Key - Hash Key First"),
+            bytesn!(
+                &env,
+                0x60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54
+            ),
+        ),
+    ];
+    for (i, (key, msg, expect)) in cases.iter().enumerate() {
+        assert_eq!(&env.crypto().hmac_sha256(key, msg), expect, "{i}");
+    }
+}
+
+#[test]
+fn test_hmac_sha256_rfc4231_large_key_and_data() {
+    let env = Env::default();
+
+    // Test case 7.
+    let key = Bytes::repeat(&env, 0xaa, 131);
+    let msg = Bytes::from_slice(
+        &env,
+        b"This is a test using a larger than block-size key and a larger than \
+        block-size data. The key needs to be hashed before being used by the \
+        HMAC algorithm.",
+    );
+    assert_eq!(
+        env.crypto().hmac_sha256(&key, &msg),
+        bytesn!(
+            &env,
+            0x9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2
+        )
+    );
+}
+
+#[test]
+fn test_hmac_sha256_rfc4231_truncated() {
+    let env = Env::default();
+
+    // Test case 5, where the output is truncated to 128 bits.
+    let mac = env.crypto().hmac_sha256(
+        &Bytes::repeat(&env, 0x0c, 20),
+        &Bytes::from_slice(&env, b"Test With Truncation"),
+    );
+    assert_eq!(
+        Bytes::from(mac).slice(..16),
+        bytes!(&env, 0xa3b6167473100ee06e0c796c2955552b)
+    );
+}
+
+#[test]
+fn test_hmac_sha256_key_of_block_size() {
+    let env = Env::default();
+
+    // A key of exactly the block size is used as is, rather than hashed, so
+    // differs from using the hash of the key.
+    let key = Bytes::repeat(&env, 0xaa, 64);
+    let hashed_key: Bytes = env.crypto().sha256(&key).into();
+    let msg = Bytes::from_slice(&env, b"msg");
+    assert_ne!(
+        env.crypto().hmac_sha256(&key, &msg),
+        env.crypto().hmac_sha256(&hashed_key, &msg)
+    );
+}
@@ -1,4 +1,4 @@
-use crate::{bytesn, BytesN, Env, IntoVal};
+use crate::{bytesn, Bytes, BytesN, Env, IntoVal};
 
 #[test]
 fn test_keccak256() {
@@ -12,3 +12,37 @@ fn test_keccak256() {
     let hash: BytesN<32> = env.crypto().keccak256(&bytes).into();
     assert_eq!(hash, expect);
 }
+
+#[test]
+fn test_keccak256_incremental() {
+    let env = Env::default();
+
+    let mut hasher = env.crypto().keccak256_init();
+    hasher.update(&Bytes::from_slice(&env, b"test "));
+    hasher.update(&Bytes::new(&env));
+    hasher.update(&Bytes::from_slice(&env, b"vector for "));
+    hasher.update(&Bytes::from_slice(&env, b"soroban"));
+    let hash: BytesN<32> = hasher.finalize().into();
+
+    let expect = bytesn!(
+        &env,
+        0x352fe2eaddf44eb02eb3eab1f8d6ff4ba426df4f1734b1e3f210d621ee8853d9
+    );
+    assert_eq!(hash, expect);
+}
+
+#[test]
+fn test_keccak256_incremental_empty() {
+    let env = Env::default();
+
+    let hash: BytesN<32> = env.crypto().keccak256_init().finalize().into();
+    let expect = bytesn!(
+        &env,
+        0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470
+    );
+    assert_eq!(hash, expect);
+    assert_eq!(
+        hash,
+        BytesN::from(env.crypto().keccak256(&Bytes::new(&env)))
+    );
+}
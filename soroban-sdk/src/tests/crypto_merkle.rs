@@ -0,0 +1,176 @@
+use crate::{crypto::merkle, vec, BytesN, Env};
+
+// A tree of four leaves, `[1; 32]` to `[4; 32]`, with the expected hashes
+// computed independently.
+const LEAF_HASHES: [&str; 4] = [
+    "dcffe786ded16d283c663846ad0c4ff26558fccde36ca9d30b2ea19eade9fc0e",
+    "cba8c596120bdb69debbd923d92cba948bde7c7d06a465a1bb7d98d3116038fa",
+    "acaa04663a8547a2f70c60cc18f9378796b13c4f9a08f70d6adae662365b30c6",
+    "1da033bf8927ed69376d91533748494f7f5e88c20603dede2afc9bfd43d46f17",
+];
+const NODE_01: &str = "3a066e0f40c6a1981ebfa60d2411625d0517ae22c2fc8c7c1784ff8a75c78565";
+const NODE_23: &str = "67863aa3208102d3a5084f0a23cc6bc72f128adc32b1ba3b953db0b49dac6968";
+const ROOT: &str = "3b3c0ce45d11517a54300a196b61497c4165150d72b7782a4548e3984da771b2";
+
+fn leaf(env: &Env, i: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[i + 1; 32])
+}
+
+fn bytes(env: &Env, hex: &str) -> BytesN<32> {
+    BytesN::from_array(env, &hex::decode(hex).unwrap().try_into().unwrap())
+}
+
+#[test]
+fn test_hashes() {
+    let env = Env::default();
+
+    for i in 0..4 {
+        assert_eq!(
+            merkle::leaf_hash(&env, &leaf(&env, i)),
+            bytes(&env, LEAF_HASHES[i as usize])
+        );
+    }
+    assert_eq!(
+        merkle::node_hash(
+            &env,
+            &bytes(&env, LEAF_HASHES[0]),
+            &bytes(&env, LEAF_HASHES[1])
+        ),
+        bytes(&env, NODE_01)
+    );
+    assert_eq!(
+        merkle::node_hash(&env, &bytes(&env, NODE_01), &bytes(&env, NODE_23)),
+        bytes(&env, ROOT)
+    );
+}
+
+#[test]
+fn test_verify_proof() {
+    let env = Env::default();
+    let root = bytes(&env, ROOT);
+
+    let proofs = [
+        vec![&env, bytes(&env, LEAF_HASHES[1]), bytes(&env, NODE_23)],
+        vec![&env, bytes(&env, LEAF_HASHES[0]), bytes(&env, NODE_23)],
+        vec![&env, bytes(&env, LEAF_HASHES[3]), bytes(&env, NODE_01)],
+        vec![&env, bytes(&env, LEAF_HASHES[2]), bytes(&env, NODE_01)],
+    ];
+    for (i, proof) in proofs.iter().enumerate() {
+        let i = i as u32;
+        assert!(merkle::verify_proof(
+            &env,
+            &root,
+            &leaf(&env, i as u8),
+            proof,
+            i
+        ));
+
+        // Every other index and leaf is rejected with the same proof.
+        for j in 0..4 {
+            if j != i {
+                assert!(!merkle::verify_proof(
+                    &env,
+                    &root,
+                    &leaf(&env, i as u8),
+                    proof,
+                    j
+                ));
+                assert!(!merkle::verify_proof(
+                    &env,
+                    &root,
+                    &leaf(&env, j as u8),
+                    proof,
+                    i
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_verify_tampered_proof() {
+    let env = Env::default();
+    let root = bytes(&env, ROOT);
+    let leaf2 = leaf(&env, 2);
+
+    let mut proof = vec![&env, bytes(&env, LEAF_HASHES[3]), bytes(&env, NODE_01)];
+    assert!(merkle::verify_proof(&env, &root, &leaf2, &proof, 2));
+
+    let mut tampered = bytes(&env, NODE_01).to_array();
+    tampered[0] ^= 1;
+    proof.set(1, BytesN::from_array(&env, &tampered));
+    assert!(!merkle::verify_proof(&env, &root, &leaf2, &proof, 2));
+
+    // A truncated or extended proof is rejected.
+    let proof = vec![&env, bytes(&env, LEAF_HASHES[3])];
+    assert!(!merkle::verify_proof(&env, &root, &leaf2, &proof, 2));
+    let proof = vec![
+        &env,
+        bytes(&env, LEAF_HASHES[3]),
+        bytes(&env, NODE_01),
+        bytes(&env, NODE_01),
+    ];
+    assert!(!merkle::verify_proof(&env, &root, &leaf2, &proof, 2));
+}
+
+#[test]
+fn test_verify_rejects_interior_node_as_leaf() {
+    let env = Env::default();
+    let root = bytes(&env, ROOT);
+
+    // Without domain separation, the hash of the left subtree could be passed
+    // off as a leaf with a proof one level shorter.
+    let proof = vec![&env, bytes(&env, NODE_23)];
+    assert!(!merkle::verify_proof(
+        &env,
+        &root,
+        &bytes(&env, NODE_01),
+        &proof,
+        0
+    ));
+}
+
+#[test]
+fn test_verify_index_out_of_range() {
+    let env = Env::default();
+    let root = bytes(&env, ROOT);
+
+    // Index 4 has the same low bits as index 0, but does not fit in the tree.
+    let proof = vec![&env, bytes(&env, LEAF_HASHES[1]), bytes(&env, NODE_23)];
+    assert!(merkle::verify_proof(&env, &root, &leaf(&env, 0), &proof, 0));
+    assert!(!merkle::verify_proof(
+        &env,
+        &root,
+        &leaf(&env, 0),
+        &proof,
+        4
+    ));
+}
+
+#[test]
+fn test_verify_single_leaf_tree() {
+    let env = Env::default();
+    let root = merkle::leaf_hash(&env, &leaf(&env, 0));
+
+    assert!(merkle::verify_proof(
+        &env,
+        &root,
+        &leaf(&env, 0),
+        &vec![&env],
+        0
+    ));
+    assert!(!merkle::verify_proof(
+        &env,
+        &root,
+        &leaf(&env, 1),
+        &vec![&env],
+        0
+    ));
+    assert!(!merkle::verify_proof(
+        &env,
+        &root,
+        &leaf(&env, 0),
+        &vec![&env],
+        1
+    ));
+}
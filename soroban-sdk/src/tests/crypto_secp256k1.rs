@@ -24,3 +24,42 @@ fn test_recover_key_ecdsa_secp256k1() {
         expected_public_key
     );
 }
+
+#[test]
+fn test_recover_eth_address_secp256k1() {
+    let env = Env::default();
+
+    // Same vector as above, with the address derived from the public key.
+    let message_digest = Hash::from_bytes(bytesn!(
+        &env,
+        0xce0677bb30baa8cf067c88db9811f4333d131bf8bcf12fe7065d211dce971008
+    ));
+    let signature = bytesn!(
+        &env,
+        0x90f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e549984a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93
+    );
+    let recovery_id = 1;
+    let expected_address = bytesn!(&env, 0xa19d069d48d2e9392ec2bb41ecab0a72119d633b);
+    assert_eq!(
+        env.crypto()
+            .secp256k1_recover_eth_address(&message_digest, &signature, recovery_id),
+        expected_address
+    );
+}
+
+#[test]
+fn test_eth_address_secp256k1() {
+    let env = Env::default();
+
+    // The generator point is the public key of private key 1, whose address is
+    // well known.
+    let public_key = bytesn!(
+        &env,
+        0x0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8
+    );
+    let expected_address = bytesn!(&env, 0x7e5f4552091a69125d5dfcb7b8c2659029395bdf);
+    assert_eq!(
+        env.crypto().secp256k1_eth_address(&public_key),
+        expected_address
+    );
+}
@@ -0,0 +1,98 @@
+use crate::{bytesn, Bytes, BytesN, Env};
+
+// Test vectors from the NIST SHA-3 example values and the SHA3-256 short and
+// long message test suites (FIPS 202).
+
+#[test]
+fn test_sha3_256_empty() {
+    let env = Env::default();
+
+    let hash: BytesN<32> = env.crypto().sha3_256(&Bytes::new(&env)).into();
+    assert_eq!(
+        hash,
+        bytesn!(
+            &env,
+            0xa7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a
+        )
+    );
+}
+
+#[test]
+fn test_sha3_256_abc() {
+    let env = Env::default();
+
+    let hash: BytesN<32> = env
+        .crypto()
+        .sha3_256(&Bytes::from_slice(&env, b"abc"))
+        .into();
+    assert_eq!(
+        hash,
+        bytesn!(
+            &env,
+            0x3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532
+        )
+    );
+}
+
+#[test]
+fn test_sha3_256_two_blocks() {
+    let env = Env::default();
+
+    let data = Bytes::from_slice(
+        &env,
+        b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+    );
+    let hash: BytesN<32> = env.crypto().sha3_256(&data).into();
+    assert_eq!(
+        hash,
+        bytesn!(
+            &env,
+            0x916f6061fe879741ca6469b43971dfdb28b1a32dc36cb3254e812be27aad1d18
+        )
+    );
+}
+
+#[test]
+fn test_sha3_256_rate_boundaries() {
+    let env = Env::default();
+
+    // Inputs on and around the 136 byte rate exercise padding into a new
+    // block, and 200 bytes of 0xa3 is the NIST 1600-bit message.
+    for (len, expect) in [
+        (
+            135,
+            bytesn!(
+                &env,
+                0xd51927265ca4bf0cc8b4453387700918c03f8894e395ad437d4573f3be4d2c34
+            ),
+        ),
+        (
+            136,
+            bytesn!(
+                &env,
+                0x0adf6bfb359ae40019b67d8c49c361574b70242a6b752de6f9e0d426ca177f7a
+            ),
+        ),
+        (
+            200,
+            bytesn!(
+                &env,
+                0x79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787
+            ),
+        ),
+    ] {
+        let data = Bytes::from_slice(&env, &[0xa3; 200][..len]);
+        let hash: BytesN<32> = env.crypto().sha3_256(&data).into();
+        assert_eq!(hash, expect);
+    }
+}
+
+#[test]
+fn test_sha3_256_differs_from_keccak256() {
+    let env = Env::default();
+
+    let data = Bytes::new(&env);
+    let sha3: BytesN<32> = env.crypto().sha3_256(&data).into();
+    let keccak: BytesN<32> = env.crypto().keccak256(&data).into();
+    assert_ne!(sha3, keccak);
+}
@@ -0,0 +1,68 @@
+use crate::{
+    self as soroban_sdk, contract, symbol_short, testutils::Events as _, vec, Env, IntoVal, Val,
+    Vec,
+};
+
+#[contract]
+pub struct Contract;
+
+#[test]
+fn test_builder_matches_tuple_publish() {
+    let env = Env::default();
+    let id = env.register(Contract, ());
+
+    env.as_contract(&id, || {
+        let mut event = env.events().builder();
+        event
+            .topic(symbol_short!("a"))
+            .topic(1u32)
+            .topic(2i64)
+            .topic(true);
+        assert_eq!(event.topics_len(), 4);
+        event.publish_data(5i128);
+
+        #[allow(deprecated)]
+        env.events()
+            .publish((symbol_short!("a"), 1u32, 2i64, true), 5i128);
+    });
+
+    let expected = (
+        id,
+        (symbol_short!("a"), 1u32, 2i64, true).into_val(&env),
+        5i128.into_val(&env),
+    );
+    assert_eq!(env.events().all(), vec![&env, expected.clone(), expected]);
+}
+
+#[test]
+fn test_builder_no_topics() {
+    let env = Env::default();
+    let id = env.register(Contract, ());
+
+    env.as_contract(&id, || {
+        env.events().builder().publish_data(());
+
+        #[allow(deprecated)]
+        env.events().publish((), ());
+    });
+
+    let expected = (id, Vec::<Val>::new(&env), ().into_val(&env));
+    assert_eq!(env.events().all(), vec![&env, expected.clone(), expected]);
+}
+
+#[test]
+#[should_panic(expected = "event topics are limited to 4")]
+fn test_builder_too_many_topics() {
+    let env = Env::default();
+    let id = env.register(Contract, ());
+
+    env.as_contract(&id, || {
+        env.events()
+            .builder()
+            .topic(1u32)
+            .topic(2u32)
+            .topic(3u32)
+            .topic(4u32)
+            .topic(5u32);
+    });
+}
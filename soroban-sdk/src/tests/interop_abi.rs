@@ -0,0 +1,137 @@
+use crate::{
+    interop::abi::{self, Decoder, Encoder},
+    Bytes, BytesN, Env, U256,
+};
+
+// The calldata of `transfer(0x5B38Da6a701c568545dCfcB03FcB875f56beddC4, 1000)`.
+const TRANSFER: &str = concat!(
+    "a9059cbb",
+    "0000000000000000000000005b38da6a701c568545dcfcb03fcb875f56beddc4",
+    "00000000000000000000000000000000000000000000000000000000000003e8",
+);
+
+// The encoding of `("dave", true, 1)` as `(bytes,bool,uint256)`.
+const DYNAMIC: &str = concat!(
+    "0000000000000000000000000000000000000000000000000000000000000060",
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "0000000000000000000000000000000000000000000000000000000000000001",
+    "0000000000000000000000000000000000000000000000000000000000000004",
+    "6461766500000000000000000000000000000000000000000000000000000000",
+);
+
+// The encoding of `("", [0xab; 40])` as `(bytes,bytes)`.
+const TWO_DYNAMIC: &str = concat!(
+    "0000000000000000000000000000000000000000000000000000000000000040",
+    "0000000000000000000000000000000000000000000000000000000000000060",
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "0000000000000000000000000000000000000000000000000000000000000028",
+    "abababababababababababababababababababababababababababababababab",
+    "abababababababab000000000000000000000000000000000000000000000000",
+);
+
+fn bytes(env: &Env, hex: &str) -> Bytes {
+    Bytes::from_slice(env, &hex::decode(hex).unwrap())
+}
+
+fn recipient(env: &Env) -> BytesN<20> {
+    let address = hex::decode("5b38da6a701c568545dcfcb03fcb875f56beddc4").unwrap();
+    BytesN::from_array(env, &address.try_into().unwrap())
+}
+
+#[test]
+fn test_selector() {
+    let env = Env::default();
+    assert_eq!(
+        abi::selector(&env, "transfer(address,uint256)"),
+        BytesN::from_array(&env, &[0xa9, 0x05, 0x9c, 0xbb])
+    );
+    assert_eq!(
+        abi::selector(&env, "balanceOf(address)"),
+        BytesN::from_array(&env, &[0x70, 0xa0, 0x82, 0x31])
+    );
+}
+
+#[test]
+fn test_encode_transfer() {
+    let env = Env::default();
+    let calldata = Encoder::new_call(&env, &abi::selector(&env, "transfer(address,uint256)"))
+        .address(&recipient(&env))
+        .uint(&U256::from_u32(&env, 1000))
+        .build();
+    assert_eq!(calldata, bytes(&env, TRANSFER));
+}
+
+#[test]
+fn test_decode_transfer() {
+    let env = Env::default();
+    let transfer = abi::selector(&env, "transfer(address,uint256)");
+    let decoder = Decoder::new_call(&bytes(&env, TRANSFER), &transfer).unwrap();
+    assert_eq!(decoder.address(0), Ok(recipient(&env)));
+    assert_eq!(decoder.uint(1), Ok(U256::from_u32(&env, 1000)));
+    assert!(decoder.uint(2).is_err());
+
+    let approve = abi::selector(&env, "approve(address,uint256)");
+    assert!(Decoder::new_call(&bytes(&env, TRANSFER), &approve).is_err());
+    assert!(Decoder::new_call(&bytes(&env, "a905"), &transfer).is_err());
+}
+
+#[test]
+fn test_encode_decode_dynamic() {
+    let env = Env::default();
+    let dave = Bytes::from_slice(&env, b"dave");
+    let encoded = Encoder::new(&env)
+        .bytes(&dave)
+        .bool(true)
+        .uint(&U256::from_u32(&env, 1))
+        .build();
+    assert_eq!(encoded, bytes(&env, DYNAMIC));
+
+    let decoder = Decoder::new(&encoded);
+    assert_eq!(decoder.bytes(0), Ok(dave));
+    assert_eq!(decoder.bool(1), Ok(true));
+    assert_eq!(decoder.uint(2), Ok(U256::from_u32(&env, 1)));
+
+    let long = Bytes::from_array(&env, &[0xab; 40]);
+    let encoded = Encoder::new(&env)
+        .bytes(&Bytes::new(&env))
+        .bytes(&long)
+        .build();
+    assert_eq!(encoded, bytes(&env, TWO_DYNAMIC));
+
+    let decoder = Decoder::new(&encoded);
+    assert_eq!(decoder.bytes(0), Ok(Bytes::new(&env)));
+    assert_eq!(decoder.bytes(1), Ok(long));
+}
+
+#[test]
+fn test_decode_malformed() {
+    let env = Env::default();
+    let word = |hex: &str| std::format!("{hex:0>64}");
+
+    // An address with non-zero padding.
+    let decoder = Decoder::new(&bytes(
+        &env,
+        &word("015b38da6a701c568545dcfcb03fcb875f56beddc4"),
+    ));
+    assert!(decoder.address(0).is_err());
+
+    // A bool other than 0 or 1.
+    let decoder = Decoder::new(&bytes(&env, &word("2")));
+    assert!(decoder.bool(0).is_err());
+
+    // An offset beyond the end of the data.
+    let decoder = Decoder::new(&bytes(&env, &word("40")));
+    assert!(decoder.bytes(0).is_err());
+
+    // A length beyond the end of the data.
+    let decoder = Decoder::new(&bytes(&env, &(word("20") + &word("21"))));
+    assert!(decoder.bytes(0).is_err());
+
+    // An offset that does not fit in a u32.
+    let decoder = Decoder::new(&bytes(&env, &word("100000020")));
+    assert!(decoder.bytes(0).is_err());
+
+    // Data truncated in the middle of the length.
+    let truncated = &DYNAMIC[..2 * (3 * 32 + 16)];
+    assert!(Decoder::new(&bytes(&env, truncated)).bytes(0).is_err());
+}
@@ -0,0 +1,149 @@
+use crate::{
+    interop::rlp::{self, RlpItem},
+    Bytes, BytesN, Env,
+};
+
+// The signed transaction from the EIP-155 example.
+const SIGNED_TX: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+fn bytes(env: &Env, hex: &str) -> Bytes {
+    Bytes::from_slice(env, &hex::decode(hex).unwrap())
+}
+
+#[test]
+fn test_decode_transaction() {
+    let env = Env::default();
+    let item = rlp::decode(&bytes(&env, SIGNED_TX)).unwrap();
+    let tx = item.as_list().unwrap();
+    assert_eq!(tx.len(), 9);
+
+    let fields: std::vec::Vec<RlpItem> = tx.iter().collect();
+    assert_eq!(fields[0].to_u64(), Ok(9));
+    assert_eq!(fields[1].to_u64(), Ok(20_000_000_000));
+    assert_eq!(fields[2].to_u64(), Ok(21_000));
+    assert_eq!(
+        fields[3].to_address(),
+        Ok(BytesN::from_array(&env, &[0x35; 20]))
+    );
+    assert_eq!(fields[4].to_u64(), Ok(1_000_000_000_000_000_000));
+    assert_eq!(fields[5].as_bytes(), Ok(&Bytes::new(&env)));
+    assert_eq!(fields[6].to_u64(), Ok(37));
+    assert_eq!(
+        fields[7].as_bytes(),
+        Ok(&bytes(
+            &env,
+            "28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276"
+        ))
+    );
+    assert_eq!(fields[8].as_bytes().unwrap().len(), 32);
+    assert_eq!(tx.get(8).as_ref(), fields.get(8));
+    assert_eq!(tx.get(9), None);
+
+    // The fields are not lists, and the transaction is not a byte string.
+    assert!(fields[0].as_list().is_err());
+    assert!(item.as_bytes().is_err());
+    assert!(fields[7].to_u64().is_err());
+}
+
+#[test]
+fn test_decode_nested_lists() {
+    let env = Env::default();
+    // The set theoretical representation of three, [ [], [[]], [ [], [[]] ] ].
+    let item = rlp::decode(&bytes(&env, "c7c0c1c0c3c0c1c0")).unwrap();
+    let list = item.as_list().unwrap();
+    assert_eq!(list.len(), 3);
+    assert!(list.get(0).unwrap().as_list().unwrap().is_empty());
+    let third = list.get(2).unwrap();
+    let third = third.as_list().unwrap();
+    assert_eq!(third.len(), 2);
+    assert_eq!(third.get(1).unwrap().as_list().unwrap().len(), 1);
+}
+
+#[test]
+fn test_decode_byte_strings() {
+    let env = Env::default();
+    let decode = |hex: &str| rlp::decode(&bytes(&env, hex));
+
+    assert_eq!(decode("00"), Ok(RlpItem::Bytes(bytes(&env, "00"))));
+    assert_eq!(decode("7f"), Ok(RlpItem::Bytes(bytes(&env, "7f"))));
+    assert_eq!(decode("8180"), Ok(RlpItem::Bytes(bytes(&env, "80"))));
+    assert_eq!(decode("80"), Ok(RlpItem::Bytes(Bytes::new(&env))));
+    assert_eq!(decode("80").unwrap().to_u64(), Ok(0));
+
+    // A string of 56 bytes is the shortest to use the long form.
+    let long = [b'a'; 56];
+    let mut encoded = std::vec![0xb8, 56];
+    encoded.extend_from_slice(&long);
+    assert_eq!(
+        rlp::decode(&Bytes::from_slice(&env, &encoded)),
+        Ok(RlpItem::Bytes(Bytes::from_slice(&env, &long)))
+    );
+}
+
+#[test]
+fn test_decode_malformed() {
+    let env = Env::default();
+    let decode = |hex: &str| rlp::decode(&bytes(&env, hex));
+
+    // Empty input.
+    assert!(decode("").is_err());
+    // Truncated transaction.
+    assert!(decode(&SIGNED_TX[..SIGNED_TX.len() - 2]).is_err());
+    // Trailing bytes.
+    assert!(decode(&std::format!("{SIGNED_TX}00")).is_err());
+    // A string longer than the list that contains it.
+    assert!(decode("c5c283646f67").is_err());
+    // A single byte below 0x80 encoded as a string.
+    assert!(decode("8105").is_err());
+    // A short length encoded in the long form.
+    assert!(decode("b805").is_err());
+    // A long form length with a leading zero.
+    assert!(decode(&std::format!("b90038{}", "61".repeat(56))).is_err());
+    // Malformed items nested in a list.
+    assert!(decode("c3c28105").is_err());
+}
+
+// Returns the encoding of empty lists nested `depth` levels deep.
+fn nested_lists(depth: u32) -> std::vec::Vec<u8> {
+    let mut encoded = std::vec![0xc0];
+    for _ in 1..depth {
+        let len = u8::try_from(encoded.len()).unwrap();
+        let mut prefix = if len < 56 {
+            std::vec![0xc0 + len]
+        } else {
+            std::vec![0xf8, len]
+        };
+        prefix.extend(encoded);
+        encoded = prefix;
+    }
+    encoded
+}
+
+#[test]
+fn test_decode_max_depth() {
+    let env = Env::default();
+
+    let mut item = rlp::decode(&Bytes::from_slice(&env, &nested_lists(rlp::MAX_DEPTH))).unwrap();
+    for _ in 1..rlp::MAX_DEPTH {
+        item = item.as_list().unwrap().get(0).unwrap();
+    }
+    assert!(item.as_list().unwrap().is_empty());
+
+    let too_deep = Bytes::from_slice(&env, &nested_lists(rlp::MAX_DEPTH + 1));
+    assert!(rlp::decode(&too_deep).is_err());
+}
+
+#[test]
+fn test_to_u64_rejects_non_canonical() {
+    let env = Env::default();
+    let decode = |hex: &str| rlp::decode(&bytes(&env, hex)).unwrap();
+
+    assert_eq!(decode("88ffffffffffffffff").to_u64(), Ok(u64::MAX));
+    // Too long.
+    assert!(decode("89010000000000000000").to_u64().is_err());
+    // Leading zero.
+    assert!(decode("820001").to_u64().is_err());
+    assert!(decode("00").to_u64().is_err());
+    // Wrong length for an address.
+    assert!(decode("8135").to_address().is_err());
+}
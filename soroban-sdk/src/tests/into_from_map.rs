@@ -0,0 +1,122 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contracttype, map, symbol_short, testutils::Address as _, Address, ConversionError, Env,
+    FromMap, IntoMap, IntoVal, Map, Symbol, TryFromVal, TryIntoVal, Val,
+};
+
+#[derive(IntoMap, FromMap, Clone, Debug, PartialEq)]
+pub struct Config {
+    pub fee_bps: u32,
+    pub admin: Address,
+    pub a_field_name_longer_than_nine: Option<i128>,
+}
+
+#[test]
+fn test_into_map() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let config = Config {
+        fee_bps: 30,
+        admin: admin.clone(),
+        a_field_name_longer_than_nine: Some(-1),
+    };
+
+    let map: Map<Symbol, Val> = config.into_val(&env);
+    let expected: Map<Symbol, Val> = map![
+        &env,
+        (symbol_short!("fee_bps"), 30u32.into_val(&env)),
+        (symbol_short!("admin"), admin.into_val(&env)),
+        (
+            Symbol::new(&env, "a_field_name_longer_than_nine"),
+            Some(-1i128).into_val(&env)
+        ),
+    ];
+    assert_eq!(map, expected);
+}
+
+#[test]
+fn test_roundtrip() {
+    let env = Env::default();
+    let config = Config {
+        fee_bps: 30,
+        admin: Address::generate(&env),
+        a_field_name_longer_than_nine: None,
+    };
+
+    let map: Map<Symbol, Val> = (&config).into_val(&env);
+    assert_eq!(map.len(), 3);
+    let back: Config = map.try_into_val(&env).unwrap();
+    assert_eq!(back, config);
+}
+
+#[test]
+fn test_from_map_ignores_other_keys() {
+    let env = Env::default();
+    let config = Config {
+        fee_bps: 1,
+        admin: Address::generate(&env),
+        a_field_name_longer_than_nine: None,
+    };
+
+    let mut map: Map<Symbol, Val> = config.clone().into_val(&env);
+    map.set(symbol_short!("version"), 2u32.into_val(&env));
+    assert_eq!(Config::try_from_val(&env, &map), Ok(config));
+}
+
+#[test]
+fn test_from_map_missing_key() {
+    let env = Env::default();
+    let config = Config {
+        fee_bps: 1,
+        admin: Address::generate(&env),
+        a_field_name_longer_than_nine: None,
+    };
+
+    let mut map: Map<Symbol, Val> = config.into_val(&env);
+    map.remove(symbol_short!("admin"));
+    assert_eq!(Config::try_from_val(&env, &map), Err(ConversionError));
+}
+
+#[test]
+fn test_from_map_wrong_type() {
+    let env = Env::default();
+    let config = Config {
+        fee_bps: 1,
+        admin: Address::generate(&env),
+        a_field_name_longer_than_nine: None,
+    };
+
+    let mut map: Map<Symbol, Val> = config.into_val(&env);
+    map.set(
+        symbol_short!("fee_bps"),
+        symbol_short!("one").into_val(&env),
+    );
+    assert_eq!(Config::try_from_val(&env, &map), Err(ConversionError));
+}
+
+#[contracttype]
+#[derive(IntoMap, FromMap, Clone, Debug, PartialEq)]
+pub struct Stored {
+    pub a: u32,
+    pub b: bool,
+}
+
+#[test]
+fn test_same_map_as_contracttype() {
+    let env = Env::default();
+    let stored = Stored { a: 7, b: true };
+
+    // A contract type struct is stored as the same map, so either can be
+    // read as the other.
+    let map: Map<Symbol, Val> = stored.clone().into_val(&env);
+    let val: Val = stored.clone().into_val(&env);
+    assert_eq!(
+        Map::<Symbol, Val>::try_from_val(&env, &val),
+        Ok(map.clone())
+    );
+    assert_eq!(
+        Stored::try_from_val(&env, &map.to_val()),
+        Ok(stored.clone())
+    );
+    assert_eq!(Stored::try_from_val(&env, &map), Ok(stored));
+}
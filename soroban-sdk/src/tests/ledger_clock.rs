@@ -0,0 +1,66 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl,
+    ledger::{Clock, EnvClock},
+    testutils::{FixedClock, Ledger as _},
+    Env,
+};
+
+// Returns the number of seconds remaining until the deadline, or zero if the
+// deadline has passed.
+fn remaining(clock: &dyn Clock, deadline: u64) -> u64 {
+    deadline.saturating_sub(clock.timestamp())
+}
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn remaining(env: Env, deadline: u64) -> u64 {
+        remaining(&EnvClock::new(&env), deadline)
+    }
+}
+
+#[test]
+fn test_deadline_with_fixed_clock() {
+    assert_eq!(remaining(&FixedClock::new(900, 1), 1_000), 100);
+    assert_eq!(remaining(&FixedClock::new(1_000, 1), 1_000), 0);
+    assert_eq!(remaining(&FixedClock::new(1_100, 1), 1_000), 0);
+}
+
+#[test]
+fn test_fixed_clock() {
+    let clock = FixedClock::new(1_000, 42);
+    assert_eq!(clock.timestamp(), 1_000);
+    assert_eq!(clock.sequence(), 42);
+    assert_eq!(FixedClock::default(), FixedClock::new(0, 0));
+}
+
+#[test]
+fn test_env_clock() {
+    let env = Env::default();
+    env.ledger().set_timestamp(900);
+    env.ledger().set_sequence_number(42);
+
+    let clock = EnvClock::new(&env);
+    assert_eq!(clock.timestamp(), 900);
+    assert_eq!(clock.sequence(), 42);
+
+    // The clock reads the current ledger each time.
+    env.ledger().set_timestamp(950);
+    assert_eq!(clock.timestamp(), 950);
+}
+
+#[test]
+fn test_deadline_in_contract_matches_fixed_clock() {
+    let env = Env::default();
+    env.ledger().set_timestamp(900);
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.remaining(&1_000),
+        remaining(&FixedClock::new(900, 1), 1_000)
+    );
+}
@@ -0,0 +1,46 @@
+use crate::{self as soroban_sdk, testutils::Ledger as _};
+use soroban_sdk::Env;
+
+#[test]
+fn test_timestamp_plus() {
+    let e = Env::default();
+    e.ledger().set_timestamp(1000);
+
+    assert_eq!(e.ledger().timestamp_plus(0), 1000);
+    assert_eq!(e.ledger().timestamp_plus(500), 1500);
+    assert_eq!(e.ledger().timestamp_plus(u64::MAX - 1000), u64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "ledger timestamp overflow")]
+fn test_timestamp_plus_overflow() {
+    let e = Env::default();
+    e.ledger().set_timestamp(u64::MAX - 10);
+
+    e.ledger().timestamp_plus(11);
+}
+
+#[test]
+fn test_is_after_is_before() {
+    let e = Env::default();
+    e.ledger().set_timestamp(1000);
+
+    assert!(e.ledger().is_after(999));
+    assert!(!e.ledger().is_after(1000));
+    assert!(!e.ledger().is_after(1001));
+
+    assert!(e.ledger().is_before(1001));
+    assert!(!e.ledger().is_before(1000));
+    assert!(!e.ledger().is_before(999));
+}
+
+#[test]
+fn test_is_after_is_before_at_max() {
+    let e = Env::default();
+    e.ledger().set_timestamp(u64::MAX);
+
+    assert!(e.ledger().is_after(u64::MAX - 1));
+    assert!(!e.ledger().is_after(u64::MAX));
+    assert!(!e.ledger().is_before(u64::MAX));
+    assert_eq!(e.ledger().timestamp_plus(0), u64::MAX);
+}
@@ -0,0 +1,90 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl, log, log_fields, map, symbol_short,
+    testutils::{Address as _, Logs as _},
+    Address, Env, IntoVal, Map, Symbol, Val,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        log_fields!(&env, "transfer", from = from, to = to, amount = amount);
+    }
+}
+
+#[test]
+fn test_fields_logged_in_contract() {
+    let env = Env::default();
+    let client = ContractClient::new(&env, &env.register(Contract, ()));
+
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.transfer(&from, &to, &100);
+
+    let all = env.logs().all_fields();
+    assert_eq!(all.len(), 1);
+    let (msg, fields) = &all[0];
+    assert_eq!(msg.as_str(), "transfer");
+    assert_eq!(fields.len(), 3);
+    let get = |name| fields.get(Symbol::new(&env, name)).unwrap();
+    let logged_from: Address = get("from").into_val(&env);
+    let logged_to: Address = get("to").into_val(&env);
+    let logged_amount: i128 = get("amount").into_val(&env);
+    assert_eq!(logged_from, from);
+    assert_eq!(logged_to, to);
+    assert_eq!(logged_amount, 100);
+}
+
+#[test]
+fn test_fields_logged_in_order() {
+    let env = Env::default();
+
+    log_fields!(&env, "first", n = 1u32);
+    log_fields!(&env, "empty");
+    log_fields!(&env, "second", n = 2u32, flag = true,);
+
+    let all = env.logs().all_fields();
+    let expected: [(&str, Map<Symbol, Val>); 3] = [
+        (
+            "first",
+            map![&env, (symbol_short!("n"), 1u32.into_val(&env))],
+        ),
+        ("empty", Map::new(&env)),
+        (
+            "second",
+            map![
+                &env,
+                (symbol_short!("n"), 2u32.into_val(&env)),
+                (symbol_short!("flag"), true.into_val(&env)),
+            ],
+        ),
+    ];
+    assert_eq!(all.len(), expected.len());
+    for ((msg, fields), (expected_msg, expected_fields)) in all.iter().zip(expected) {
+        assert_eq!(msg.as_str(), expected_msg);
+        assert_eq!(fields, &expected_fields);
+    }
+
+    // Each entry is also in the log as a string.
+    assert_eq!(env.logs().all().len(), 3);
+}
+
+#[test]
+fn test_other_logs_have_no_fields() {
+    let env = Env::default();
+
+    log!(&env, "no values");
+    log!(&env, "values", 1u32, symbol_short!("a"));
+    log!(&env, "a map with other keys", map![&env, (1u32, 2u32)]);
+    log!(
+        &env,
+        "a map with symbol keys",
+        map![&env, (symbol_short!("n"), 1u32)]
+    );
+
+    assert!(env.logs().all_fields().is_empty());
+    assert_eq!(env.logs().all().len(), 4);
+}
@@ -0,0 +1,177 @@
+use crate as soroban_sdk;
+use soroban_sdk::{contract, contractimpl, migration::Migration, symbol_short, Env};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    /// Migrates from storing the count as a u32 to a u64.
+    pub fn migrate(env: Env) -> bool {
+        Migration::run_if_needed(&env, 0, 1, |env| {
+            let storage = env.storage().instance();
+            let count: u32 = storage.get(&symbol_short!("COUNT")).unwrap_or(0);
+            storage.set(&symbol_short!("COUNT"), &u64::from(count));
+        })
+    }
+
+    pub fn count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("COUNT"))
+            .unwrap()
+    }
+}
+
+#[test]
+fn test_migrate() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    e.as_contract(&contract_id, || {
+        assert_eq!(Migration::schema_version(&e), 0);
+        e.storage().instance().set(&symbol_short!("COUNT"), &7u32);
+    });
+
+    assert!(client.migrate());
+    assert_eq!(client.count(), 7);
+    e.as_contract(&contract_id, || {
+        assert_eq!(Migration::schema_version(&e), 1);
+    });
+}
+
+#[test]
+fn test_migrate_repeated_is_noop() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    assert!(client.migrate());
+    assert!(!client.migrate());
+    assert_eq!(client.count(), 0);
+
+    e.as_contract(&contract_id, || {
+        let mut runs = 0;
+        assert!(!Migration::run_if_needed(&e, 0, 1, |_| runs += 1));
+        assert_eq!(runs, 0);
+        assert_eq!(Migration::schema_version(&e), 1);
+    });
+}
+
+#[test]
+fn test_migrations_in_sequence() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    e.as_contract(&contract_id, || {
+        let mut runs = std::vec::Vec::new();
+        assert!(Migration::run_if_needed(&e, 0, 1, |_| runs.push(1)));
+        assert!(Migration::run_if_needed(&e, 1, 3, |_| runs.push(3)));
+        assert!(!Migration::run_if_needed(&e, 1, 3, |_| runs.push(3)));
+        assert_eq!(runs, [1, 3]);
+        assert_eq!(Migration::schema_version(&e), 3);
+    });
+}
+
+#[test]
+#[should_panic(expected = "unexpected schema version")]
+fn test_migrate_skipped_version_panics() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    e.as_contract(&contract_id, || {
+        // The migration from 0 to 1 has not been run.
+        Migration::run_if_needed(&e, 1, 2, |_| {});
+    });
+}
+
+#[test]
+fn test_migrate_from_later_version_is_noop() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    e.as_contract(&contract_id, || {
+        Migration::set_schema_version(&e, 3);
+        let mut runs = 0;
+        assert!(!Migration::run_if_needed(&e, 1, 2, |_| runs += 1));
+        assert_eq!(runs, 0);
+        assert_eq!(Migration::schema_version(&e), 3);
+    });
+}
+
+#[test]
+#[should_panic(expected = "unexpected schema version")]
+fn test_migrate_from_intermediate_version_panics() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    e.as_contract(&contract_id, || {
+        Migration::set_schema_version(&e, 2);
+        Migration::run_if_needed(&e, 1, 3, |_| {});
+    });
+}
+
+#[test]
+fn test_migration_chain_rerun_is_noop() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    let run_chain = |runs: &mut std::vec::Vec<u32>| {
+        e.as_contract(&contract_id, || {
+            Migration::run_if_needed(&e, 0, 1, |_| runs.push(1));
+            Migration::run_if_needed(&e, 1, 2, |_| runs.push(2));
+            Migration::run_if_needed(&e, 2, 3, |_| runs.push(3));
+        })
+    };
+
+    let mut runs = std::vec::Vec::new();
+    run_chain(&mut runs);
+    assert_eq!(runs, [1, 2, 3]);
+
+    // Running the whole chain again, such as after the next upgrade, runs
+    // nothing.
+    run_chain(&mut runs);
+    assert_eq!(runs, [1, 2, 3]);
+
+    // Contracts deployed with the latest schema run nothing either.
+    let contract_id = e.register(Contract, ());
+    e.as_contract(&contract_id, || {
+        Migration::set_schema_version(&e, 3);
+        let mut runs = 0;
+        Migration::run_if_needed(&e, 0, 1, |_| runs += 1);
+        Migration::run_if_needed(&e, 1, 2, |_| runs += 1);
+        Migration::run_if_needed(&e, 2, 3, |_| runs += 1);
+        assert_eq!(runs, 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "migration must be to a greater schema version")]
+fn test_migrate_backwards_panics() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+
+    e.as_contract(&contract_id, || {
+        Migration::run_if_needed(&e, 1, 1, |_| {});
+    });
+}
+
+#[test]
+fn test_failed_migration_is_not_recorded() {
+    let e = Env::default();
+    let contract_id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &contract_id);
+
+    // The stored count is not a u32, so the migration fails.
+    e.as_contract(&contract_id, || {
+        e.storage()
+            .instance()
+            .set(&symbol_short!("COUNT"), &symbol_short!("x"));
+    });
+    assert!(client.try_migrate().is_err());
+
+    e.as_contract(&contract_id, || {
+        assert_eq!(Migration::schema_version(&e), 0);
+    });
+}
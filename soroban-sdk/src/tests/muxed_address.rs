@@ -19,6 +19,10 @@ impl MuxedAddressContract {
     ) -> (Option<u64>, Option<u64>) {
         (a.id(), b.id())
     }
+
+    pub fn balance_key(_e: Env, to: MuxedAddress) -> Address {
+        to.into()
+    }
 }
 
 #[test]
@@ -108,3 +112,41 @@ fn test_accept_muxed_address_argument_in_contract() {
         (None, Some(2))
     );
 }
+
+#[test]
+fn test_muxed_address_into_address_strips_id() {
+    let env = Env::default();
+    let muxed_address = MuxedAddress::new(MuxedAddress::generate(&env), 123);
+    assert_eq!(muxed_address.id(), Some(123));
+
+    let address: Address = (&muxed_address).into();
+    assert_eq!(address, muxed_address.address());
+    let address: Address = muxed_address.clone().into();
+    assert_eq!(address, muxed_address.address());
+
+    // Converting back gives a muxed address without an id.
+    let unmuxed: MuxedAddress = address.clone().into();
+    assert_eq!(unmuxed.id(), None);
+    assert_eq!(unmuxed.address(), address);
+    assert_ne!(unmuxed, muxed_address);
+
+    // A regular address converts to itself.
+    let regular = Address::generate(&env);
+    let converted: Address = MuxedAddress::from(&regular).into();
+    assert_eq!(converted, regular);
+}
+
+#[test]
+fn test_muxed_address_into_address_in_contract() {
+    let env = Env::default();
+    let contract_id = env.register(MuxedAddressContract, ());
+    let client = MuxedAddressContractClient::new(&env, &contract_id);
+
+    let muxed_address = MuxedAddress::new(MuxedAddress::generate(&env), 7);
+    let other_id = MuxedAddress::new(muxed_address.address(), 8);
+    let address = muxed_address.address();
+
+    assert_eq!(client.balance_key(&muxed_address), address);
+    assert_eq!(client.balance_key(&other_id), address);
+    assert_eq!(client.balance_key(&address.clone().into()), address);
+}
@@ -0,0 +1,191 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    ownable::Ownable,
+    symbol_short,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Address, Env, IntoVal,
+};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn __constructor(env: Env, owner: Address) {
+        Ownable::set_owner(&env, &owner);
+    }
+
+    pub fn owner(env: Env) -> Option<Address> {
+        Ownable::owner(&env)
+    }
+
+    pub fn pending_owner(env: Env) -> Option<Address> {
+        Ownable::pending_owner(&env)
+    }
+
+    pub fn transfer_ownership(env: Env, new_owner: Address) {
+        Ownable::transfer_ownership(&env, &new_owner);
+    }
+
+    pub fn accept_ownership(env: Env) {
+        Ownable::accept_ownership(&env);
+    }
+
+    pub fn only_owner(env: Env) -> Address {
+        Ownable::require_owner(&env)
+    }
+}
+
+#[test]
+fn test_transfer_is_pending_until_accepted() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+    let client = ContractClient::new(&e, &e.register(Contract, (&owner,)));
+
+    assert_eq!(client.owner(), Some(owner.clone()));
+    assert_eq!(client.pending_owner(), None);
+
+    client.transfer_ownership(&new_owner);
+    assert_eq!(e.auths()[0].0, owner);
+
+    // The current owner remains the owner until the transfer is accepted.
+    assert_eq!(client.owner(), Some(owner.clone()));
+    assert_eq!(client.pending_owner(), Some(new_owner.clone()));
+    assert_eq!(client.only_owner(), owner);
+}
+
+#[test]
+fn test_successful_handoff() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+    let client = ContractClient::new(&e, &e.register(Contract, (&owner,)));
+
+    client.transfer_ownership(&new_owner);
+    client.accept_ownership();
+    assert_eq!(e.auths()[0].0, new_owner);
+
+    assert_eq!(client.owner(), Some(new_owner.clone()));
+    assert_eq!(client.pending_owner(), None);
+    assert_eq!(client.only_owner(), new_owner);
+    assert_eq!(e.auths()[0].0, new_owner);
+}
+
+#[test]
+fn test_wrong_acceptor_rejected() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+    let wrong = Address::generate(&e);
+    let contract_id = e.register(Contract, (&owner,));
+    let client = ContractClient::new(&e, &contract_id);
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "transfer_ownership",
+                args: (&new_owner,).into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .transfer_ownership(&new_owner);
+
+    // Only the pending owner can accept.
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &wrong,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "accept_ownership",
+                args: ().into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .try_accept_ownership();
+    assert!(res.is_err());
+    assert_eq!(client.owner(), Some(owner.clone()));
+    assert_eq!(client.pending_owner(), Some(new_owner.clone()));
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &new_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "accept_ownership",
+                args: ().into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .accept_ownership();
+    assert_eq!(client.owner(), Some(new_owner));
+}
+
+#[test]
+fn test_non_owner_cannot_transfer() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let attacker = Address::generate(&e);
+    let contract_id = e.register(Contract, (&owner,));
+    let client = ContractClient::new(&e, &contract_id);
+
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "transfer_ownership",
+                args: (&attacker,).into_val(&e),
+                sub_invokes: &[],
+            },
+        }])
+        .try_transfer_ownership(&attacker);
+    assert!(res.is_err());
+    assert_eq!(client.pending_owner(), None);
+}
+
+#[test]
+#[should_panic(expected = "no pending owner")]
+fn test_accept_without_transfer_panics() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let client = ContractClient::new(&e, &e.register(Contract, (&owner,)));
+
+    client.accept_ownership();
+}
+
+#[test]
+#[should_panic(expected = "owner already set")]
+fn test_set_owner_twice_panics() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let contract_id = e.register(Contract, (&owner,));
+
+    e.as_contract(&contract_id, || Ownable::set_owner(&e, &owner));
+}
+
+#[contracttype]
+pub enum DataKey {
+    Owner,
+}
+
+#[test]
+fn test_owner_key_does_not_collide_with_contract_keys() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+    let id = e.register(Contract, (&owner,));
+
+    e.as_contract(&id, || {
+        let storage = e.storage().instance();
+        storage.set(&symbol_short!("OWNER"), &other);
+        storage.set(&DataKey::Owner, &other);
+        assert_eq!(Ownable::owner(&e), Some(owner.clone()));
+    });
+}
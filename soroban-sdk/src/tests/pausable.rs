@@ -0,0 +1,122 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl, pausable::Pausable, symbol_short, testutils::Events as _, vec, Env,
+    IntoVal,
+};
+
+#[contract]
+pub struct Counter;
+
+#[contractimpl]
+impl Counter {
+    pub fn pause(env: Env) {
+        Pausable::pause(&env);
+    }
+
+    pub fn unpause(env: Env) {
+        Pausable::unpause(&env);
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        Pausable::is_paused(&env)
+    }
+
+    pub fn increment(env: Env) -> u32 {
+        Pausable::when_not_paused(&env);
+        let count = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("count"))
+            .unwrap_or(0u32)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&symbol_short!("count"), &count);
+        count
+    }
+}
+
+#[test]
+fn test_pause_blocks_guarded_call_and_unpause_restores() {
+    let e = Env::default();
+    let client = CounterClient::new(&e, &e.register(Counter, ()));
+
+    assert!(!client.is_paused());
+    assert_eq!(client.increment(), 1);
+
+    client.pause();
+    assert!(client.is_paused());
+    assert!(client.try_increment().is_err());
+
+    client.unpause();
+    assert!(!client.is_paused());
+    assert_eq!(client.increment(), 2);
+}
+
+#[test]
+#[should_panic(expected = "contract is paused")]
+fn test_guarded_call_panics_while_paused() {
+    let e = Env::default();
+    let client = CounterClient::new(&e, &e.register(Counter, ()));
+
+    client.pause();
+    client.increment();
+}
+
+#[test]
+#[should_panic(expected = "contract is paused")]
+fn test_pause_twice_panics() {
+    let e = Env::default();
+    let client = CounterClient::new(&e, &e.register(Counter, ()));
+
+    client.pause();
+    client.pause();
+}
+
+#[test]
+#[should_panic(expected = "contract is not paused")]
+fn test_unpause_when_not_paused_panics() {
+    let e = Env::default();
+    let client = CounterClient::new(&e, &e.register(Counter, ()));
+
+    client.unpause();
+}
+
+#[test]
+fn test_pause_and_unpause_events() {
+    let e = Env::default();
+    let id = e.register(Counter, ());
+    let client = CounterClient::new(&e, &id);
+
+    // Returns the last event published.
+    let last_event = || {
+        let events = e.events().all();
+        events.slice(events.len() - 1..)
+    };
+
+    client.pause();
+    assert_eq!(
+        last_event(),
+        vec![
+            &e,
+            (
+                id.clone(),
+                (symbol_short!("paused"),).into_val(&e),
+                ().into_val(&e)
+            )
+        ]
+    );
+
+    client.unpause();
+    assert_eq!(
+        last_event(),
+        vec![
+            &e,
+            (
+                id.clone(),
+                (symbol_short!("unpaused"),).into_val(&e),
+                ().into_val(&e)
+            )
+        ]
+    );
+}
@@ -391,3 +391,156 @@ fn useful_error_message_for_access_outside_contract_gen_len() {
     let e = Env::default();
     let _: Bytes = e.prng().gen_len(32);
 }
+
+// Returns PRNG values from a few invocations of the contract.
+fn prng_values(e: &Env, id: &crate::Address) -> std::vec::Vec<u64> {
+    let mut values = std::vec::Vec::new();
+    for _ in 0..3 {
+        e.as_contract(id, || {
+            for _ in 0..3 {
+                values.push(e.prng().gen_range::<u64>(0..=1_000_000));
+            }
+        });
+    }
+    values
+}
+
+#[test]
+fn test_set_prng_seed_same_seed_same_values() {
+    let e1 = Env::default();
+    let id1 = e1.register(TestPrngContract, ());
+    e1.set_prng_seed(BytesN::from_array(&e1, &[7; 32]));
+
+    let e2 = Env::default();
+    let id2 = e2.register(TestPrngContract, ());
+    e2.set_prng_seed(BytesN::from_array(&e2, &[7; 32]));
+
+    let e3 = Env::default();
+    let id3 = e3.register(TestPrngContract, ());
+    e3.set_prng_seed(BytesN::from_array(&e3, &[8; 32]));
+
+    let values = prng_values(&e1, &id1);
+    assert_eq!(values, prng_values(&e2, &id2));
+    assert_ne!(values, prng_values(&e3, &id3));
+}
+
+#[test]
+fn test_prng_snapshot_does_not_change_values() {
+    let seed = [7; 32];
+
+    let e = Env::default();
+    let id = e.register(TestPrngContract, ());
+    e.set_prng_seed(BytesN::from_array(&e, &seed));
+    let mut without_snapshot = prng_values(&e, &id);
+    without_snapshot.extend(prng_values(&e, &id));
+
+    let e = Env::default();
+    let id = e.register(TestPrngContract, ());
+    e.set_prng_seed(BytesN::from_array(&e, &seed));
+    let mut with_snapshot = prng_values(&e, &id);
+    let snapshot = e.prng_snapshot();
+    assert_eq!(e.prng_snapshot(), snapshot);
+    with_snapshot.extend(prng_values(&e, &id));
+
+    assert_eq!(with_snapshot, without_snapshot);
+}
+
+#[test]
+fn test_prng_snapshot_restore() {
+    let e = Env::default();
+    let id = e.register(TestPrngContract, ());
+    e.set_prng_seed(BytesN::from_array(&e, &[7; 32]));
+    prng_values(&e, &id);
+
+    let snapshot = e.prng_snapshot();
+    assert_eq!(snapshot.seed(), [7; 32]);
+    assert_eq!(snapshot.invocations(), 3);
+    let values = prng_values(&e, &id);
+    assert_ne!(values, prng_values(&e, &id));
+
+    e.restore_prng(&snapshot);
+    assert_eq!(values, prng_values(&e, &id));
+}
+
+#[test]
+#[should_panic(expected = "prng_snapshot requires a seed set with set_prng_seed")]
+fn test_prng_snapshot_without_seed_panics() {
+    let e = Env::default();
+    e.prng_snapshot();
+}
+
+#[test]
+fn test_prng_shuffle_preserves_items() {
+    let e = Env::default();
+    let id = e.register(TestPrngContract, ());
+
+    e.as_contract(&id, || {
+        let mut v = Vec::<u32>::from_iter(&e, 0..20);
+        e.prng().shuffle(&mut v);
+        assert_ne!(v, Vec::<u32>::from_iter(&e, 0..20));
+        let mut items: std::vec::Vec<u32> = v.iter().collect();
+        items.sort();
+        assert_eq!(items, (0..20).collect::<std::vec::Vec<u32>>());
+    });
+}
+
+#[test]
+fn test_prng_shuffle_deterministic_with_seed() {
+    let shuffled = || {
+        let e = Env::default();
+        let id = e.register(TestPrngContract, ());
+        e.set_prng_seed(BytesN::from_array(&e, &[3; 32]));
+        e.as_contract(&id, || {
+            let mut v = Vec::<u32>::from_iter(&e, 0..20);
+            e.prng().shuffle(&mut v);
+            v.iter().collect::<std::vec::Vec<u32>>()
+        })
+    };
+    assert_eq!(shuffled(), shuffled());
+}
+
+#[test]
+fn test_prng_sample() {
+    let e = Env::default();
+    let id = e.register(TestPrngContract, ());
+
+    e.as_contract(&id, || {
+        let v = Vec::<u32>::from_iter(&e, 0..20);
+
+        let s = e.prng().sample(&v, 5);
+        assert_eq!(s.len(), 5);
+        let mut items: std::vec::Vec<u32> = s.iter().collect();
+        items.sort();
+        items.dedup();
+        assert_eq!(items.len(), 5);
+        assert!(items.iter().all(|i| v.contains(i)));
+        // The input is not modified.
+        assert_eq!(v, Vec::<u32>::from_iter(&e, 0..20));
+
+        assert_eq!(e.prng().sample(&v, 0), Vec::<u32>::new(&e));
+
+        let all = e.prng().sample(&v, 20);
+        let mut items: std::vec::Vec<u32> = all.iter().collect();
+        items.sort();
+        assert_eq!(items, (0..20).collect::<std::vec::Vec<u32>>());
+    });
+}
+
+#[test]
+#[should_panic(expected = "sample size is greater than the vec length")]
+fn test_prng_sample_panics_when_too_large() {
+    let e = Env::default();
+    let id = e.register(TestPrngContract, ());
+
+    e.as_contract(&id, || {
+        let v = vec![&e, 1u32, 2, 3];
+        e.prng().sample(&v, 4);
+    });
+}
+
+#[test]
+#[should_panic(expected = "`env.as_contract()`")]
+fn useful_error_message_for_access_outside_contract_sample() {
+    let e = Env::default();
+    e.prng().sample(&Vec::<u64>::new(&e), 0);
+}
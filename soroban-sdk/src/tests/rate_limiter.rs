@@ -0,0 +1,89 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl,
+    rate_limiter::RateLimiter,
+    storage::{ReservedKey, SdkKey},
+    testutils::{storage::Temporary as _, Address as _, Ledger as _},
+    Address, Env,
+};
+
+#[contract]
+pub struct Faucet;
+
+#[contractimpl]
+impl Faucet {
+    pub fn claim(env: Env, to: Address) {
+        RateLimiter::check_and_record(&env, &to, 60);
+    }
+}
+
+#[test]
+fn test_first_call_allowed() {
+    let e = Env::default();
+    e.ledger().set_timestamp(1000);
+    let client = FaucetClient::new(&e, &e.register(Faucet, ()));
+
+    client.claim(&Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "rate limited")]
+fn test_call_within_interval_panics() {
+    let e = Env::default();
+    e.ledger().set_timestamp(1000);
+    let client = FaucetClient::new(&e, &e.register(Faucet, ()));
+    let to = Address::generate(&e);
+
+    client.claim(&to);
+    e.ledger().set_timestamp(1059);
+    client.claim(&to);
+}
+
+#[test]
+fn test_call_after_interval_allowed() {
+    let e = Env::default();
+    e.ledger().set_timestamp(1000);
+    let client = FaucetClient::new(&e, &e.register(Faucet, ()));
+    let to = Address::generate(&e);
+
+    client.claim(&to);
+    assert!(client.try_claim(&to).is_err());
+
+    // Allowed once exactly the interval has passed.
+    e.ledger().set_timestamp(1060);
+    client.claim(&to);
+
+    // The interval restarts from the last allowed call.
+    e.ledger().set_timestamp(1119);
+    assert!(client.try_claim(&to).is_err());
+    e.ledger().set_timestamp(1120);
+    client.claim(&to);
+}
+
+#[test]
+fn test_addresses_limited_independently() {
+    let e = Env::default();
+    e.ledger().set_timestamp(1000);
+    let client = FaucetClient::new(&e, &e.register(Faucet, ()));
+    let a = Address::generate(&e);
+    let b = Address::generate(&e);
+
+    client.claim(&a);
+    client.claim(&b);
+    assert!(client.try_claim(&a).is_err());
+    assert!(client.try_claim(&b).is_err());
+}
+
+#[test]
+fn test_record_kept_for_interval() {
+    let e = Env::default();
+    e.ledger().set_min_temp_entry_ttl(10);
+    let id = e.register(Faucet, ());
+    let to = Address::generate(&e);
+
+    e.as_contract(&id, || {
+        RateLimiter::check_and_record(&e, &to, 600);
+        let key = ReservedKey::from(SdkKey::RateLimit(to.clone()));
+        assert_eq!(e.storage().temporary().get_ttl(&key), 600);
+    });
+}
@@ -0,0 +1,122 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract, contractimpl, reentrancy::ReentrancyGuard, Address, Env, IntoVal, Symbol,
+};
+
+#[contract]
+pub struct Vault;
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env, callee: Option<Address>) -> bool {
+        let _guard = ReentrancyGuard::new(&env);
+        if let Some(callee) = callee {
+            env.invoke_contract::<()>(
+                &callee,
+                &Symbol::new(&env, "on_withdraw"),
+                (env.current_contract_address(),).into_val(&env),
+            );
+        }
+        ReentrancyGuard::is_entered(&env)
+    }
+
+    pub fn withdraw_twice(env: Env) -> bool {
+        let _guard = ReentrancyGuard::new(&env);
+        Self::withdraw(env.clone(), None)
+    }
+
+    pub fn unguarded_withdraw(env: Env, callee: Address) {
+        env.invoke_contract::<()>(
+            &callee,
+            &Symbol::new(&env, "on_withdraw"),
+            (env.current_contract_address(),).into_val(&env),
+        );
+    }
+}
+
+#[contract]
+pub struct Malicious;
+
+#[contractimpl]
+impl Malicious {
+    pub fn on_withdraw(env: Env, vault: Address) {
+        VaultClient::new(&env, &vault).withdraw(&None);
+    }
+}
+
+#[contract]
+pub struct Honest;
+
+#[contractimpl]
+impl Honest {
+    pub fn on_withdraw(_env: Env, _vault: Address) {}
+}
+
+#[test]
+fn test_guard_released_after_call() {
+    let e = Env::default();
+    let vault_id = e.register(Vault, ());
+    let honest_id = e.register(Honest, ());
+    let client = VaultClient::new(&e, &vault_id);
+
+    assert!(client.withdraw(&None));
+    assert!(client.withdraw(&Some(honest_id)));
+
+    e.as_contract(&vault_id, || {
+        assert!(!ReentrancyGuard::is_entered(&e));
+    });
+}
+
+#[test]
+fn test_reentrant_callee_rejected_by_host() {
+    let e = Env::default();
+    let vault_id = e.register(Vault, ());
+    let malicious_id = e.register(Malicious, ());
+    let client = VaultClient::new(&e, &vault_id);
+
+    assert!(client.try_withdraw(&Some(malicious_id.clone())).is_err());
+
+    // The host rejects the re-entry whether or not a guard is held.
+    assert!(client.try_unguarded_withdraw(&malicious_id).is_err());
+
+    // The failed call leaves the vault unlocked.
+    assert!(client.withdraw(&None));
+}
+
+#[test]
+fn test_guarded_function_calling_guarded_function_rejected() {
+    let e = Env::default();
+    let vault_id = e.register(Vault, ());
+    let client = VaultClient::new(&e, &vault_id);
+
+    assert!(client.try_withdraw_twice().is_err());
+
+    // The failed call leaves the vault unlocked.
+    assert!(client.withdraw(&None));
+}
+
+#[test]
+fn test_guard_drop_clears_flag() {
+    let e = Env::default();
+    let vault_id = e.register(Vault, ());
+
+    e.as_contract(&vault_id, || {
+        let guard = ReentrancyGuard::new(&e);
+        assert!(ReentrancyGuard::is_entered(&e));
+        drop(guard);
+        assert!(!ReentrancyGuard::is_entered(&e));
+        let _guard = ReentrancyGuard::new(&e);
+    });
+}
+
+#[test]
+#[should_panic(expected = "reentrant call")]
+fn test_guard_held_twice_panics() {
+    let e = Env::default();
+    let vault_id = e.register(Vault, ());
+
+    e.as_contract(&vault_id, || {
+        let _guard = ReentrancyGuard::new(&e);
+        let _reentered = ReentrancyGuard::new(&e);
+    });
+}
@@ -0,0 +1,187 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    contract,
+    crypto::merkle,
+    sparse_merkle::{self, SparseMerkle, DEPTH, EMPTY_LEAF, EMPTY_ROOT},
+    Address, BytesN, Env, Vec,
+};
+
+#[contract]
+pub struct Contract;
+
+// The root of the tree with `KEY_1` and `KEY_2` inserted, computed
+// independently.
+const ROOT_2: &str = "ad9c16dc99b2c58d09f0128b15cd9ccb410385cfcc6dc0c0797a8d9ac01a6b52";
+
+const KEY_1: [u8; 32] = {
+    let mut k = [0; 32];
+    k[31] = 1;
+    k
+};
+const KEY_2: [u8; 32] = {
+    let mut k = [0; 32];
+    k[0] = 0x80;
+    k
+};
+const KEY_3: [u8; 32] = {
+    let mut k = [0; 32];
+    k[0] = 0x40;
+    k
+};
+
+fn bit(key: &[u8; 32], i: usize) -> u8 {
+    (key[31 - i / 8] >> (i % 8)) & 1
+}
+
+// A minimal off-chain tree, that computes proofs from all the key-value pairs.
+struct Tree {
+    env: Env,
+    defaults: std::vec::Vec<BytesN<32>>,
+    leaves: std::vec::Vec<([u8; 32], [u8; 32])>,
+}
+
+impl Tree {
+    fn new(env: &Env) -> Self {
+        let mut defaults = std::vec![BytesN::from_array(env, &EMPTY_LEAF)];
+        for h in 0..DEPTH as usize {
+            defaults.push(merkle::node_hash(env, &defaults[h], &defaults[h]));
+        }
+        Tree {
+            env: env.clone(),
+            defaults,
+            leaves: std::vec::Vec::new(),
+        }
+    }
+
+    fn insert(&mut self, key: [u8; 32], value: [u8; 32]) {
+        self.leaves.push((key, value));
+    }
+
+    fn node(&self, height: usize, leaves: &[([u8; 32], [u8; 32])]) -> BytesN<32> {
+        match leaves {
+            [] => self.defaults[height].clone(),
+            [(k, v)] if height == 0 => sparse_merkle::leaf_hash(
+                &self.env,
+                &BytesN::from_array(&self.env, k),
+                &BytesN::from_array(&self.env, v),
+            ),
+            _ => {
+                let (left, right): (std::vec::Vec<_>, std::vec::Vec<_>) =
+                    leaves.iter().partition(|(k, _)| bit(k, height - 1) == 0);
+                merkle::node_hash(
+                    &self.env,
+                    &self.node(height - 1, &left),
+                    &self.node(height - 1, &right),
+                )
+            }
+        }
+    }
+
+    fn proof(&self, key: &[u8; 32]) -> Vec<BytesN<32>> {
+        let mut proof = Vec::new(&self.env);
+        for i in 0..DEPTH as usize {
+            let sibling: std::vec::Vec<_> = self
+                .leaves
+                .iter()
+                .filter(|(k, _)| {
+                    bit(k, i) != bit(key, i) && (i + 1..256).all(|j| bit(k, j) == bit(key, j))
+                })
+                .cloned()
+                .collect();
+            proof.push_back(self.node(i, &sibling));
+        }
+        proof
+    }
+}
+
+fn setup() -> (Env, Address) {
+    let e = Env::default();
+    // Each proof takes hundreds of hashes to compute and to verify.
+    e.cost_estimate().budget().reset_unlimited();
+    let id = e.register(Contract, ());
+    (e, id)
+}
+
+#[test]
+fn test_empty_root() {
+    let (e, id) = setup();
+    let tree = Tree::new(&e);
+    assert_eq!(tree.defaults[DEPTH as usize].to_array(), EMPTY_ROOT);
+    e.as_contract(&id, || {
+        assert_eq!(SparseMerkle::root(&e).to_array(), EMPTY_ROOT);
+        assert!(SparseMerkle::verify_exclusion(
+            &e,
+            &BytesN::from_array(&e, &KEY_1),
+            &tree.proof(&KEY_1)
+        ));
+    });
+}
+
+#[test]
+fn test_insert_two_and_prove_exclusion_of_third() {
+    let (e, id) = setup();
+    let mut tree = Tree::new(&e);
+    let [key_1, key_2, key_3] = [KEY_1, KEY_2, KEY_3].map(|k| BytesN::from_array(&e, &k));
+    let value_1 = BytesN::from_array(&e, &[0xaa; 32]);
+    let value_2 = BytesN::from_array(&e, &[0xbb; 32]);
+
+    e.as_contract(&id, || {
+        let root = SparseMerkle::insert(&e, &key_1, &value_1, &tree.proof(&KEY_1));
+        tree.insert(KEY_1, value_1.to_array());
+        assert_eq!(root, tree.node(DEPTH as usize, &tree.leaves));
+
+        let root = SparseMerkle::insert(&e, &key_2, &value_2, &tree.proof(&KEY_2));
+        tree.insert(KEY_2, value_2.to_array());
+        assert_eq!(root, tree.node(DEPTH as usize, &tree.leaves));
+        assert_eq!(root.to_array().to_vec(), hex::decode(ROOT_2).unwrap());
+        assert_eq!(SparseMerkle::root(&e), root);
+
+        let proof_1 = tree.proof(&KEY_1);
+        let proof_2 = tree.proof(&KEY_2);
+        let proof_3 = tree.proof(&KEY_3);
+
+        assert!(SparseMerkle::verify_inclusion(
+            &e, &key_1, &value_1, &proof_1
+        ));
+        assert!(SparseMerkle::verify_inclusion(
+            &e, &key_2, &value_2, &proof_2
+        ));
+        assert!(SparseMerkle::verify_exclusion(&e, &key_3, &proof_3));
+
+        // The proofs do not prove anything else.
+        assert!(!SparseMerkle::verify_exclusion(&e, &key_1, &proof_1));
+        assert!(!SparseMerkle::verify_inclusion(
+            &e, &key_1, &value_2, &proof_1
+        ));
+        assert!(!SparseMerkle::verify_inclusion(
+            &e, &key_3, &value_1, &proof_3
+        ));
+        assert!(!SparseMerkle::verify_exclusion(&e, &key_3, &proof_1));
+    });
+}
+
+#[test]
+fn test_proof_of_wrong_length() {
+    let (e, id) = setup();
+    let tree = Tree::new(&e);
+    let key = BytesN::from_array(&e, &KEY_1);
+    let mut proof = tree.proof(&KEY_1);
+    proof.pop_back();
+    e.as_contract(&id, || {
+        assert!(!SparseMerkle::verify_exclusion(&e, &key, &proof));
+    });
+}
+
+#[test]
+#[should_panic(expected = "invalid exclusion proof")]
+fn test_insert_existing_key() {
+    let (e, id) = setup();
+    let mut tree = Tree::new(&e);
+    let key = BytesN::from_array(&e, &KEY_1);
+    let value = BytesN::from_array(&e, &[0xaa; 32]);
+    e.as_contract(&id, || {
+        SparseMerkle::insert(&e, &key, &value, &tree.proof(&KEY_1));
+        tree.insert(KEY_1, value.to_array());
+        SparseMerkle::insert(&e, &key, &value, &tree.proof(&KEY_1));
+    });
+}
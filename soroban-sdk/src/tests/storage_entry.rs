@@ -0,0 +1,60 @@
+use crate::testutils::storage::{Persistent as _, Temporary as _};
+use crate::{self as soroban_sdk};
+use soroban_sdk::{contract, symbol_short, Env, Symbol};
+
+#[contract]
+pub struct Contract;
+
+#[test]
+fn test_persistent_entry() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+
+    e.as_contract(&id, || {
+        let entry = e
+            .storage()
+            .persistent()
+            .entry::<Symbol, u64>(symbol_short!("a"));
+        assert_eq!(entry.key(), &symbol_short!("a"));
+        assert!(!entry.has());
+        assert_eq!(entry.get(), None);
+
+        entry.set(&5);
+        assert!(entry.has());
+        assert_eq!(entry.get(), Some(5));
+        assert_eq!(
+            e.storage().persistent().get::<_, u64>(&symbol_short!("a")),
+            Some(5)
+        );
+
+        entry.extend_ttl(5000, 10000);
+        assert_eq!(e.storage().persistent().get_ttl(&symbol_short!("a")), 10000);
+
+        entry.remove();
+        assert!(!entry.has());
+        assert_eq!(entry.get(), None);
+        assert!(!e.storage().persistent().has(&symbol_short!("a")));
+    });
+}
+
+#[test]
+fn test_temporary_entry() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+
+    e.as_contract(&id, || {
+        let entry = e.storage().temporary().entry::<u32, bool>(1);
+        assert!(!entry.has());
+
+        entry.set(&true);
+        assert_eq!(entry.get(), Some(true));
+        // Temporary and persistent entries with the same key are distinct.
+        assert!(!e.storage().persistent().has(&1u32));
+
+        entry.extend_ttl(5000, 10000);
+        assert_eq!(e.storage().temporary().get_ttl(&1u32), 10000);
+
+        entry.remove();
+        assert_eq!(entry.get(), None);
+    });
+}
@@ -0,0 +1,66 @@
+use crate::{self as soroban_sdk};
+use soroban_sdk::{contract, symbol_short, vec, Env, Symbol, Vec};
+
+#[contract]
+pub struct Contract;
+
+#[test]
+fn test_persistent_get_set_many() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+
+    e.as_contract(&id, || {
+        let storage = e.storage().persistent();
+        storage.set_many(&vec![
+            &e,
+            (symbol_short!("a"), 1u32),
+            (symbol_short!("c"), 3u32),
+        ]);
+        assert_eq!(storage.get::<_, u32>(&symbol_short!("a")), Some(1));
+        assert_eq!(storage.get::<_, u32>(&symbol_short!("c")), Some(3));
+
+        let keys = vec![
+            &e,
+            symbol_short!("c"),
+            symbol_short!("b"),
+            symbol_short!("a"),
+            symbol_short!("d"),
+        ];
+        assert_eq!(
+            storage.get_many::<Symbol, u32>(&keys),
+            vec![&e, Some(3), None, Some(1), None]
+        );
+
+        assert_eq!(storage.get_many::<Symbol, u32>(&Vec::new(&e)), Vec::new(&e));
+    });
+}
+
+#[test]
+fn test_set_many_duplicate_keys_last_wins() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+
+    e.as_contract(&id, || {
+        let storage = e.storage().temporary();
+        storage.set_many(&vec![&e, (1u32, 10i64), (1u32, 20i64)]);
+        assert_eq!(
+            storage.get_many::<u32, i64>(&vec![&e, 1, 2]),
+            vec![&e, Some(20), None]
+        );
+    });
+}
+
+#[test]
+fn test_instance_get_set_many() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+
+    e.as_contract(&id, || {
+        let storage = e.storage().instance();
+        storage.set_many(&vec![&e, (1u32, true)]);
+        assert_eq!(
+            storage.get_many::<u32, bool>(&vec![&e, 0, 1]),
+            vec![&e, None, Some(true)]
+        );
+    });
+}
@@ -0,0 +1,57 @@
+use crate::{self as soroban_sdk};
+use soroban_sdk::{contract, symbol_short, testutils::Address as _, Address, Env};
+
+#[contract]
+pub struct Contract;
+
+#[test]
+fn test_namespaces_do_not_collide() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+    let user = Address::generate(&e);
+
+    e.as_contract(&id, || {
+        let balances = e.storage().persistent().namespace(symbol_short!("bal"));
+        let allowances = e.storage().persistent().namespace(symbol_short!("allow"));
+        assert_eq!(balances.prefix(), &symbol_short!("bal"));
+
+        balances.set(&user, &100i128);
+        assert!(balances.has(&user));
+        assert!(!allowances.has(&user));
+        assert!(!e.storage().persistent().has(&user));
+
+        allowances.set(&user, &5i128);
+        assert_eq!(balances.get::<_, i128>(&user), Some(100));
+        assert_eq!(allowances.get::<_, i128>(&user), Some(5));
+
+        balances.remove(&user);
+        assert_eq!(balances.get::<_, i128>(&user), None);
+        assert_eq!(allowances.get::<_, i128>(&user), Some(5));
+    });
+}
+
+#[test]
+fn test_namespace_key_is_prefix_tuple() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+
+    e.as_contract(&id, || {
+        let ns = e.storage().temporary().namespace(symbol_short!("cfg"));
+        ns.set(&7u32, &true);
+        assert_eq!(
+            e.storage()
+                .temporary()
+                .get::<_, bool>(&(symbol_short!("cfg"), 7u32)),
+            Some(true)
+        );
+
+        e.storage()
+            .temporary()
+            .set(&(symbol_short!("cfg"), 8u32), &false);
+        assert_eq!(ns.get::<_, bool>(&8u32), Some(false));
+
+        // Namespaces in different storage types don't collide either.
+        let persistent = e.storage().persistent().namespace(symbol_short!("cfg"));
+        assert!(!persistent.has(&7u32));
+    });
+}
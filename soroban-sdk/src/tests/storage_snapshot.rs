@@ -0,0 +1,153 @@
+use crate::{
+    self as soroban_sdk,
+    testutils::storage::{assert_storage_unchanged, StorageKind, StorageSnapshot},
+    xdr,
+};
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn setup(env: Env) {
+        env.storage().instance().set(&1, &10);
+        env.storage().persistent().set(&2, &20);
+        env.storage().temporary().set(&3, &30);
+    }
+
+    pub fn set_persistent(env: Env, key: u32, value: u32) {
+        env.storage().persistent().set(&key, &value);
+    }
+
+    pub fn noop(_env: Env) {}
+}
+
+#[test]
+fn test_snapshot_covers_all_storage_types() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &id);
+
+    let before = e.storage_snapshot();
+    client.setup();
+    let after = e.storage_snapshot();
+
+    let changes = before.diff(&after);
+    let created: std::vec::Vec<_> = changes
+        .iter()
+        .map(|c| {
+            (
+                c.key.kind,
+                c.key.key.clone(),
+                c.before.clone(),
+                c.after.clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        created,
+        [
+            (
+                StorageKind::Instance,
+                xdr::ScVal::U32(1),
+                None,
+                Some(xdr::ScVal::U32(10))
+            ),
+            (
+                StorageKind::Persistent,
+                xdr::ScVal::U32(2),
+                None,
+                Some(xdr::ScVal::U32(20))
+            ),
+            (
+                StorageKind::Temporary,
+                xdr::ScVal::U32(3),
+                None,
+                Some(xdr::ScVal::U32(30))
+            ),
+        ]
+    );
+    let contract: xdr::ScAddress = (&id).into();
+    assert!(changes.iter().all(|c| c.key.contract == contract));
+}
+
+#[test]
+fn test_mutating_one_key_shows_one_change() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &id);
+    client.setup();
+    client.set_persistent(&4, &40);
+
+    let before = e.storage_snapshot();
+    client.set_persistent(&4, &41);
+    let after = e.storage_snapshot();
+
+    let changes = before.diff(&after);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].key.kind, StorageKind::Persistent);
+    assert_eq!(changes[0].key.key, xdr::ScVal::U32(4));
+    assert_eq!(changes[0].before, Some(xdr::ScVal::U32(40)));
+    assert_eq!(changes[0].after, Some(xdr::ScVal::U32(41)));
+}
+
+#[test]
+fn test_removal_shows_change() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+    ContractClient::new(&e, &id).setup();
+
+    let before = e.storage_snapshot();
+    e.as_contract(&id, || e.storage().temporary().remove(&3));
+    let after = e.storage_snapshot();
+
+    let changes = before.diff(&after);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].key.kind, StorageKind::Temporary);
+    assert_eq!(changes[0].before, Some(xdr::ScVal::U32(30)));
+    assert_eq!(changes[0].after, None);
+}
+
+#[test]
+fn test_assert_storage_unchanged() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &id);
+    client.setup();
+
+    let before = e.storage_snapshot();
+    client.noop();
+    // Writing the same value is not a change.
+    client.set_persistent(&2, &20);
+    assert_storage_unchanged(&before, &e.storage_snapshot());
+}
+
+#[test]
+#[should_panic(expected = "storage changed")]
+fn test_assert_storage_unchanged_panics() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &id);
+    client.setup();
+
+    let before = e.storage_snapshot();
+    client.set_persistent(&2, &21);
+    assert_storage_unchanged(&before, &e.storage_snapshot());
+}
+
+#[test]
+fn test_snapshot_json_round_trip() {
+    let e = Env::default();
+    let id = e.register(Contract, ());
+    let client = ContractClient::new(&e, &id);
+    client.setup();
+    client.set_persistent(&4, &40);
+
+    let snapshot = e.storage_snapshot();
+    let mut json = std::vec::Vec::new();
+    snapshot.write(&mut json).unwrap();
+    let read = StorageSnapshot::read(json.as_slice()).unwrap();
+    assert_eq!(read, snapshot);
+    assert!(read.diff(&snapshot).is_empty());
+}
@@ -0,0 +1,48 @@
+use crate::{symbol_short, Bytes, Env, Symbol, SymbolError};
+
+#[test]
+fn test_symbol_try_from_bytes_valid() {
+    let env = Env::default();
+
+    let b = Bytes::from_slice(&env, b"transfer");
+    assert_eq!(
+        Symbol::try_from_bytes(&env, &b),
+        Ok(symbol_short!("transfer"))
+    );
+
+    let b = Bytes::from_slice(&env, b"abcdefghijklmnopqrstuvwxyzABC_09");
+    assert_eq!(
+        Symbol::try_from_bytes(&env, &b),
+        Ok(Symbol::new(&env, "abcdefghijklmnopqrstuvwxyzABC_09"))
+    );
+
+    let b = Bytes::new(&env);
+    assert_eq!(Symbol::try_from_bytes(&env, &b), Ok(Symbol::new(&env, "")));
+}
+
+#[test]
+fn test_symbol_try_from_bytes_too_long() {
+    let env = Env::default();
+    let b = Bytes::from_slice(&env, b"abcdefghijklmnopqrstuvwxyzABC_09X");
+    assert_eq!(
+        Symbol::try_from_bytes(&env, &b),
+        Err(SymbolError::TooLong(33))
+    );
+}
+
+#[test]
+fn test_symbol_try_from_bytes_bad_char() {
+    let env = Env::default();
+
+    let b = Bytes::from_slice(&env, b"hello world");
+    assert_eq!(
+        Symbol::try_from_bytes(&env, &b),
+        Err(SymbolError::BadChar(' '))
+    );
+
+    let b = Bytes::from_slice(&env, &[b'a', 0xff]);
+    assert_eq!(
+        Symbol::try_from_bytes(&env, &b),
+        Err(SymbolError::BadChar('\u{ff}'))
+    );
+}
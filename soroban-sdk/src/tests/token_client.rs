@@ -5,9 +5,9 @@ use crate::{
 
 use soroban_sdk::{
     contract, contractimpl, contracttype,
-    testutils::{Address as _, IssuerFlags, MockAuth, MockAuthInvoke},
+    testutils::{Address as _, IssuerFlags, Ledger as _, MockAuth, MockAuthInvoke},
     token::Client as TokenClient,
-    Address, Env, IntoVal, Symbol,
+    Address, Env, IntoVal, String, Symbol,
 };
 
 #[contracttype]
@@ -144,3 +144,81 @@ fn test_mock_auth() {
 
     assert_eq!(client.allowance(&from, &spender), 20);
 }
+
+#[test]
+fn test_metadata() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_client = TokenClient::new(&env, &sac.address());
+
+    let metadata = token_client.metadata();
+    assert_eq!(metadata.name, token_client.name());
+    assert_eq!(metadata.symbol, String::from_str(&env, "aaa"));
+    assert_eq!(metadata.decimals, 7);
+
+    // The name of a Stellar Asset Contract is the asset code and issuer.
+    let issuer = sac.issuer().address().to_string().to_string();
+    assert_eq!(metadata.name.to_string(), std::format!("aaa:{issuer}"));
+}
+
+#[test]
+fn test_approve_for_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+
+    let admin = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    let token_client = TokenClient::new(&env, &sac.address());
+
+    let from = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    // 60 seconds is exactly 12 ledgers.
+    let expiration_ledger = token_client.approve_for_ttl(&from, &spender, &20, 60);
+    assert_eq!(expiration_ledger, 112);
+    assert_eq!(token_client.allowance(&from, &spender), 20);
+
+    // The allowance is live up to and including the expiration ledger.
+    env.ledger().set_sequence_number(112);
+    assert_eq!(token_client.allowance(&from, &spender), 20);
+    env.ledger().set_sequence_number(113);
+    assert_eq!(token_client.allowance(&from, &spender), 0);
+}
+
+#[test]
+fn test_approve_for_ttl_rounds_up() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+
+    let admin = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    let token_client = TokenClient::new(&env, &sac.address());
+
+    let from = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    assert_eq!(token_client.approve_for_ttl(&from, &spender, &20, 61), 113);
+    assert_eq!(token_client.approve_for_ttl(&from, &spender, &20, 1), 101);
+    assert_eq!(token_client.approve_for_ttl(&from, &spender, &20, 0), 100);
+}
+
+#[test]
+#[should_panic(expected = "allowance expiration ledger overflow")]
+fn test_approve_for_ttl_overflow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+
+    let admin = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    let token_client = TokenClient::new(&env, &sac.address());
+
+    let from = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    token_client.approve_for_ttl(&from, &spender, &20, u64::MAX);
+}
@@ -0,0 +1,92 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    symbol_short, vec,
+    xdr::{ScVal, ScVec, VecM},
+    Bytes, Env, IntoVal, Symbol, TryFromVal, TryIntoVal, Val, Vec,
+};
+
+type Tuple12 = (
+    u32,
+    i32,
+    u64,
+    i64,
+    u128,
+    i128,
+    bool,
+    (),
+    Symbol,
+    Bytes,
+    Vec<u32>,
+    u32,
+);
+
+fn tuple12(env: &Env) -> Tuple12 {
+    (
+        1,
+        -2,
+        3,
+        -4,
+        5,
+        -6,
+        true,
+        (),
+        symbol_short!("seven"),
+        Bytes::from_array(env, &[8]),
+        vec![env, 9],
+        u32::MAX,
+    )
+}
+
+#[test]
+fn test_tuple12_roundtrip_through_val() {
+    let env = Env::default();
+    let t = tuple12(&env);
+
+    let val: Val = t.clone().into_val(&env);
+    let back: Tuple12 = val.try_into_val(&env).unwrap();
+    assert_eq!(back, t);
+}
+
+#[test]
+fn test_tuple12_encodes_as_vec() {
+    let env = Env::default();
+    let t = tuple12(&env);
+
+    // A tuple is encoded as a vec of its elements in order, the same as
+    // shorter tuples.
+    let vals: Vec<Val> = t.clone().into_val(&env);
+    assert_eq!(vals.len(), 12);
+    assert_eq!(u32::try_from_val(&env, &vals.get_unchecked(0)).unwrap(), 1);
+    assert_eq!(
+        u32::try_from_val(&env, &vals.get_unchecked(11)).unwrap(),
+        u32::MAX
+    );
+
+    let val: Val = t.into_val(&env);
+    let scval = ScVal::try_from_val(&env, &val).unwrap();
+    assert_eq!(scval, ScVal::try_from_val(&env, &vals.to_val()).unwrap());
+    let ScVal::Vec(Some(ScVec(elements))) = &scval else {
+        panic!("tuple is not encoded as a vec: {scval:?}");
+    };
+    let elements = elements.as_slice();
+    assert_eq!(elements.len(), 12);
+    assert_eq!(elements[0], ScVal::U32(1));
+    assert_eq!(elements[7], ScVal::Void);
+    assert_eq!(elements[11], ScVal::U32(u32::MAX));
+
+    // Shorter tuples follow the same convention.
+    let val: Val = (1u32, ()).into_val(&env);
+    assert_eq!(
+        ScVal::try_from_val(&env, &val).unwrap(),
+        ScVal::Vec(Some(ScVec(
+            VecM::try_from(std::vec![ScVal::U32(1), ScVal::Void]).unwrap()
+        )))
+    );
+}
+
+#[test]
+fn test_tuple12_wrong_length_fails() {
+    let env = Env::default();
+    let val: Val = (1u32, 2u32).into_val(&env);
+    assert!(Tuple12::try_from_val(&env, &val).is_err());
+}
@@ -0,0 +1,89 @@
+use crate as soroban_sdk;
+use soroban_sdk::{
+    address::Executable,
+    contract, contractimpl,
+    ownable::Ownable,
+    symbol_short,
+    testutils::{Address as _, Events as _},
+    upgradeable::Upgradeable,
+    vec, Address, BytesN, Env, IntoVal,
+};
+
+const WASM: &[u8] = include_bytes!("../../doctest_fixtures/contract.wasm");
+
+#[contract]
+pub struct Contract;
+
+#[contractimpl]
+impl Contract {
+    pub fn __constructor(env: Env, owner: Address) {
+        Ownable::set_owner(&env, &owner);
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> u32 {
+        Upgradeable::upgrade(&env, &new_wasm_hash)
+    }
+}
+
+#[test]
+fn test_upgrade_bumps_version() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let contract_id = e.register(Contract, (&owner,));
+    let client = ContractClient::new(&e, &contract_id);
+
+    e.as_contract(&contract_id, || {
+        assert_eq!(Upgradeable::version(&e), 0);
+        assert_eq!(Upgradeable::wasm_hash(&e), None);
+    });
+
+    let wasm_hash = e.deployer().upload_contract_wasm(WASM);
+    assert_eq!(client.upgrade(&wasm_hash), 1);
+    assert_eq!(e.auths()[0].0, owner);
+
+    let events = e.events().all();
+    assert_eq!(
+        events.slice(events.len() - 1..),
+        vec![
+            &e,
+            (
+                contract_id.clone(),
+                (symbol_short!("upgraded"), 1u32).into_val(&e),
+                wasm_hash.into_val(&e)
+            )
+        ]
+    );
+
+    // The version is kept in instance storage, which survives the upgrade.
+    e.as_contract(&contract_id, || {
+        assert_eq!(Upgradeable::version(&e), 1);
+        assert_eq!(Upgradeable::wasm_hash(&e), Some(wasm_hash.clone()));
+    });
+    assert_eq!(contract_id.executable(), Some(Executable::Wasm(wasm_hash)));
+}
+
+#[test]
+fn test_upgrade_requires_owner() {
+    let e = Env::default();
+    let owner = Address::generate(&e);
+    let contract_id = e.register(Contract, (&owner,));
+    let client = ContractClient::new(&e, &contract_id);
+
+    let wasm_hash = e.deployer().upload_contract_wasm(WASM);
+    assert!(client.try_upgrade(&wasm_hash).is_err());
+    e.as_contract(&contract_id, || {
+        assert_eq!(Upgradeable::version(&e), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "wasm hash must be non-zero")]
+fn test_upgrade_rejects_zero_hash() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let client = ContractClient::new(&e, &e.register(Contract, (&owner,)));
+
+    client.upgrade(&BytesN::from_array(&e, &[0; 32]));
+}
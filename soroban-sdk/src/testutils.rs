@@ -16,6 +16,9 @@ pub use mock_auth::{
 };
 use soroban_env_host::TryIntoVal;
 
+mod custom_account;
+pub use custom_account::CustomAccount;
+
 pub mod storage;
 
 pub mod cost_estimate;
@@ -241,6 +244,28 @@ impl Generators {
     }
 }
 
+/// Snapshot of the base PRNG of a test [Env], taken with
+/// [`Env::prng_snapshot`] and restored with [`Env::restore_prng`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrngSnapshot {
+    pub(crate) seed: [u8; 32],
+    pub(crate) invocations: u64,
+}
+
+impl PrngSnapshot {
+    /// Returns the seed set with [`Env::set_prng_seed`] when the snapshot was
+    /// taken.
+    pub fn seed(&self) -> [u8; 32] {
+        self.seed
+    }
+
+    /// Returns the number of top-level invocations made since the seed was
+    /// set, when the snapshot was taken.
+    pub fn invocations(&self) -> u64 {
+        self.invocations
+    }
+}
+
 #[doc(hidden)]
 pub type ContractFunctionF = dyn Send + Sync + Fn(Env, &[Val]) -> Val;
 #[doc(hidden)]
@@ -293,6 +318,49 @@ pub trait Ledger {
         F: FnMut(&mut LedgerInfo);
 }
 
+/// FixedClock is a [`Clock`][crate::ledger::Clock] that always returns the
+/// same time.
+///
+/// Useful for unit testing logic that takes a clock, without an [Env].
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{ledger::Clock, testutils::FixedClock};
+///
+/// fn is_expired(clock: &impl Clock, deadline: u64) -> bool {
+///     clock.timestamp() > deadline
+/// }
+///
+/// assert!(!is_expired(&FixedClock::new(1_000, 1), 1_000));
+/// assert!(is_expired(&FixedClock::new(1_001, 1), 1_000));
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    pub timestamp: u64,
+    pub sequence: u32,
+}
+
+impl FixedClock {
+    /// Create a clock that returns the given timestamp and sequence number.
+    pub fn new(timestamp: u64, sequence: u32) -> FixedClock {
+        FixedClock {
+            timestamp,
+            sequence,
+        }
+    }
+}
+
+impl crate::ledger::Clock for FixedClock {
+    fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    fn sequence(&self) -> u32 {
+        self.sequence
+    }
+}
+
 pub mod budget {
     use core::fmt::{Debug, Display};
 
@@ -414,6 +482,14 @@ pub trait Events {
 pub trait Logs {
     /// Returns all diagnostic events that have been logged.
     fn all(&self) -> std::vec::Vec<String>;
+    /// Returns the message and fields of all diagnostic events that have been
+    /// logged with [`log_fields`][crate::log_fields].
+    ///
+    /// The default implementation returns no entries, for implementations
+    /// that do not record fields.
+    fn all_fields(&self) -> std::vec::Vec<(String, crate::Map<crate::Symbol, Val>)> {
+        std::vec::Vec::new()
+    }
     /// Prints all diagnostic events to stdout.
     fn print(&self);
 }
@@ -89,4 +89,28 @@ impl CostEstimate {
     pub fn budget(&self) -> Budget {
         Budget::new(self.env.host().budget_cloned())
     }
+
+    /// Asserts that the CPU instructions and memory bytes metered by the
+    /// [`budget()`][CostEstimate::budget] are below the given limits.
+    ///
+    /// Useful for catching regressions in the cost of a contract invocation,
+    /// with the same caveats as `budget()`: the costs are those of the last
+    /// top-level invocation, and are likely to be underestimated if a test
+    /// contract is used instead of a Wasm contract.
+    ///
+    /// ### Panics
+    ///
+    /// If the CPU instructions or memory bytes used are not below the limits.
+    #[track_caller]
+    pub fn assert_budget_below(&self, cpu: u64, mem: u64) {
+        let budget = self.budget();
+        let cpu_used = budget.cpu_instruction_cost();
+        let mem_used = budget.memory_bytes_cost();
+        if cpu_used >= cpu {
+            panic!("budget exceeded: cpu instructions used {cpu_used}, expected below {cpu}");
+        }
+        if mem_used >= mem {
+            panic!("budget exceeded: memory bytes used {mem_used}, expected below {mem}");
+        }
+    }
 }
@@ -0,0 +1,150 @@
+#![cfg(any(test, feature = "testutils"))]
+
+use crate::{testutils::MockAuthInvoke, xdr, Address, Bytes, BytesN, TryFromVal, Val};
+use xdr::{Limits, WriteXdr};
+
+/// CustomAccount signs authorizations for a custom account contract in tests.
+///
+/// Wraps the address of a contract implementing
+/// [`CustomAccountInterface`][crate::auth::CustomAccountInterface] and a
+/// signer function that produces the signature the contract's `__check_auth`
+/// expects for a given signature payload.
+///
+/// [`authorize`][CustomAccount::authorize] builds a signed authorization entry
+/// for an invocation tree described the same way as for
+/// [`mock_auths`][crate::Env::mock_auths]. Unlike mocked auths, the entries are
+/// passed to [`set_auths`][crate::Env::set_auths] and the contract's
+/// `__check_auth` is called to verify them.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{
+///     auth::{Context, CustomAccountInterface},
+///     contract, contracterror, contractimpl,
+///     crypto::Hash,
+///     testutils::{CustomAccount, MockAuthInvoke},
+///     Address, BytesN, Env, IntoVal, Val, Vec,
+/// };
+///
+/// #[contracterror]
+/// #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+/// pub enum AccountError {
+///     BadSignature = 1,
+/// }
+///
+/// #[contract]
+/// pub struct Account;
+///
+/// #[contractimpl]
+/// impl CustomAccountInterface for Account {
+///     type Signature = BytesN<32>;
+///     type Error = AccountError;
+///
+///     // Accepts the payload itself as the signature.
+///     fn __check_auth(
+///         _env: Env,
+///         signature_payload: Hash<32>,
+///         signature: BytesN<32>,
+///         _auth_contexts: Vec<Context>,
+///     ) -> Result<(), AccountError> {
+///         if signature_payload.to_bytes() == signature {
+///             Ok(())
+///         } else {
+///             Err(AccountError::BadSignature)
+///         }
+///     }
+/// }
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn hello(_env: Env, from: Address) {
+///         from.require_auth();
+///     }
+/// }
+///
+/// #[test]
+/// fn test() {
+/// # }
+/// # fn main() {
+///     let env = Env::default();
+///     let contract_id = env.register(Contract, ());
+///     let account_id = env.register(Account, ());
+///
+///     let account = CustomAccount::new(&account_id, |payload: &BytesN<32>| -> Val {
+///         payload.into_val(payload.env())
+///     });
+///
+///     let client = ContractClient::new(&env, &contract_id);
+///     client
+///         .set_auths(&[account.authorize(&MockAuthInvoke {
+///             contract: &contract_id,
+///             fn_name: "hello",
+///             args: (&account_id,).into_val(&env),
+///             sub_invokes: &[],
+///         })])
+///         .hello(&account_id);
+/// }
+/// ```
+pub struct CustomAccount<F> {
+    address: Address,
+    signer: F,
+}
+
+impl<F> CustomAccount<F>
+where
+    F: Fn(&BytesN<32>) -> Val,
+{
+    /// Creates a CustomAccount for the account contract at `address`, signing
+    /// payloads with `signer`.
+    pub fn new(address: &Address, signer: F) -> Self {
+        Self {
+            address: address.clone(),
+            signer,
+        }
+    }
+
+    /// Returns the address of the account contract.
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
+    /// Returns the signature for the signature payload, as produced by the
+    /// signer.
+    pub fn sign(&self, signature_payload: &BytesN<32>) -> Val {
+        (self.signer)(signature_payload)
+    }
+
+    /// Returns an authorization entry for the account authorizing the
+    /// invocation tree rooted at `invoke`, signed by the signer.
+    pub fn authorize(&self, invoke: &MockAuthInvoke) -> xdr::SorobanAuthorizationEntry {
+        let env = self.address.env();
+        let nonce = env.with_generator(|mut g| g.nonce());
+        let signature_expiration_ledger = env.ledger().sequence() + env.storage().max_ttl();
+        let root_invocation: xdr::SorobanAuthorizedInvocation = invoke.into();
+
+        let preimage =
+            xdr::HashIdPreimage::SorobanAuthorization(xdr::HashIdPreimageSorobanAuthorization {
+                network_id: xdr::Hash(env.ledger().network_id().to_array()),
+                nonce,
+                signature_expiration_ledger,
+                invocation: root_invocation.clone(),
+            });
+        let preimage = Bytes::from_slice(env, &preimage.to_xdr(Limits::none()).unwrap());
+        let signature_payload = env.crypto().sha256(&preimage).to_bytes();
+        let signature = xdr::ScVal::try_from_val(env, &self.sign(&signature_payload)).unwrap();
+
+        xdr::SorobanAuthorizationEntry {
+            root_invocation,
+            credentials: xdr::SorobanCredentials::Address(xdr::SorobanAddressCredentials {
+                address: (&self.address).into(),
+                nonce,
+                signature_expiration_ledger,
+                signature,
+            }),
+        }
+    }
+}
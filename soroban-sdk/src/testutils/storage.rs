@@ -1,4 +1,8 @@
-use crate::{Env, IntoVal, Map, Val};
+use std::collections::BTreeMap;
+
+use soroban_ledger_snapshot::LedgerSnapshot;
+
+use crate::{xdr, Env, IntoVal, Map, Val};
 
 /// Test utilities for [`Persistent`][crate::storage::Persistent].
 pub trait Persistent {
@@ -39,3 +43,183 @@ pub trait Instance {
     /// expired, excluding the current ledger.
     fn get_ttl(&self) -> u32;
 }
+
+/// The kind of storage a [`StorageKey`] refers to.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageKind {
+    Instance,
+    Persistent,
+    Temporary,
+}
+
+/// Identifies a single stored value in a [`StorageSnapshot`].
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct StorageKey {
+    pub contract: xdr::ScAddress,
+    pub kind: StorageKind,
+    pub key: xdr::ScVal,
+}
+
+/// A change to a stored value between two [`StorageSnapshot`]s.
+///
+/// `before` is None if the value was created, and `after` is None if the value
+/// was removed.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct StorageChange {
+    pub key: StorageKey,
+    pub before: Option<xdr::ScVal>,
+    pub after: Option<xdr::ScVal>,
+}
+
+/// A snapshot of the values in the persistent, temporary, and instance storage
+/// of every contract in an [`Env`].
+///
+/// Created with [`Env::storage_snapshot`]. Snapshots only capture values, so
+/// changes to TTLs alone are not reported as changes.
+///
+/// Snapshots serialize as a JSON list of key and value pairs, ordered by key.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageSnapshot(BTreeMap<StorageKey, xdr::ScVal>);
+
+impl serde::Serialize for StorageSnapshot {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for StorageSnapshot {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = <std::vec::Vec<(StorageKey, xdr::ScVal)> as serde::Deserialize>::deserialize(
+            deserializer,
+        )?;
+        Ok(StorageSnapshot(entries.into_iter().collect()))
+    }
+}
+
+impl StorageSnapshot {
+    pub(crate) fn from_ledger_snapshot(snapshot: &LedgerSnapshot) -> StorageSnapshot {
+        let mut values = BTreeMap::new();
+        for (_, (entry, _)) in snapshot.entries() {
+            let xdr::LedgerEntryData::ContractData(data) = &entry.data else {
+                continue;
+            };
+            if let xdr::ScVal::ContractInstance(instance) = &data.val {
+                if let Some(storage) = &instance.storage {
+                    for e in storage.0.iter() {
+                        let key = StorageKey {
+                            contract: data.contract.clone(),
+                            kind: StorageKind::Instance,
+                            key: e.key.clone(),
+                        };
+                        values.insert(key, e.val.clone());
+                    }
+                }
+                continue;
+            }
+            let kind = match data.durability {
+                xdr::ContractDataDurability::Persistent => StorageKind::Persistent,
+                xdr::ContractDataDurability::Temporary => StorageKind::Temporary,
+            };
+            let key = StorageKey {
+                contract: data.contract.clone(),
+                kind,
+                key: data.key.clone(),
+            };
+            values.insert(key, data.val.clone());
+        }
+        StorageSnapshot(values)
+    }
+
+    // Read in a [`StorageSnapshot`] from a reader.
+    pub fn read(r: impl std::io::Read) -> Result<StorageSnapshot, std::io::Error> {
+        Ok(serde_json::from_reader::<_, StorageSnapshot>(r)?)
+    }
+
+    // Read in a [`StorageSnapshot`] from a file.
+    pub fn read_file(p: impl AsRef<std::path::Path>) -> Result<StorageSnapshot, std::io::Error> {
+        Self::read(std::fs::File::open(p)?)
+    }
+
+    // Write a [`StorageSnapshot`] to a writer.
+    pub fn write(&self, w: impl std::io::Write) -> Result<(), std::io::Error> {
+        Ok(serde_json::to_writer_pretty(w, self)?)
+    }
+
+    // Write a [`StorageSnapshot`] to file.
+    pub fn write_file(&self, p: impl AsRef<std::path::Path>) -> Result<(), std::io::Error> {
+        let p = p.as_ref();
+        if let Some(dir) = p.parent() {
+            if !dir.exists() {
+                std::fs::create_dir_all(dir)?;
+            }
+        }
+        self.write(std::fs::File::create(p)?)
+    }
+
+    /// Returns the value stored for the key, if any.
+    pub fn get(&self, key: &StorageKey) -> Option<&xdr::ScVal> {
+        self.0.get(key)
+    }
+
+    /// Returns the number of values in the snapshot.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the snapshot contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the keys and values in the snapshot, ordered
+    /// by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&StorageKey, &xdr::ScVal)> {
+        self.0.iter()
+    }
+
+    /// Returns the changes from self to `after`, ordered by key.
+    pub fn diff(&self, after: &StorageSnapshot) -> std::vec::Vec<StorageChange> {
+        let mut changes = std::vec::Vec::new();
+        for (key, before) in self.0.iter() {
+            match after.0.get(key) {
+                Some(v) if v == before => {}
+                v => changes.push(StorageChange {
+                    key: key.clone(),
+                    before: Some(before.clone()),
+                    after: v.cloned(),
+                }),
+            }
+        }
+        for (key, v) in after.0.iter() {
+            if !self.0.contains_key(key) {
+                changes.push(StorageChange {
+                    key: key.clone(),
+                    before: None,
+                    after: Some(v.clone()),
+                });
+            }
+        }
+        changes.sort_by(|a, b| a.key.cmp(&b.key));
+        changes
+    }
+}
+
+/// Asserts that no stored values changed between the two snapshots.
+///
+/// ### Panics
+///
+/// If any value was created, updated, or removed, listing the changes.
+#[track_caller]
+pub fn assert_storage_unchanged(before: &StorageSnapshot, after: &StorageSnapshot) {
+    let changes = before.diff(after);
+    if !changes.is_empty() {
+        panic!("storage changed: {changes:#?}");
+    }
+}
@@ -239,6 +239,72 @@ pub trait TokenInterface {
 #[doc(hidden)]
 pub struct TokenFnSpec;
 
+/// TokenMetadata contains the name, symbol, and decimals of a token.
+///
+/// Returned by [`TokenClient::metadata`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMetadata {
+    /// The name of the token.
+    pub name: String,
+    /// The symbol of the token.
+    pub symbol: String,
+    /// The number of decimals the token uses.
+    pub decimals: u32,
+}
+
+impl TokenClient<'_> {
+    /// Returns the name, symbol, and decimals of the token.
+    ///
+    /// Calls [`name`][TokenInterface::name],
+    /// [`symbol`][TokenInterface::symbol], and
+    /// [`decimals`][TokenInterface::decimals] on the token contract.
+    pub fn metadata(&self) -> TokenMetadata {
+        TokenMetadata {
+            name: self.name(),
+            symbol: self.symbol(),
+            decimals: self.decimals(),
+        }
+    }
+
+    /// Allows `spender` to spend up to `amount` of `from`'s balance for at
+    /// least `ttl_seconds` seconds, by calling
+    /// [`approve`][TokenInterface::approve] with an expiration ledger computed
+    /// from the current ledger sequence.
+    ///
+    /// The duration is converted to a number of ledgers assuming the ledger
+    /// closes every [`SECONDS_PER_LEDGER`] seconds, rounded up. Returns the
+    /// expiration ledger that was passed to `approve`, after which the
+    /// allowance is zero.
+    ///
+    /// ### Panics
+    ///
+    /// If the expiration ledger overflows a `u32`.
+    ///
+    /// If the token contract rejects the expiration ledger, for example if it
+    /// is beyond the maximum ledger that entries can live to.
+    pub fn approve_for_ttl(
+        &self,
+        from: &Address,
+        spender: &Address,
+        amount: &i128,
+        ttl_seconds: u64,
+    ) -> u32 {
+        let ledgers = ttl_seconds.div_ceil(SECONDS_PER_LEDGER);
+        let expiration_ledger = u32::try_from(ledgers)
+            .ok()
+            .and_then(|ledgers| self.env.ledger().sequence().checked_add(ledgers));
+        let Some(expiration_ledger) = expiration_ledger else {
+            sdk_panic!("allowance expiration ledger overflow");
+        };
+        self.approve(from, spender, amount, &expiration_ledger);
+        expiration_ledger
+    }
+}
+
+/// The target number of seconds between ledger closes, used to convert
+/// durations into a number of ledgers.
+pub const SECONDS_PER_LEDGER: u64 = 5;
+
 /// Interface for admin capabilities for Token contracts, such as the Stellar
 /// Asset Contract.
 #[contractspecfn(name = "StellarAssetFnSpec", export = false)]
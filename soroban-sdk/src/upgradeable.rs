@@ -0,0 +1,106 @@
+//! Upgradeable contains a helper for upgrading a contract's Wasm with version
+//! tracking.
+use crate::{
+    ownable::Ownable,
+    storage::{ReservedKey, SdkKey},
+    BytesN, Env,
+};
+
+/// Upgradeable upgrades the Wasm of the current contract, and keeps track of
+/// the number of upgrades and the Wasm hash most recently upgraded to.
+///
+/// Upgrades are gated by [`Ownable`], so the current contract must set an
+/// owner with [`Ownable::set_owner`], and the owner must authorize each
+/// upgrade.
+///
+/// The version and Wasm hash are stored in the current contract's instance
+/// storage, which is kept across upgrades.
+///
+/// ### Events
+///
+/// Each upgrade publishes an event with the topics `upgraded` and the new
+/// version as a `u32`, and the new Wasm hash as the data.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::{contract, contractimpl, ownable::Ownable, upgradeable::Upgradeable, Address, BytesN, Env};
+///
+/// #[contract]
+/// pub struct Contract;
+///
+/// #[contractimpl]
+/// impl Contract {
+///     pub fn __constructor(env: Env, owner: Address) {
+///         Ownable::set_owner(&env, &owner);
+///     }
+///
+///     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> u32 {
+///         Upgradeable::upgrade(&env, &new_wasm_hash)
+///     }
+/// }
+/// # fn main() { }
+/// ```
+pub struct Upgradeable;
+
+impl Upgradeable {
+    /// Returns the number of times the current contract has been upgraded
+    /// with [`upgrade`][Upgradeable::upgrade].
+    pub fn version(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Self::version_key())
+            .unwrap_or(0)
+    }
+
+    /// Returns the Wasm hash the current contract was most recently upgraded
+    /// to, or `None` if it has never been upgraded with
+    /// [`upgrade`][Upgradeable::upgrade].
+    pub fn wasm_hash(env: &Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&Self::wasm_hash_key())
+    }
+
+    /// Upgrades the Wasm of the current contract to `new_wasm_hash`, and
+    /// returns the new version.
+    ///
+    /// The owner of the current contract must authorize the invocation. The
+    /// Wasm must already be uploaded, and the current contract's executable is
+    /// only replaced after the invocation has successfully finished. See
+    /// [`Deployer::update_current_contract_wasm`][crate::deploy::Deployer::update_current_contract_wasm].
+    ///
+    /// ### Panics
+    ///
+    /// If `new_wasm_hash` is all zeros.
+    ///
+    /// If no owner has been set, or the owner has not authorized the
+    /// invocation.
+    pub fn upgrade(env: &Env, new_wasm_hash: &BytesN<32>) -> u32 {
+        Ownable::require_owner(env);
+        if new_wasm_hash.to_array() == [0u8; 32] {
+            sdk_panic!("wasm hash must be non-zero");
+        }
+        let version = match Self::version(env).checked_add(1) {
+            Some(version) => version,
+            None => sdk_panic!("version overflow"),
+        };
+        let storage = env.storage().instance();
+        storage.set(&Self::version_key(), &version);
+        storage.set(&Self::wasm_hash_key(), new_wasm_hash);
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        env.events()
+            .builder()
+            .topic(soroban_sdk_macros::internal_symbol_short!("upgraded"))
+            .topic(version)
+            .publish_data(new_wasm_hash);
+        version
+    }
+
+    fn version_key() -> ReservedKey {
+        SdkKey::Version.into()
+    }
+
+    fn wasm_hash_key() -> ReservedKey {
+        SdkKey::WasmHash.into()
+    }
+}
@@ -176,6 +176,22 @@ impl<T> TryFromVal<Env, &Vec<Val>> for Vec<T> {
     }
 }
 
+impl<T, const N: usize> TryFromVal<Env, [T; N]> for Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    type Error = Infallible;
+
+    fn try_from_val(env: &Env, v: &[T; N]) -> Result<Self, Self::Error> {
+        let mut tmp: [Val; N] = [Val::VOID.to_val(); N];
+        for (dst, src) in tmp.iter_mut().zip(v.iter()) {
+            *dst = src.into_val(env)
+        }
+        let vec = env.vec_new_from_slice(&tmp).unwrap_infallible();
+        Ok(unsafe { Vec::unchecked_new(env.clone(), vec) })
+    }
+}
+
 impl<T> TryFromVal<Env, VecObject> for Vec<T>
 where
     T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
@@ -366,19 +382,20 @@ where
     }
 
     /// Create a Vec from the array of items.
+    ///
+    /// Arrays can also be converted with [`IntoVal`], as in
+    /// `[1, 2, 3].into_val(&env)`, where a `Vec` is expected.
     #[inline(always)]
     pub fn from_array<const N: usize>(env: &Env, items: [T; N]) -> Vec<T> {
-        let mut tmp: [Val; N] = [Val::VOID.to_val(); N];
-        for (dst, src) in tmp.iter_mut().zip(items.iter()) {
-            *dst = src.into_val(env)
-        }
-        let vec = env.vec_new_from_slice(&tmp).unwrap_infallible();
-        unsafe { Self::unchecked_new(env.clone(), vec) }
+        Self::try_from_val(env, &items).unwrap_infallible()
     }
 
     /// Create a Vec from an iterator of items.
     ///
     /// This provides FromIterator-like functionality but requires an Env parameter.
+    /// `FromIterator` itself cannot be implemented because a [`Vec`] cannot be
+    /// created without an [`Env`], so use this in place of `collect`, for
+    /// example to map over another [`Vec`].
     ///
     /// Note: This function iteratively adds each item one at a time, making a call to the Soroban
     /// environment for each item making it inefficient for joining two [`Vec`]s. Use
@@ -393,6 +410,9 @@ where
     /// let items = vec![1, 2, 3, 4];
     /// let vec = Vec::from_iter(&env, items.into_iter());
     /// assert_eq!(vec.len(), 4);
+    ///
+    /// let doubled: Vec<u32> = Vec::from_iter(&env, vec.iter().map(|v| v * 2));
+    /// assert_eq!(doubled, Vec::from_array(&env, [2, 4, 6, 8]));
     /// ```
     #[inline(always)]
     pub fn from_iter<I: IntoIterator<Item = T>>(env: &Env, iter: I) -> Vec<T> {
@@ -401,6 +421,26 @@ where
         vec
     }
 
+    /// Create a Vec in the [`Env`] from an iterator of items.
+    ///
+    /// The same as [`from_iter`][Vec::from_iter], named for use in place of
+    /// `collect` where the Vec is created in the given [`Env`].
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{vec, Env, Vec};
+    ///
+    /// let env = Env::default();
+    /// let vec = vec![&env, 1u32, 2, 3];
+    /// let doubled = Vec::from_iter_in(&env, vec.iter().map(|v| v * 2));
+    /// assert_eq!(doubled, vec![&env, 2, 4, 6]);
+    /// ```
+    #[inline(always)]
+    pub fn from_iter_in<I: IntoIterator<Item = T>>(env: &Env, iter: I) -> Vec<T> {
+        Self::from_iter(env, iter)
+    }
+
     /// Create a Vec from the slice of items.
     #[inline(always)]
     pub fn from_slice(env: &Env, items: &[T]) -> Vec<T>
@@ -477,7 +517,10 @@ where
             .unwrap_infallible();
     }
 
-    /// Removes the item at the position.
+    /// Removes the item at the position, shifting all items after it down by
+    /// one.
+    ///
+    /// The cost is proportional to the length of the vec.
     ///
     /// Returns `None` if out-of-bounds.
     #[inline(always)]
@@ -490,7 +533,10 @@ where
         }
     }
 
-    /// Removes the item at the position.
+    /// Removes the item at the position, shifting all items after it down by
+    /// one.
+    ///
+    /// The cost is proportional to the length of the vec.
     ///
     /// ### Panics
     ///
@@ -734,11 +780,15 @@ where
         T::try_from_val(env, &val)
     }
 
-    /// Inserts an item at the position.
+    /// Inserts an item at the position, shifting all items at and after it up
+    /// by one.
+    ///
+    /// The position may be equal to the length, to add the item to the back.
+    /// The cost is proportional to the length of the vec.
     ///
     /// ### Panics
     ///
-    /// If the position is out-of-bounds.
+    /// If the position is greater than the length.
     #[inline(always)]
     pub fn insert(&mut self, i: u32, x: T) {
         let env = self.env();
@@ -770,6 +820,66 @@ where
             self.push_back(item.clone());
         }
     }
+
+    /// Sorts the [Vec] with a comparator function.
+    ///
+    /// The sort is stable, items that compare equal keep their relative order.
+    ///
+    /// Unlike [Vec::sort] every compared item is converted into `T` in the
+    /// guest. Items are placed with a binary insertion sort, making O(n log n)
+    /// comparisons, so prefer [Vec::sort] when the host ordering suffices.
+    ///
+    /// ### Panics
+    ///
+    /// If any value cannot be converted to type T.
+    pub fn sort_by<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        T: Clone,
+        T::Error: Debug,
+    {
+        let mut sorted = Vec::<T>::new(self.env());
+        for item in self.iter() {
+            // Find the first position holding an item greater than this one,
+            // placing it after any equal items.
+            let mut low = 0;
+            let mut high = sorted.len();
+            while low < high {
+                let mid = low + (high - low) / 2;
+                if f(&sorted.get_unchecked(mid), &item) == Ordering::Greater {
+                    high = mid;
+                } else {
+                    low = mid + 1;
+                }
+            }
+            sorted.insert(low, item);
+        }
+        *self = sorted;
+    }
+
+    /// Retains only the items for which the predicate returns true, removing
+    /// all others. The order of the retained items is preserved.
+    ///
+    /// Each item is converted into `T` once, and only the retained items are
+    /// written back to the host.
+    ///
+    /// ### Panics
+    ///
+    /// If any value cannot be converted to type T.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+        T: Clone,
+        T::Error: Debug,
+    {
+        let mut retained = Vec::<T>::new(self.env());
+        for item in self.iter() {
+            if f(&item) {
+                retained.push_back(item);
+            }
+        }
+        *self = retained;
+    }
 }
 
 impl<T> Vec<T> {
@@ -798,6 +908,84 @@ impl<T> Vec<T> {
         unsafe { Self::unchecked_new(env.clone(), obj) }
     }
 
+    /// Splits the vec into two at the position, returning the items before
+    /// the position and the items from the position onwards.
+    ///
+    /// ### Panics
+    ///
+    /// If the position is out-of-bounds.
+    #[must_use]
+    pub fn split_at(&self, mid: u32) -> (Self, Self) {
+        (self.slice(..mid), self.slice(mid..))
+    }
+
+    /// Shortens the vec to the length, removing the items after it.
+    ///
+    /// Has no effect if the length is greater than or equal to the number of
+    /// items in the vec.
+    pub fn truncate(&mut self, len: u32) {
+        if len < self.len() {
+            *self = self.slice(..len);
+        }
+    }
+
+    /// Reverses the order of the items.
+    ///
+    /// Items are moved without being converted into `T`.
+    pub fn reverse(&mut self) {
+        // Items are reversed a chunk at a time in the guest, so that the
+        // number of host calls grows with the number of chunks rather than
+        // with the number of items.
+        const CHUNK_SIZE: u32 = 32;
+        let env = self.env();
+        let mut buf = [Val::VOID.to_val(); CHUNK_SIZE as usize];
+        let mut reversed = env.vec_new().unwrap_infallible();
+        let mut end = self.len();
+        while end > 0 {
+            let start = end.saturating_sub(CHUNK_SIZE);
+            let chunk = &mut buf[..(end - start) as usize];
+            let slice = env
+                .vec_slice(self.obj, start.into(), end.into())
+                .unwrap_infallible();
+            env.vec_unpack_to_slice(slice, chunk).unwrap_infallible();
+            chunk.reverse();
+            let chunk = env.vec_new_from_slice(chunk).unwrap_infallible();
+            reversed = env.vec_append(reversed, chunk).unwrap_infallible();
+            end = start;
+        }
+        self.obj = reversed;
+    }
+
+    /// Rotates the items `n` positions to the left, so that the item at
+    /// position `n` becomes the first item, and the first `n` items move to
+    /// the back.
+    ///
+    /// Rotating by the length or more rotates by `n` modulo the length.
+    pub fn rotate_left(&mut self, n: u32) {
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+        let mid = n % len;
+        if mid != 0 {
+            let (front, back) = self.split_at(mid);
+            let env = self.env();
+            self.obj = env.vec_append(back.obj, front.obj).unwrap_infallible();
+        }
+    }
+
+    /// Rotates the items `n` positions to the right, so that the last `n`
+    /// items move to the front.
+    ///
+    /// Rotating by the length or more rotates by `n` modulo the length.
+    pub fn rotate_right(&mut self, n: u32) {
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+        self.rotate_left(len - n % len);
+    }
+
     /// Returns copy of the vec shuffled using the NOT-SECURE PRNG.
     ///
     /// In tests, must be called from within a running contract.
@@ -888,6 +1076,11 @@ where
     ///
     /// If the item is not found, [Result::Err] is returned containing the index
     /// of where the item could be inserted to retain the sorted ordering.
+    ///
+    /// If multiple items match, the index of any one of them may be returned.
+    ///
+    /// Items are compared by the host without being converted into `T`, using
+    /// the same ordering as [Vec::sort].
     #[inline(always)]
     pub fn binary_search(&self, item: impl Borrow<T>) -> Result<u32, u32> {
         let env = self.env();
@@ -901,6 +1094,120 @@ where
             Err(low)
         }
     }
+
+    /// Sorts the [Vec] in ascending order.
+    ///
+    /// Items are ordered by the host, using the same ordering as [Map] keys
+    /// and the `Ord` implementations of SDK types, without converting items
+    /// into `T` in the guest. Each item is placed using a host binary search
+    /// followed by an insert.
+    ///
+    /// Items that are equal under the host ordering are identical values, so
+    /// the sort is stable.
+    pub fn sort(&mut self) {
+        let env = self.env();
+        let mut sorted = Vec::<Val>::new(env);
+        for val in self.to_vals().iter() {
+            let i = match sorted.binary_search(val) {
+                Ok(i) | Err(i) => i,
+            };
+            sorted.insert(i, val);
+        }
+        self.obj = sorted.to_object();
+    }
+
+    /// Removes consecutive repeated items, keeping the first of each run.
+    ///
+    /// Items are compared by the host without being converted into `T`. If
+    /// the [Vec] is sorted this removes all duplicates.
+    pub fn dedup(&mut self) {
+        let env = self.env();
+        let mut deduped = Vec::<Val>::new(env);
+        let mut prev: Option<Val> = None;
+        for val in self.to_vals().iter() {
+            if let Some(prev) = prev {
+                if env.obj_cmp(prev, val).unwrap_infallible() == 0 {
+                    continue;
+                }
+            }
+            deduped.push_back(val);
+            prev = Some(val);
+        }
+        self.obj = deduped.to_object();
+    }
+
+    /// Returns the items split into consecutive, non-overlapping chunks of
+    /// `size` items.
+    ///
+    /// If the length of the [Vec] is not a multiple of `size`, the last chunk
+    /// is shorter than `size`.
+    ///
+    /// ### Panics
+    ///
+    /// If `size` is zero.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{vec, Env};
+    ///
+    /// let env = Env::default();
+    /// let v = vec![&env, 1, 2, 3, 4, 5];
+    /// assert_eq!(
+    ///     v.chunks(2),
+    ///     vec![&env, vec![&env, 1, 2], vec![&env, 3, 4], vec![&env, 5]]
+    /// );
+    /// ```
+    pub fn chunks(&self, size: u32) -> Vec<Vec<T>> {
+        if size == 0 {
+            sdk_panic!("chunk size must be non-zero");
+        }
+        let len = self.len();
+        let mut chunks = Vec::new(self.env());
+        let mut start = 0;
+        while start < len {
+            let end = start.saturating_add(size).min(len);
+            chunks.push_back(self.slice(start..end));
+            start = end;
+        }
+        chunks
+    }
+
+    /// Returns every contiguous window of `size` items, in order.
+    ///
+    /// Each window starts one item after the previous window, so consecutive
+    /// windows overlap by `size - 1` items. If the [Vec] is shorter than
+    /// `size` there are no windows.
+    ///
+    /// ### Panics
+    ///
+    /// If `size` is zero.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use soroban_sdk::{vec, Env};
+    ///
+    /// let env = Env::default();
+    /// let v = vec![&env, 1, 2, 3, 4];
+    /// assert_eq!(
+    ///     v.windows(2),
+    ///     vec![&env, vec![&env, 1, 2], vec![&env, 2, 3], vec![&env, 3, 4]]
+    /// );
+    /// ```
+    pub fn windows(&self, size: u32) -> Vec<Vec<T>> {
+        if size == 0 {
+            sdk_panic!("window size must be non-zero");
+        }
+        let len = self.len();
+        let mut windows = Vec::new(self.env());
+        if len >= size {
+            for start in 0..=len - size {
+                windows.push_back(self.slice(start..start + size));
+            }
+        }
+        windows
+    }
 }
 
 impl<T> Vec<Vec<T>>
@@ -1062,6 +1369,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::{Address, U256};
 
     #[test]
     fn test_vec_macro() {
@@ -1087,6 +1395,20 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_vec_from_array() {
+        let env = Env::default();
+        assert_eq!(Vec::<i32>::from_array(&env, []), vec![&env]);
+        assert_eq!(Vec::from_array(&env, [3, 2, 1]), vec![&env, 3, 2, 1]);
+
+        // Arrays also convert with an env, such as for arguments that are
+        // `impl IntoVal<Env, Vec<T>>`.
+        let v: Vec<u32> = [3, 2, 1].into_val(&env);
+        assert_eq!(v, vec![&env, 3, 2, 1]);
+        let v: Vec<Vec<u32>> = [vec![&env, 1], vec![&env]].into_val(&env);
+        assert_eq!(v, vec![&env, vec![&env, 1], vec![&env]]);
+    }
+
     #[test]
     fn test_vec_to_val() {
         let env = Env::default();
@@ -1260,6 +1582,107 @@ mod test {
         assert_eq!(slice, vec![&env, 1, 2]);
     }
 
+    #[test]
+    fn test_vec_split_at() {
+        let env = Env::default();
+        let vec = vec![&env, 0, 1, 2, 3, 4];
+
+        assert_eq!(vec.split_at(0), (vec![&env], vec![&env, 0, 1, 2, 3, 4]));
+        assert_eq!(vec.split_at(2), (vec![&env, 0, 1], vec![&env, 2, 3, 4]));
+        assert_eq!(vec.split_at(5), (vec![&env, 0, 1, 2, 3, 4], vec![&env]));
+
+        let empty: Vec<u32> = vec![&env];
+        assert_eq!(empty.split_at(0), (vec![&env], vec![&env]));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Object, IndexBounds)")]
+    fn test_vec_split_at_panic() {
+        let env = Env::default();
+        let vec = vec![&env, 0, 1, 2];
+        let _ = vec.split_at(4);
+    }
+
+    #[test]
+    fn test_vec_truncate() {
+        let env = Env::default();
+
+        let mut vec = vec![&env, 0, 1, 2, 3, 4];
+        vec.truncate(3);
+        assert_eq!(vec, vec![&env, 0, 1, 2]);
+        vec.truncate(3);
+        assert_eq!(vec, vec![&env, 0, 1, 2]);
+        vec.truncate(10);
+        assert_eq!(vec, vec![&env, 0, 1, 2]);
+        vec.truncate(0);
+        assert_eq!(vec, vec![&env]);
+        vec.truncate(0);
+        assert_eq!(vec, vec![&env]);
+    }
+
+    #[test]
+    fn test_vec_reverse() {
+        let env = Env::default();
+
+        let mut odd = vec![&env, 1, 2, 3, 4, 5];
+        odd.reverse();
+        assert_eq!(odd, vec![&env, 5, 4, 3, 2, 1]);
+
+        let mut even = vec![&env, 1, 2, 3, 4];
+        even.reverse();
+        assert_eq!(even, vec![&env, 4, 3, 2, 1]);
+        even.reverse();
+        assert_eq!(even, vec![&env, 1, 2, 3, 4]);
+
+        let mut one = vec![&env, 1];
+        one.reverse();
+        assert_eq!(one, vec![&env, 1]);
+
+        let mut empty: Vec<u32> = vec![&env];
+        empty.reverse();
+        assert_eq!(empty, vec![&env]);
+
+        // Lengths around and across multiple chunks.
+        for len in [31, 32, 33, 64, 100] {
+            let mut v = Vec::<u32>::from_iter(&env, 0..len);
+            v.reverse();
+            assert_eq!(v, Vec::<u32>::from_iter(&env, (0..len).rev()));
+        }
+    }
+
+    #[test]
+    fn test_vec_rotate() {
+        let env = Env::default();
+        let vec = vec![&env, 0, 1, 2, 3, 4];
+        let rotated_left = |n| {
+            let mut v = vec.clone();
+            v.rotate_left(n);
+            v
+        };
+        let rotated_right = |n| {
+            let mut v = vec.clone();
+            v.rotate_right(n);
+            v
+        };
+
+        assert_eq!(rotated_left(0), vec);
+        assert_eq!(rotated_left(2), vec![&env, 2, 3, 4, 0, 1]);
+        assert_eq!(rotated_left(5), vec);
+        assert_eq!(rotated_left(7), vec![&env, 2, 3, 4, 0, 1]);
+        assert_eq!(rotated_left(u32::MAX), rotated_left(u32::MAX % 5));
+
+        assert_eq!(rotated_right(0), vec);
+        assert_eq!(rotated_right(2), vec![&env, 3, 4, 0, 1, 2]);
+        assert_eq!(rotated_right(5), vec);
+        assert_eq!(rotated_right(7), vec![&env, 3, 4, 0, 1, 2]);
+        assert_eq!(rotated_right(u32::MAX), rotated_right(u32::MAX % 5));
+
+        let mut empty: Vec<u32> = vec![&env];
+        empty.rotate_left(3);
+        empty.rotate_right(3);
+        assert_eq!(empty, vec![&env]);
+    }
+
     #[test]
     fn test_vec_iter() {
         let env = Env::default();
@@ -1459,6 +1882,177 @@ mod test {
         assert_eq!(vec.binary_search(5), Ok(3));
     }
 
+    #[test]
+    fn test_dedup() {
+        let env = Env::default();
+
+        let mut vec = vec![&env, 1, 1, 2, 2, 2, 3];
+        vec.dedup();
+        assert_eq!(vec, vec![&env, 1, 2, 3]);
+
+        // only consecutive items are removed
+        let mut vec = vec![&env, 1, 2, 1, 1];
+        vec.dedup();
+        assert_eq!(vec, vec![&env, 1, 2, 1]);
+
+        let mut vec = Vec::<u32>::new(&env);
+        vec.dedup();
+        assert_eq!(vec, vec![&env]);
+
+        let mut vec = vec![
+            &env,
+            U256::from_u128(&env, u128::MAX),
+            U256::from_u128(&env, u128::MAX),
+            U256::from_u32(&env, 7),
+        ];
+        vec.dedup();
+        assert_eq!(
+            vec,
+            vec![
+                &env,
+                U256::from_u128(&env, u128::MAX),
+                U256::from_u32(&env, 7)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_retain() {
+        let env = Env::default();
+
+        let mut vec = Vec::from_iter(&env, (0..10u32).map(|i| U256::from_u32(&env, i)));
+        let two = U256::from_u32(&env, 2);
+        let zero = U256::from_u32(&env, 0);
+        vec.retain(|v| v.rem_euclid(&two) == zero);
+        assert_eq!(
+            vec,
+            Vec::from_iter(&env, [0u32, 2, 4, 6, 8].map(|i| U256::from_u32(&env, i)))
+        );
+
+        let mut vec = vec![&env, 1, 2, 3];
+        vec.retain(|_| false);
+        assert_eq!(vec, vec![&env]);
+
+        let mut vec = vec![&env, 1, 2, 3];
+        vec.retain(|_| true);
+        assert_eq!(vec, vec![&env, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_binary_search_edge_cases() {
+        let env = Env::default();
+
+        let empty = Vec::<u32>::new(&env);
+        assert_eq!(empty.binary_search(1), Err(0));
+
+        let vec = vec![&env, 2u32, 4, 6];
+        // smaller than all
+        assert_eq!(vec.binary_search(0), Err(0));
+        // larger than all
+        assert_eq!(vec.binary_search(7), Err(3));
+        assert_eq!(vec.binary_search(6), Ok(2));
+
+        // duplicates return any matching index
+        let vec = vec![&env, 1u32, 3, 3, 3, 3, 8];
+        let i = vec.binary_search(3).unwrap();
+        assert!((1..=4).contains(&i));
+        assert_eq!(vec.get_unchecked(i), 3);
+
+        // works on the output of sort
+        let mut vec = vec![
+            &env,
+            U256::from_u32(&env, 30),
+            U256::from_u32(&env, 10),
+            U256::from_u32(&env, 20),
+        ];
+        vec.sort();
+        assert_eq!(vec.binary_search(U256::from_u32(&env, 20)), Ok(1));
+        assert_eq!(vec.binary_search(U256::from_u32(&env, 25)), Err(2));
+    }
+
+    #[test]
+    fn test_sort() {
+        let env = Env::default();
+
+        let mut vec = vec![&env, 5, 3, 9, 0, 5, 7];
+        vec.sort();
+        assert_eq!(vec, vec![&env, 0, 3, 5, 5, 7, 9]);
+
+        let mut vec = Vec::<u32>::new(&env);
+        vec.sort();
+        assert_eq!(vec, vec![&env]);
+
+        let mut vec = vec![
+            &env,
+            U256::from_u128(&env, u128::MAX),
+            U256::from_parts(&env, 1, 0, 0, 0),
+            U256::from_u32(&env, 0),
+            U256::from_u32(&env, 42),
+        ];
+        vec.sort();
+        assert_eq!(
+            vec,
+            vec![
+                &env,
+                U256::from_u32(&env, 0),
+                U256::from_u32(&env, 42),
+                U256::from_u128(&env, u128::MAX),
+                U256::from_parts(&env, 1, 0, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sort_addresses() {
+        use crate::testutils::Address as _;
+        let env = Env::default();
+
+        let mut vec = Vec::<Address>::new(&env);
+        for _ in 0..8 {
+            vec.push_back(Address::generate(&env));
+        }
+        let mut sorted = vec.clone();
+        sorted.sort();
+        assert_eq!(sorted.len(), vec.len());
+        for i in 1..sorted.len() {
+            assert!(sorted.get_unchecked(i - 1) <= sorted.get_unchecked(i));
+        }
+        for address in vec.iter() {
+            assert!(sorted.contains(address));
+        }
+    }
+
+    #[test]
+    fn test_sort_by() {
+        let env = Env::default();
+
+        let mut vec = vec![&env, 5, 3, 9, 0, 5, 7];
+        vec.sort_by(|a, b| b.cmp(a));
+        assert_eq!(vec, vec![&env, 9, 7, 5, 5, 3, 0]);
+
+        // stable, items with equal keys keep their order
+        let mut vec = vec![&env, (1u32, 0u32), (0, 1), (1, 2), (0, 3)];
+        vec.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(vec, vec![&env, (0, 1), (0, 3), (1, 0), (1, 2)]);
+
+        let mut vec = vec![
+            &env,
+            U256::from_u32(&env, 3),
+            U256::from_u32(&env, 1),
+            U256::from_u32(&env, 2),
+        ];
+        vec.sort_by(|a, b| a.cmp(b));
+        assert_eq!(
+            vec,
+            vec![
+                &env,
+                U256::from_u32(&env, 1),
+                U256::from_u32(&env, 2),
+                U256::from_u32(&env, 3),
+            ]
+        );
+    }
+
     #[cfg(not(target_family = "wasm"))]
     #[test]
     fn test_scval_accessibility_from_udt_types() {
@@ -1486,6 +2080,26 @@ mod test {
         assert_eq!(v, vec![&env, 7, 6, 2, 5]);
     }
 
+    #[test]
+    fn test_insert_front_middle_end() {
+        let env = Env::default();
+        let mut v: Vec<i64> = vec![&env, 2, 4];
+        v.insert(0, 1);
+        assert_eq!(v, vec![&env, 1, 2, 4]);
+        v.insert(2, 3);
+        assert_eq!(v, vec![&env, 1, 2, 3, 4]);
+        v.insert(v.len(), 5);
+        assert_eq!(v, vec![&env, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Object, IndexBounds)")]
+    fn test_insert_panics() {
+        let env = Env::default();
+        let mut v: Vec<i64> = vec![&env, 1, 2];
+        v.insert(3, 3);
+    }
+
     #[test]
     fn test_is_empty_and_len() {
         let env = Env::default();
@@ -1911,6 +2525,105 @@ mod test {
         assert_eq!(v.len(), 9);
     }
 
+    #[test]
+    fn test_append() {
+        let env = Env::default();
+
+        // Append an empty vec
+        let mut v: Vec<i64> = vec![&env, 1, 2, 3];
+        v.append(&vec![&env]);
+        assert_eq!(v, vec![&env, 1, 2, 3]);
+        assert_eq!(v.len(), 3);
+
+        // Append to an empty vec
+        let mut v: Vec<i64> = vec![&env];
+        v.append(&vec![&env, 1, 2, 3]);
+        assert_eq!(v, vec![&env, 1, 2, 3]);
+        assert_eq!(v.len(), 3);
+
+        // Append preserves the order of both vecs, and leaves other unchanged
+        let other: Vec<i64> = vec![&env, 4, 5];
+        v.append(&other);
+        assert_eq!(v, vec![&env, 1, 2, 3, 4, 5]);
+        assert_eq!(v.len(), 5);
+        assert_eq!(other, vec![&env, 4, 5]);
+    }
+
+    #[test]
+    fn test_chunks() {
+        let env = Env::default();
+        let v: Vec<i64> = vec![&env, 1, 2, 3, 4, 5];
+
+        assert_eq!(
+            v.chunks(2),
+            vec![&env, vec![&env, 1, 2], vec![&env, 3, 4], vec![&env, 5]]
+        );
+        assert_eq!(v.chunks(5), vec![&env, v.clone()]);
+        assert_eq!(v.chunks(6), vec![&env, v.clone()]);
+        assert_eq!(v.chunks(u32::MAX), vec![&env, v.clone()]);
+        assert_eq!(Vec::<i64>::new(&env).chunks(2), vec![&env]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_chunks_zero_size_panic() {
+        let env = Env::default();
+        let v: Vec<i64> = vec![&env, 1, 2];
+        let _ = v.chunks(0);
+    }
+
+    #[test]
+    fn test_windows() {
+        let env = Env::default();
+        let v: Vec<i64> = vec![&env, 1, 2, 3, 4, 5];
+
+        assert_eq!(
+            v.windows(2),
+            vec![
+                &env,
+                vec![&env, 1, 2],
+                vec![&env, 2, 3],
+                vec![&env, 3, 4],
+                vec![&env, 4, 5]
+            ]
+        );
+        assert_eq!(v.windows(5), vec![&env, v.clone()]);
+        assert_eq!(v.windows(6), vec![&env]);
+        assert_eq!(Vec::<i64>::new(&env).windows(1), vec![&env]);
+
+        // differences between consecutive items
+        let diffs: std::vec::Vec<i64> = v
+            .windows(2)
+            .iter()
+            .map(|w| w.get_unchecked(1) - w.get_unchecked(0))
+            .collect();
+        assert_eq!(diffs, [1, 1, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_windows_zero_size_panic() {
+        let env = Env::default();
+        let v: Vec<i64> = vec![&env, 1, 2];
+        let _ = v.windows(0);
+    }
+
+    #[test]
+    fn test_extend_from_array() {
+        let env = Env::default();
+
+        let mut v: Vec<i64> = vec![&env];
+        v.extend_from_array([1, 2, 3]);
+        assert_eq!(v, vec![&env, 1, 2, 3]);
+
+        v.extend_from_array([]);
+        assert_eq!(v, vec![&env, 1, 2, 3]);
+
+        v.extend_from_array([4, 5]);
+        assert_eq!(v, vec![&env, 1, 2, 3, 4, 5]);
+        assert_eq!(v.len(), 5);
+    }
+
     #[test]
     fn test_extend_empty_vec() {
         let env = Env::default();
@@ -0,0 +1,163 @@
+//! Vesting contains math for schedules that release an amount linearly over
+//! time, such as token streams, payroll, and grants.
+use crate::num::{mul_div_u128, Rounding};
+
+/// Returns the amount of `total` vested at the timestamp `now`, for a
+/// schedule that starts at the timestamp `start` and vests linearly over
+/// `duration` seconds, with nothing vested until `cliff` seconds after the
+/// start.
+///
+/// The amount vested is:
+///
+/// - `0` before `start + cliff`.
+/// - `total * (now - start) / duration`, rounded down, from `start + cliff`,
+///   so that the amount vested before the cliff is released all at once at
+///   the cliff.
+/// - `total` from `start + duration`.
+///
+/// The amount is rounded down so that it is never more than the linear
+/// schedule, and the remainder is vested at the end. The product is
+/// computed without overflow for any `total`.
+///
+/// Timestamps and durations are in seconds, such as from
+/// [`Ledger::timestamp`][crate::ledger::Ledger::timestamp].
+///
+/// ### Panics
+///
+/// If `total` is negative, or if `cliff` is greater than `duration`.
+///
+/// ### Examples
+///
+/// ```
+/// use soroban_sdk::vesting::vested_amount;
+///
+/// // 1200 vesting over 12 months from the start at 0, with a 3 month cliff.
+/// let month = 30 * 24 * 60 * 60;
+/// assert_eq!(vested_amount(0, 3 * month, 12 * month, 1200, 2 * month), 0);
+/// assert_eq!(vested_amount(0, 3 * month, 12 * month, 1200, 3 * month), 300);
+/// assert_eq!(vested_amount(0, 3 * month, 12 * month, 1200, 6 * month), 600);
+/// assert_eq!(vested_amount(0, 3 * month, 12 * month, 1200, 13 * month), 1200);
+/// ```
+pub fn vested_amount(start: u64, cliff: u64, duration: u64, total: i128, now: u64) -> i128 {
+    if total < 0 {
+        sdk_panic!("vesting total must not be negative");
+    }
+    if cliff > duration {
+        sdk_panic!("vesting cliff must not be after the end");
+    }
+    if now < start.saturating_add(cliff) {
+        return 0;
+    }
+    let elapsed = now - start;
+    if elapsed >= duration {
+        return total;
+    }
+    // The vested amount is less than total, so it fits in an i128.
+    mul_div_u128(
+        total as u128,
+        elapsed.into(),
+        duration.into(),
+        Rounding::Floor,
+    )
+    .unwrap_or_default() as i128
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const START: u64 = 1_000;
+    const CLIFF: u64 = 100;
+    const DURATION: u64 = 400;
+    const TOTAL: i128 = 1_000;
+
+    fn vested(now: u64) -> i128 {
+        vested_amount(START, CLIFF, DURATION, TOTAL, now)
+    }
+
+    #[test]
+    fn test_before_start() {
+        assert_eq!(vested(0), 0);
+        assert_eq!(vested(START - 1), 0);
+    }
+
+    #[test]
+    fn test_at_start() {
+        assert_eq!(vested(START), 0);
+    }
+
+    #[test]
+    fn test_pre_cliff() {
+        assert_eq!(vested(START + 1), 0);
+        assert_eq!(vested(START + CLIFF - 1), 0);
+    }
+
+    #[test]
+    fn test_post_cliff() {
+        // Everything vested up to the cliff is released at the cliff.
+        assert_eq!(vested(START + CLIFF), 250);
+        assert_eq!(vested(START + CLIFF + 1), 252);
+    }
+
+    #[test]
+    fn test_mid_stream() {
+        assert_eq!(vested(START + 200), 500);
+        // Rounded down.
+        assert_eq!(vested(START + 201), 502);
+        assert_eq!(vested(START + 399), 997);
+    }
+
+    #[test]
+    fn test_fully_vested() {
+        assert_eq!(vested(START + DURATION), TOTAL);
+        assert_eq!(vested(START + DURATION + 1), TOTAL);
+        assert_eq!(vested(u64::MAX), TOTAL);
+    }
+
+    #[test]
+    fn test_no_cliff() {
+        assert_eq!(vested_amount(START, 0, DURATION, TOTAL, START), 0);
+        assert_eq!(vested_amount(START, 0, DURATION, TOTAL, START + 1), 2);
+    }
+
+    #[test]
+    fn test_cliff_at_end() {
+        assert_eq!(
+            vested_amount(START, DURATION, DURATION, TOTAL, START + 399),
+            0
+        );
+        assert_eq!(
+            vested_amount(START, DURATION, DURATION, TOTAL, START + 400),
+            TOTAL
+        );
+    }
+
+    #[test]
+    fn test_zero_duration() {
+        assert_eq!(vested_amount(START, 0, 0, TOTAL, START - 1), 0);
+        assert_eq!(vested_amount(START, 0, 0, TOTAL, START), TOTAL);
+    }
+
+    #[test]
+    fn test_large_total_does_not_overflow() {
+        let total = i128::MAX;
+        assert_eq!(vested_amount(0, 0, 2, total, 1), i128::MAX / 2);
+        assert_eq!(
+            vested_amount(0, 0, u64::MAX, total, u64::MAX - 1),
+            170141183460469231722463931679029329918
+        );
+        assert_eq!(vested_amount(0, 0, u64::MAX, total, u64::MAX), total);
+    }
+
+    #[test]
+    #[should_panic(expected = "vesting cliff must not be after the end")]
+    fn test_cliff_after_end_panics() {
+        vested_amount(START, DURATION + 1, DURATION, TOTAL, START);
+    }
+
+    #[test]
+    #[should_panic(expected = "vesting total must not be negative")]
+    fn test_negative_total_panics() {
+        vested_amount(START, CLIFF, DURATION, -1, START);
+    }
+}
@@ -0,0 +1,30 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use soroban_sdk::{
+    testutils::arbitrary::{arbitrary, Arbitrary, SorobanArbitrary},
+    Env, IntoVal,
+};
+
+use test_fuzz::{Contract, ContractClient, Order};
+
+// Types with the contracttype attribute derive SorobanArbitrary, so a fuzz
+// input can contain the prototype of a whole user-defined type, including its
+// Address, U256, Bytes, Vec and Map fields.
+#[derive(Arbitrary, Debug)]
+struct Input {
+    order: <Order as SorobanArbitrary>::Prototype,
+}
+
+fuzz_target!(|input: Input| {
+    let env = Env::default();
+
+    let order: Order = input.order.into_val(&env);
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    client.place(&order);
+    assert_eq!(client.order(), Some(order));
+});
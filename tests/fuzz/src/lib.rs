@@ -1,9 +1,21 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, U256};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, Bytes, Env, Map, Symbol, Vec, U256,
+};
 
 #[contract]
 pub struct Contract;
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Order {
+    pub owner: Address,
+    pub amount: U256,
+    pub memo: Bytes,
+    pub legs: Vec<u32>,
+    pub meta: Map<Symbol, Bytes>,
+}
+
 #[contractimpl]
 impl Contract {
     pub fn run(a: U256, b: U256) {
@@ -11,4 +23,14 @@ impl Contract {
             panic!("unexpected")
         }
     }
+
+    pub fn place(env: Env, order: Order) {
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("order"), &order);
+    }
+
+    pub fn order(env: Env) -> Option<Order> {
+        env.storage().persistent().get(&symbol_short!("order"))
+    }
 }